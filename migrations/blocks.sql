@@ -0,0 +1,9 @@
+-- adds the author of comments, filtered for blocked users, to existing databases; run it after schema.sql.
+\set ON_ERROR_STOP on
+
+begin;
+
+alter table comments
+	add column if not exists author text generated always as (((data -> 'author'::text) ->> 'raw'::text)) stored;
+
+commit;
@@ -0,0 +1,16 @@
+-- adds the season of matches and the competition context of communities to existing databases; run it after schema.sql.
+-- matches stored before are friendlies, outside of any season.
+\set ON_ERROR_STOP on
+
+begin;
+
+alter table matches
+	add column if not exists season text;
+
+create index if not exists matches_season_idx
+	on matches (season, status);
+
+alter table communities
+	add column if not exists context_competition text;
+
+commit;
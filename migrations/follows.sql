@@ -0,0 +1,12 @@
+-- adds the author of posts, listed in the following feed, to existing databases; run it after schema.sql.
+\set ON_ERROR_STOP on
+
+begin;
+
+alter table posts
+	add column if not exists author text generated always as (((data -> 'author'::text) ->> 'raw'::text)) stored;
+
+create index if not exists posts_author_idx
+	on posts (author, published);
+
+commit;
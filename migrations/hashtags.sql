@@ -0,0 +1,13 @@
+-- adds the hashtags of posts to existing databases; run it after schema.sql.
+-- hashtags are extracted when posts are stored, so posts stored before have none.
+\set ON_ERROR_STOP on
+
+begin;
+
+alter table posts
+	add column if not exists hashtags text[] default '{}'::text[] not null;
+
+create index if not exists posts_hashtags_idx
+	on posts using gin (hashtags);
+
+commit;
@@ -0,0 +1,24 @@
+-- registers the accounts stored before the support of identity providers other than firebase auth;
+-- accounts of firebase auth keep their bare id, so only their (firebase) identity has to be registered.
+-- other ids (e.g. authors of posts only) are registered when their user signs in again.
+insert into identities (identity, account, data)
+select 'firebase:' || account,
+       account,
+       json_build_object(
+           'identity', json_build_object('raw', 'firebase:' || account),
+           'account', json_build_object('raw', account),
+           'linked', to_char(now() at time zone 'utc', 'YYYY-MM-DD"T"HH24:MI:SS"Z"')
+       )
+from (
+    select unnest(members) as account from communities
+    union
+    select unnest(editors) from communities
+    union
+    select json_array_elements(data->'staff')->>'raw' from clubs
+    union
+    select json_array_elements(data->'staff')->>'raw' from teams
+    union
+    select author from post_reactions
+) accounts
+where account ~ '^[A-Za-z0-9]{20,24}$'
+on conflict (identity) do nothing;
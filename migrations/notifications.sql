@@ -0,0 +1,6 @@
+-- starts the notifications at the events published from now on;
+-- notifying users of everything that happened before would flood their inboxes.
+insert into event_consumers (consumer, position)
+select 'notifications', coalesce(max(id), 0)
+from events
+on conflict (consumer) do nothing;
@@ -0,0 +1,12 @@
+-- adds the membership offers of communities to existing databases; run it after schema.sql.
+\set ON_ERROR_STOP on
+
+begin;
+
+alter table communities
+	add column if not exists offers text[] generated always as (extract_community_offers(data)) stored;
+
+create index if not exists communities_offers_idx
+	on communities using gin (offers);
+
+commit;
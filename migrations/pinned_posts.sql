@@ -0,0 +1,16 @@
+-- adds pinned posts and announcements to existing databases; run it after schema.sql.
+\set ON_ERROR_STOP on
+
+begin;
+
+alter table posts
+	add column if not exists pinned timestamp,
+	add column if not exists announced_until timestamp;
+
+create index if not exists posts_pinned_idx
+	on posts (community, pinned) where pinned is not null;
+
+create index if not exists posts_announced_idx
+	on posts (announced_until) where announced_until is not null;
+
+commit;
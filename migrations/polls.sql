@@ -0,0 +1,14 @@
+-- adds the closing of polls to existing databases; run it after schema.sql.
+-- posts stored before have no poll, so there's nothing to close.
+\set ON_ERROR_STOP on
+
+begin;
+
+alter table posts
+	add column if not exists poll_closes timestamp,
+	add column if not exists poll_closed boolean default false not null;
+
+create index if not exists posts_poll_closes_idx
+	on posts (poll_closes) where not poll_closed;
+
+commit;
@@ -0,0 +1,10 @@
+-- adds the time of post reactions to existing databases; run it after schema.sql.
+-- reactions stored before are listed with an unknown time.
+\set ON_ERROR_STOP on
+
+begin;
+
+alter table post_reactions
+	add column if not exists reacted timestamp;
+
+commit;
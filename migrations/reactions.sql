@@ -0,0 +1,27 @@
+-- replaces the reaction stats of existing databases, counting any reaction instead of the emotions only; run it after schema.sql.
+-- the columns of the views change, so they're dropped instead of replaced.
+\set ON_ERROR_STOP on
+
+begin;
+
+drop view if exists post_reactions_stats;
+create view post_reactions_stats(post, reaction, reactions) as
+	SELECT post,
+       emotion  AS reaction,
+       count(*) AS reactions
+FROM post_reactions
+GROUP BY post, emotion;
+
+alter table post_reactions_stats owner to postgres;
+
+drop view if exists comment_reactions_stats;
+create view comment_reactions_stats(comment, reaction, reactions) as
+	SELECT comment,
+       emotion  AS reaction,
+       count(*) AS reactions
+FROM comment_reactions
+GROUP BY comment, emotion;
+
+alter table comment_reactions_stats owner to postgres;
+
+commit;
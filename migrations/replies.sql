@@ -0,0 +1,13 @@
+-- adds the parent of comments to existing databases; run it after schema.sql.
+-- comments stored before reply to posts only, so they're top-level comments.
+\set ON_ERROR_STOP on
+
+begin;
+
+alter table comments
+	add column if not exists parent text generated always as (((data -> 'parent_comment'::text) ->> 'raw'::text)) stored;
+
+create index if not exists comments_parent_idx
+	on comments (parent);
+
+commit;
@@ -0,0 +1,12 @@
+-- adds the editors of communities and the hidden flag of posts to existing databases; run it after schema.sql.
+\set ON_ERROR_STOP on
+
+begin;
+
+alter table communities
+	add column if not exists editors text[] generated always as (extract_community_editors(data)) stored;
+
+alter table posts
+	add column if not exists hidden boolean default false not null;
+
+commit;
@@ -0,0 +1,14 @@
+-- adds drafts and scheduled posts to existing databases; run it after schema.sql.
+-- posts stored before are published.
+\set ON_ERROR_STOP on
+
+begin;
+
+alter table posts
+	add column if not exists status text default 'published'::text not null,
+	add column if not exists scheduled timestamp;
+
+create index if not exists posts_scheduled_idx
+	on posts (scheduled) where status = 'scheduled'::text;
+
+commit;
@@ -0,0 +1,32 @@
+-- rebuilds the search indexes with the text search configuration used by the queries (SEARCH_CONFIGURATION),
+-- e.g. psql -v configuration=english -f migrations/search.sql; schema.sql creates them for the default, simple.
+-- queries with another configuration than the indexes can't use them, so run this whenever it changes.
+\set ON_ERROR_STOP on
+
+begin;
+
+drop index if exists clubs_search_idx;
+create index clubs_search_idx
+	on clubs using gin (to_tsvector(:'configuration'::regconfig, name));
+
+drop index if exists teams_search_idx;
+create index teams_search_idx
+	on teams using gin (to_tsvector(:'configuration'::regconfig, name));
+
+drop index if exists competitions_search_idx;
+create index competitions_search_idx
+	on competitions using gin (to_tsvector(:'configuration'::regconfig, name));
+
+drop index if exists communities_search_idx;
+create index communities_search_idx
+	on communities using gin (to_tsvector(:'configuration'::regconfig, name));
+
+drop index if exists posts_search_idx;
+create index posts_search_idx
+	on posts using gin (to_tsvector(:'configuration'::regconfig, ((data -> 'text'::text) ->> 'raw'::text)));
+
+drop index if exists comments_search_idx;
+create index comments_search_idx
+	on comments using gin (to_tsvector(:'configuration'::regconfig, ((data -> 'text'::text) ->> 'raw'::text)));
+
+commit;
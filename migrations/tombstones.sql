@@ -0,0 +1,19 @@
+-- adds the removal of posts and comments to existing databases; run it after schema.sql.
+-- content stored before was never removed, so it's left without tombstone.
+\set ON_ERROR_STOP on
+
+begin;
+
+alter table posts
+	add column if not exists removed timestamp;
+
+create index if not exists posts_removed_idx
+	on posts (removed) where removed is not null;
+
+alter table comments
+	add column if not exists removed timestamp;
+
+create index if not exists comments_removed_idx
+	on comments (removed) where removed is not null;
+
+commit;
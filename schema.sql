@@ -1,3 +1,39 @@
+create table if not exists profiles
+(
+	id text not null
+		constraint profiles_pkey
+			primary key,
+	data json not null
+);
+
+alter table profiles owner to postgres;
+
+create table if not exists identities
+(
+	identity text not null
+		constraint identities_pkey
+			primary key,
+	account text not null,
+	data json not null
+);
+
+create index if not exists identities_account_idx
+	on identities (account);
+
+alter table identities owner to postgres;
+
+create table if not exists identity_links
+(
+	code text not null
+		constraint identity_links_pkey
+			primary key,
+	account text not null,
+	data json not null,
+	expires timestamp not null
+);
+
+alter table identity_links owner to postgres;
+
 create table if not exists clubs
 (
 	id text not null
@@ -7,6 +43,9 @@ create table if not exists clubs
 	name text default ((data -> 'name'::text) ->> 'raw'::text) not null
 );
 
+create index if not exists clubs_search_idx
+	on clubs using gin (to_tsvector('simple'::regconfig, name));
+
 alter table clubs owner to postgres;
 
 create table if not exists teams
@@ -18,8 +57,108 @@ create table if not exists teams
 	name text default ((data -> 'name'::text) ->> 'raw'::text) not null
 );
 
+create index if not exists teams_search_idx
+	on teams using gin (to_tsvector('simple'::regconfig, name));
+
 alter table teams owner to postgres;
 
+create table if not exists players
+(
+	id text not null
+		constraint players_pkey
+			primary key,
+	data json not null,
+	name text not null,
+	team text,
+	shirt_number integer
+);
+
+create index if not exists players_team_idx
+	on players (team);
+
+alter table players owner to postgres;
+
+create table if not exists team_events
+(
+	id text not null
+		constraint team_events_pkey
+			primary key,
+	data json not null,
+	team text not null,
+	start timestamp not null,
+	last_end timestamp not null
+);
+
+create index if not exists team_events_team_idx
+	on team_events (team, start);
+
+alter table team_events owner to postgres;
+
+create table if not exists rsvps
+(
+	event text not null,
+	occurrence timestamp not null,
+	respondent text not null,
+	data json not null,
+	constraint rsvps_pkey
+		primary key (event, occurrence, respondent)
+);
+
+alter table rsvps owner to postgres;
+
+create table if not exists matches
+(
+	id text not null
+		constraint matches_pkey
+			primary key,
+	data json not null,
+	home text not null,
+	away text not null,
+	kickoff timestamp not null,
+	status text not null,
+	season text
+);
+
+create index if not exists matches_home_idx
+	on matches (home, kickoff);
+
+create index if not exists matches_away_idx
+	on matches (away, kickoff);
+
+create index if not exists matches_season_idx
+	on matches (season, status);
+
+alter table matches owner to postgres;
+
+create table if not exists competitions
+(
+	id text not null
+		constraint competitions_pkey
+			primary key,
+	data json not null,
+	name text default ((data -> 'name'::text) ->> 'raw'::text) not null
+);
+
+create index if not exists competitions_search_idx
+	on competitions using gin (to_tsvector('simple'::regconfig, name));
+
+alter table competitions owner to postgres;
+
+create table if not exists seasons
+(
+	id text not null
+		constraint seasons_pkey
+			primary key,
+	data json not null,
+	competition text default ((data -> 'competition'::text) ->> 'raw'::text) not null,
+	name text default ((data -> 'name'::text) ->> 'raw'::text) not null
+);
+
+create index if not exists seasons_competition_idx
+	on seasons (competition);
+
+alter table seasons owner to postgres;
+
 create table if not exists communities
 (
 	id text not null
@@ -29,9 +168,19 @@ create table if not exists communities
 	name text default ((data -> 'name'::text) ->> 'raw'::text) not null,
 	context_club text,
 	context_team text,
-	members text[] default extract_community_members(data)
+	context_competition text,
+	members text[] default extract_community_members(data),
+	editors text[] default extract_community_editors(data),
+	offers text[] default extract_community_offers(data)
 );
 
+create index if not exists communities_offers_idx
+	on communities using gin (offers);
+
+-- note: search indexes use the 'simple' configuration; recreate them when SEARCH_CONFIGURATION selects another
+create index if not exists communities_search_idx
+	on communities using gin (to_tsvector('simple'::regconfig, name));
+
 alter table communities owner to postgres;
 
 create table if not exists posts
@@ -41,9 +190,43 @@ create table if not exists posts
 			primary key,
 	data json not null,
 	community text default ((data -> 'community'::text) ->> 'raw'::text) not null,
-	published timestamp default text_to_timestamp((data ->> 'published'::text)) not null
+	published timestamp default text_to_timestamp((data ->> 'published'::text)) not null,
+	hidden boolean default false not null,
+	removed timestamp,
+	pinned timestamp,
+	announced_until timestamp,
+	status text default 'published'::text not null,
+	scheduled timestamp,
+	poll_closes timestamp,
+	poll_closed boolean default false not null,
+	hashtags text[] default '{}'::text[] not null,
+	author text default ((data -> 'author'::text) ->> 'raw'::text) not null
 );
 
+create index if not exists posts_removed_idx
+	on posts (removed) where removed is not null;
+
+create index if not exists posts_pinned_idx
+	on posts (community, pinned) where pinned is not null;
+
+create index if not exists posts_announced_idx
+	on posts (announced_until) where announced_until is not null;
+
+create index if not exists posts_scheduled_idx
+	on posts (scheduled) where status = 'scheduled'::text;
+
+create index if not exists posts_hashtags_idx
+	on posts using gin (hashtags);
+
+create index if not exists posts_author_idx
+	on posts (author, published);
+
+create index if not exists posts_poll_closes_idx
+	on posts (poll_closes) where not poll_closed;
+
+create index if not exists posts_search_idx
+	on posts using gin (to_tsvector('simple'::regconfig, ((data -> 'text'::text) ->> 'raw'::text)));
+
 alter table posts owner to postgres;
 
 create table if not exists comments
@@ -53,9 +236,21 @@ create table if not exists comments
 			primary key,
 	data json not null,
 	reply_to text default ((data -> 'reply_to'::text) ->> 'raw'::text) not null,
-	published timestamp default text_to_timestamp((data ->> 'published'::text)) not null
+	parent text default ((data -> 'parent_comment'::text) ->> 'raw'::text),
+	published timestamp default text_to_timestamp((data ->> 'published'::text)) not null,
+	removed timestamp,
+	author text default ((data -> 'author'::text) ->> 'raw'::text) not null
 );
 
+create index if not exists comments_parent_idx
+	on comments (parent);
+
+create index if not exists comments_removed_idx
+	on comments (removed) where removed is not null;
+
+create index if not exists comments_search_idx
+	on comments using gin (to_tsvector('simple'::regconfig, ((data -> 'text'::text) ->> 'raw'::text)));
+
 alter table comments owner to postgres;
 
 create table if not exists events
@@ -70,35 +265,171 @@ create table if not exists events
 
 alter table events owner to postgres;
 
+create table if not exists event_consumers
+(
+	consumer text not null
+		constraint event_consumers_pkey
+			primary key,
+	position integer not null
+);
+
+alter table event_consumers owner to postgres;
+
 create table if not exists post_reactions
 (
 	post text not null,
 	author text not null,
 	emotion text not null,
 	data json not null,
+	reacted timestamp,
 	constraint post_reactions_pkey
 		primary key (post, author)
 );
 
 alter table post_reactions owner to postgres;
 
-create view post_reactions_stats(post, reactions_love, reactions_funny, reactions_celebrate, reactions_support, reactions_insightful) as
-	SELECT reaction.post,
-       count(love.*)       AS reactions_love,
-       count(funny.*)      AS reactions_funny,
-       count(celebrate.*)  AS reactions_celebrate,
-       count(support.*)    AS reactions_support,
-       count(insightful.*) AS reactions_insightful
-FROM post_reactions reaction
-         LEFT JOIN post_reactions love ON reaction.post = love.post AND love.emotion = 'love'::text
-         LEFT JOIN post_reactions funny ON reaction.post = funny.post AND funny.emotion = 'funny'::text
-         LEFT JOIN post_reactions celebrate ON reaction.post = celebrate.post AND celebrate.emotion = 'celebrate'::text
-         LEFT JOIN post_reactions support ON reaction.post = support.post AND support.emotion = 'support'::text
-         LEFT JOIN post_reactions insightful ON reaction.post = insightful.post AND insightful.emotion = 'insightful'::text
-GROUP BY reaction.post;
+create table if not exists comment_reactions
+(
+	comment text not null,
+	author text not null,
+	emotion text not null,
+	data json not null,
+	constraint comment_reactions_pkey
+		primary key (comment, author)
+);
+
+alter table comment_reactions owner to postgres;
+
+create table if not exists poll_votes
+(
+	post text not null,
+	voter text not null,
+	choices integer[] not null,
+	data json not null,
+	voted timestamp not null,
+	constraint poll_votes_pkey
+		primary key (post, voter)
+);
+
+alter table poll_votes owner to postgres;
+
+create table if not exists bookmarks
+(
+	owner text not null,
+	target_kind text not null,
+	target_id text not null,
+	data json not null,
+	constraint bookmarks_pkey
+		primary key (owner, target_kind, target_id)
+);
+
+alter table bookmarks owner to postgres;
+
+create table if not exists follows
+(
+	follower text not null,
+	followee text not null,
+	data json not null,
+	followed timestamp not null,
+	constraint follows_pkey
+		primary key (follower, followee)
+);
+
+create index if not exists follows_followee_idx
+	on follows (followee);
+
+alter table follows owner to postgres;
+
+create table if not exists blocks
+(
+	blocker text not null,
+	blocked text not null,
+	data json not null,
+	since timestamp not null,
+	constraint blocks_pkey
+		primary key (blocker, blocked)
+);
+
+alter table blocks owner to postgres;
+
+create table if not exists reports
+(
+	id text not null
+		constraint reports_pkey
+			primary key,
+	data json not null,
+	community text not null,
+	target_kind text not null,
+	target_id text not null,
+	reporter text,
+	status text not null,
+	reported timestamp default text_to_timestamp((data ->> 'reported'::text)) not null
+);
+
+create index if not exists reports_target_idx
+	on reports (target_kind, target_id);
+
+alter table reports owner to postgres;
+
+create table if not exists word_lists
+(
+	community text not null,
+	language text not null,
+	data json not null,
+	constraint word_lists_pkey
+		primary key (community, language)
+);
+
+alter table word_lists owner to postgres;
+
+create table if not exists notifications
+(
+	id text not null
+		constraint notifications_pkey
+			primary key,
+	recipient text not null,
+	key text not null,
+	data json not null,
+	updated timestamp not null,
+	read boolean default false not null
+);
+
+create index if not exists notifications_recipient_idx
+	on notifications (recipient, updated);
+
+create unique index if not exists notifications_unread_idx
+	on notifications (recipient, key) where not read;
+
+alter table notifications owner to postgres;
+
+create table if not exists notification_preferences
+(
+	id text not null
+		constraint notification_preferences_pkey
+			primary key,
+	data json not null
+);
+
+alter table notification_preferences owner to postgres;
+
+create view post_reactions_stats(post, reaction, reactions) as
+	SELECT post,
+       emotion  AS reaction,
+       count(*) AS reactions
+FROM post_reactions
+GROUP BY post, emotion;
 
 alter table post_reactions_stats owner to postgres;
 
+create view comment_reactions_stats(comment, reaction, reactions) as
+	SELECT comment,
+       emotion  AS reaction,
+       count(*) AS reactions
+FROM comment_reactions
+GROUP BY comment, emotion;
+
+alter table comment_reactions_stats owner to postgres;
+
 create function bigint_max() returns bigint
 	immutable
 	strict
@@ -129,3 +460,67 @@ $$;
 
 alter function extract_community_members(json) owner to postgres;
 
+create function extract_community_editors(json) returns text[]
+	immutable
+	strict
+	language sql
+as $$
+select array(select json_array_elements($1->'editors')->>'raw')
+$$;
+
+alter function extract_community_editors(json) owner to postgres;
+
+create function extract_community_offers(json) returns text[]
+	immutable
+	strict
+	language sql
+as $$
+select array(select json_array_elements(coalesce($1->'offers', '[]'::json))->>'raw')
+$$;
+
+alter function extract_community_offers(json) owner to postgres;
+
+create function community_member_muted(json, text) returns boolean
+	stable
+	strict
+	language sql
+as $$
+select exists(
+    select 1
+    from json_array_elements($1->'mutes') mute
+    where (mute->'member')->>'raw' = $2
+      and text_to_timestamp(mute->>'until') > (now() at time zone 'utc')
+)
+$$;
+
+alter function community_member_muted(json, text) owner to postgres;
+
+create function community_member_banned(json, text) returns boolean
+	stable
+	strict
+	language sql
+as $$
+select exists(
+    select 1
+    from json_array_elements($1->'bans') ban
+    where (ban->'user')->>'raw' = $2
+      and (ban->>'expires' is null or text_to_timestamp(ban->>'expires') > (now() at time zone 'utc'))
+)
+$$;
+
+alter function community_member_banned(json, text) owner to postgres;
+
+create function user_blocked(text, text) returns boolean
+	stable
+	strict
+	language sql
+as $$
+select exists(
+    select 1
+    from blocks
+    where blocker = $1
+      and blocked = $2
+)
+$$;
+
+alter function user_blocked(text, text) owner to postgres;
@@ -1,4 +1,9 @@
+use std::collections::HashMap;
 use std::error::Error;
+use std::pin::Pin;
+use chrono::{DateTime, Utc};
+use futures_util::{stream, Stream, StreamExt};
+use tokio::sync::broadcast::error::RecvError;
 use jwt::{Header, RegisteredClaims, Token};
 use tonic::{Request, Response, Status};
 use tonic::metadata::MetadataMap;
@@ -8,41 +13,316 @@ use crate::api::ApiService;
 use crate::api::api_v1_server::{ApiV1};
 
 use crate::domain::media::aggregates::ImageId;
+use crate::domain::calendar::aggregates::{Availability, EventTitle, Frequency, Location, Recurrence, RsvpResponse, TeamEvent, TeamEventId, TeamEventKind};
+use crate::domain::calendar::commands::{CancelEvent, RespondToEvent, ScheduleEvent};
 use crate::domain::club::aggregates::{Club, ClubId, ClubName};
 use crate::domain::club::commands::{AddStaffMember, New, RemoveStaffMember, SetLogo};
-use crate::domain::social::aggregates::{Comment, CommentId, CommentText, Community, CommunityContext, CommunityId, CommunityName, Feed, FeedListing, Post, PostAttachment, PostAttachments, PostId, PostReaction, PostText};
-use crate::domain::team::aggregates::{Team, TeamId, TeamName};
-use crate::domain::account::aggregates::UserId;
+use crate::domain::competition::aggregates::{BonusRule, Competition, CompetitionId, CompetitionName, PointsRules, Season, SeasonId, SeasonName, Standing, TieBreaker};
+use crate::domain::competition::commands::{AddTeamToSeason, NewSeason, RemoveTeamFromSeason};
+use crate::domain::fixture::aggregates::{CardColour, FixtureScope, Match, MatchId, MatchIncident, MatchIncidentKind, MatchStatus, PlayerName, Score, Side, Venue};
+use crate::domain::fixture::commands::{FinaliseResult, PostponeMatch, RecordIncident, RescheduleMatch, ScheduleMatch, UpdateScore};
+use crate::domain::social::aggregates::{Block, BookmarkTarget, CommentId, CommentListing, CommentParent, CommentReaction, CommentRevision, CommentText, Community, CommunityContext, CommunityId, CommunityName, Feed, FeedListing, Follow, Hashtag, Language, ModerationReason, Post, Poll, PollChoice, PollOption, PollTally, PollVisibility, PollVote, PostAttachment, PostAttachments, PostId, PostReaction, PostReactionRecord, PostRevision, PostStatus, PostText, ReactionDefinition, ReactionEmoji, ReactionKey, RemoverRole, Report, ReportCategory, ReportId, ReportTarget, ReportText, SearchHit, SearchKind, SearchQuery, SearchTarget, TextEntity, TextEntityKind, Tombstone, ViewerState, WordListAction};
+use crate::domain::team::aggregates::{Player, PlayerId, PlayerPosition, PlayerStatus, ShirtNumber, Team, TeamId, TeamName};
+use crate::domain::account::aggregates::{Bio, DisplayName, Identity, IdentityProvider, IdentityProviders, LinkCode, LinkedIdentity, Locale, ProfileSummary, UserId, UserProfile};
+use crate::domain::account::commands::{LinkIdentity, OfferIdentityLink, UnlinkIdentity, UpdateProfile};
+use crate::domain::notification::aggregates::{Notification, NotificationCategory, NotificationId, NotificationKind};
+use crate::domain::notification::commands::{MarkAllNotificationsRead, MarkNotificationsRead, UpdateNotificationPreferences};
 
 #[tonic::async_trait]
 impl ApiV1 for ApiService {
     // queries
+    async fn get_profile(&self, request: Request<api::GetProfileRequest>) -> Result<Response<api::GetProfileResponse>, Status> {
+        let payload = request.into_inner();
+        let user = UserId::parse(payload.user_id.as_str())
+            .map_err(|_| to_malformed_status("user_id"))?;
+
+        self.account_usecase.get_profile(user)
+            .await
+            .map_err(to_status)
+            .map(|profile|
+                Response::new(api::GetProfileResponse {
+                    profile: Some(to_profile(&profile)),
+                })
+            )
+    }
+
+    async fn list_my_identities(&self, request: Request<api::ListMyIdentitiesRequest>) -> Result<Response<api::ListMyIdentitiesResponse>, Status> {
+        let account = self.current_user(request.metadata()).await?;
+
+        self.account_usecase.list_identities(account)
+            .await
+            .map_err(to_status)
+            .map(|identities|
+                Response::new(api::ListMyIdentitiesResponse {
+                    identities: identities.map(to_identity),
+                })
+            )
+    }
+
+    async fn batch_get_profiles(&self, request: Request<api::BatchGetProfilesRequest>) -> Result<Response<api::BatchGetProfilesResponse>, Status> {
+        let payload = request.into_inner();
+        let users = payload.user_ids
+            .iter()
+            .map(|id| UserId::parse(id.as_str()))
+            .collect::<Result<Vec<UserId>, _>>()
+            .map_err(|_| to_malformed_status("user_ids"))?;
+
+        self.account_usecase.batch_get_profiles(users)
+            .await
+            .map_err(to_status)
+            .map(|profiles|
+                Response::new(api::BatchGetProfilesResponse {
+                    profiles: profiles.map(to_profile),
+                })
+            )
+    }
+
     async fn list_clubs(&self, request: Request<api::ListClubsRequest>) -> Result<Response<api::ListClubsResponse>, Status> {
         let payload = request.into_inner();
         let after = parse_optional(payload.after, ClubId::parse)
             .map_err(|_| to_malformed_status("after"))?;
 
-        self.club_usecase.list_clubs(after)
+        let clubs = self.club_usecase.list_clubs(after)
+            .await
+            .map_err(to_status)?;
+        let users = self.users(clubs.iter().flat_map(|club| club.staff.iter().cloned()).collect())
+            .await?;
+
+        Ok(Response::new(api::ListClubsResponse {
+            clubs: clubs.map(to_club),
+            users,
+        }))
+    }
+
+    async fn list_teams(&self, request: Request<api::ListTeamsRequest>) -> Result<Response<api::ListTeamsResponse>, Status> {
+        let payload = request.into_inner();
+        let after = parse_optional(payload.after, TeamId::parse)
+            .map_err(|_| to_malformed_status("after"))?;
+
+        let teams = self.team_usecase.list_teams(after)
+            .await
+            .map_err(to_status)?;
+        let users = self.users(teams.iter().flat_map(|team| team.staff.iter().cloned()).collect())
+            .await?;
+
+        Ok(Response::new(api::ListTeamsResponse {
+            teams: teams.map(to_team),
+            users,
+        }))
+    }
+
+    async fn list_squad(&self, request: Request<api::ListSquadRequest>) -> Result<Response<api::ListSquadResponse>, Status> {
+        let payload = request.into_inner();
+        let team = TeamId::parse(payload.team_id.as_str())
+            .map_err(|_| to_malformed_status("team_id"))?;
+
+        self.team_usecase.list_squad(team)
             .await
             .map_err(to_status)
             .map(|result|
-                Response::new(api::ListClubsResponse {
-                    clubs: result.map(to_club),
+                Response::new(api::ListSquadResponse {
+                    players: result.map(to_player),
                 })
             )
     }
 
-    async fn list_teams(&self, request: Request<api::ListTeamsRequest>) -> Result<Response<api::ListTeamsResponse>, Status> {
+    async fn list_team_events(&self, request: Request<api::ListTeamEventsRequest>) -> Result<Response<api::ListTeamEventsResponse>, Status> {
         let payload = request.into_inner();
-        let after = parse_optional(payload.after, TeamId::parse)
+        let team = TeamId::parse(payload.team_id.as_str())
+            .map_err(|_| to_malformed_status("team_id"))?;
+        let from = parse_timestamp(payload.from)
+            .map_err(|_| to_malformed_status("from"))?;
+        let until = parse_timestamp(payload.until)
+            .map_err(|_| to_malformed_status("until"))?;
+
+        self.calendar_usecase.list_events(team, from, until)
+            .await
+            .map_err(to_status)
+            .map(|result| {
+                let mut occurrences: Vec<api::TeamEventOccurrence> = result
+                    .iter()
+                    .flat_map(|event| event.occurrences(&from, &until)
+                        .into_iter()
+                        .map(|occurrence| api::TeamEventOccurrence {
+                            event_id: event.id.to_string(),
+                            start: occurrence.start.timestamp_millis() as u64,
+                            end: occurrence.end.timestamp_millis() as u64,
+                            cancelled: occurrence.cancelled,
+                        }))
+                    .collect();
+                occurrences.sort_by_key(|occurrence| occurrence.start);
+
+                Response::new(api::ListTeamEventsResponse {
+                    events: result.map(to_team_event),
+                    occurrences,
+                })
+            })
+    }
+
+    async fn get_availability(&self, request: Request<api::GetAvailabilityRequest>) -> Result<Response<api::GetAvailabilityResponse>, Status> {
+        let viewer = self.current_user(request.metadata()).await?;
+        let payload = request.into_inner();
+        let id = TeamEventId::parse(payload.event_id.as_str())
+            .map_err(|_| to_malformed_status("event_id"))?;
+        let occurrence = parse_timestamp(payload.occurrence)
+            .map_err(|_| to_malformed_status("occurrence"))?;
+
+        self.calendar_usecase.get_availability(id, occurrence, viewer)
+            .await
+            .map_err(to_status)
+            .map(|result|
+                Response::new(api::GetAvailabilityResponse {
+                    availability: result.map(to_availability),
+                })
+            )
+    }
+
+    async fn export_team_calendar(&self, request: Request<api::ExportTeamCalendarRequest>) -> Result<Response<api::ExportTeamCalendarResponse>, Status> {
+        let viewer = self.current_user(request.metadata()).await?;
+        let payload = request.into_inner();
+        let team = TeamId::parse(payload.team_id.as_str())
+            .map_err(|_| to_malformed_status("team_id"))?;
+
+        self.calendar_usecase.export_calendar(team, viewer)
+            .await
+            .map_err(to_status)
+            .map(|ics|
+                Response::new(api::ExportTeamCalendarResponse {
+                    ics,
+                })
+            )
+    }
+
+    async fn list_fixtures(&self, request: Request<api::ListFixturesRequest>) -> Result<Response<api::ListFixturesResponse>, Status> {
+        let payload = request.into_inner();
+        let scope = match payload.scope {
+            Some(api::list_fixtures_request::Scope::TeamId(id)) => TeamId::parse(id.as_str())
+                .map(FixtureScope::Team)
+                .map_err(|_| to_malformed_status("team_id"))?,
+            Some(api::list_fixtures_request::Scope::ClubId(id)) => ClubId::parse(id.as_str())
+                .map(FixtureScope::Club)
+                .map_err(|_| to_malformed_status("club_id"))?,
+            None => return Err(to_malformed_status("scope")),
+        };
+        let from = parse_optional_timestamp(payload.from)
+            .map_err(|_| to_malformed_status("from"))?;
+        let until = parse_optional_timestamp(payload.until)
+            .map_err(|_| to_malformed_status("until"))?;
+        let after = parse_optional(payload.after, MatchId::parse)
+            .map_err(|_| to_malformed_status("after"))?;
+
+        self.fixture_usecase.list_fixtures(scope, from, until, after)
+            .await
+            .map_err(to_status)
+            .map(|result|
+                Response::new(api::ListFixturesResponse {
+                    matches: result.map(to_match),
+                })
+            )
+    }
+
+    type WatchMatchStream = Pin<Box<dyn Stream<Item = Result<api::MatchIncident, Status>> + Send>>;
+
+    async fn watch_match(&self, request: Request<api::WatchMatchRequest>) -> Result<Response<Self::WatchMatchStream>, Status> {
+        let payload = request.into_inner();
+        let id = MatchId::parse(payload.match_id.as_str())
+            .map_err(|_| to_malformed_status("match_id"))?;
+
+        let (fixture, receiver) = self.fixture_usecase.watch_match(id.clone())
+            .await
+            .map_err(to_status)?;
+
+        // note: replays the timeline so far, then follows live incidents (not replayed already) until full time;
+        // a watcher falling behind gets an error instead of a gap, watching again replays what it missed
+        let replayed = fixture.timeline.last().map(|incident| incident.recorded);
+        let timeline = stream::iter(fixture.timeline.map(to_match_incident).into_iter().map(Ok));
+        let live = stream::unfold((receiver, fixture.is_finished()), move |(mut receiver, finished)| {
+            let id = id.clone();
+            async move {
+                if finished {
+                    return None;
+                }
+
+                loop {
+                    match receiver.recv().await {
+                        Ok((fixture, incident)) if fixture == id && is_after(&incident.recorded, &replayed) => {
+                            let finished = incident.kind == MatchIncidentKind::FullTime;
+                            return Some((Ok(to_match_incident(&incident)), (receiver, finished)));
+                        },
+                        Ok(_) => continue,
+                        Err(RecvError::Lagged(_)) => {
+                            let status = Status::aborted("fell behind on live incidents, watch the match again");
+                            return Some((Err(status), (receiver, true)));
+                        },
+                        Err(RecvError::Closed) => return None,
+                    }
+                }
+            }
+        });
+
+        Ok(Response::new(Box::pin(timeline.chain(live))))
+    }
+
+    async fn list_community_offers(&self, request: Request<api::ListCommunityOffersRequest>) -> Result<Response<api::ListCommunityOffersResponse>, Status> {
+        let person = self.current_user(request.metadata()).await?;
+        let payload = request.into_inner();
+        let after = parse_optional(payload.after, CommunityId::parse)
+            .map_err(|_| to_malformed_status("after"))?;
+
+        self.social_usecase.list_offered_communities(person, after)
+            .await
+            .map_err(to_status)
+            .map(|result|
+                Response::new(api::ListCommunityOffersResponse {
+                    communities: result.map(to_community),
+                })
+            )
+    }
+
+    async fn list_competitions(&self, request: Request<api::ListCompetitionsRequest>) -> Result<Response<api::ListCompetitionsResponse>, Status> {
+        let payload = request.into_inner();
+        let after = parse_optional(payload.after, CompetitionId::parse)
+            .map_err(|_| to_malformed_status("after"))?;
+
+        self.competition_usecase.list_competitions(after)
+            .await
+            .map_err(to_status)
+            .map(|result|
+                Response::new(api::ListCompetitionsResponse {
+                    competitions: result.map(to_competition),
+                })
+            )
+    }
+
+    async fn list_seasons(&self, request: Request<api::ListSeasonsRequest>) -> Result<Response<api::ListSeasonsResponse>, Status> {
+        let payload = request.into_inner();
+        let competition = CompetitionId::parse(payload.competition_id.as_str())
+            .map_err(|_| to_malformed_status("competition_id"))?;
+        let after = parse_optional(payload.after, SeasonId::parse)
             .map_err(|_| to_malformed_status("after"))?;
 
-        self.team_usecase.list_teams(after)
+        self.competition_usecase.list_seasons(competition, after)
             .await
             .map_err(to_status)
             .map(|result|
-                Response::new(api::ListTeamsResponse {
-                    teams: result.map(to_team),
+                Response::new(api::ListSeasonsResponse {
+                    seasons: result.map(to_season),
+                })
+            )
+    }
+
+    async fn get_standings(&self, request: Request<api::GetStandingsRequest>) -> Result<Response<api::GetStandingsResponse>, Status> {
+        let payload = request.into_inner();
+        let season = SeasonId::parse(payload.season_id.as_str())
+            .map_err(|_| to_malformed_status("season_id"))?;
+
+        self.competition_usecase.get_standings(season)
+            .await
+            .map_err(to_status)
+            .map(|(season, standings)|
+                Response::new(api::GetStandingsResponse {
+                    season: Some(to_season(&season)),
+                    standings: standings.map(to_standing),
                 })
             )
     }
@@ -61,50 +341,141 @@ impl ApiV1 for ApiService {
                     .map_err(|_| to_malformed_status("context"))
                     .map(|team| Some(CommunityContext::Team(team)))?
             },
+            Some(api::list_communities_request::Context::CompetitionId(id)) => {
+                CompetitionId::parse(id.as_str())
+                    .map_err(|_| to_malformed_status("context"))
+                    .map(|competition| Some(CommunityContext::Competition(competition)))?
+            },
             _ => None
         };
 
         let after = parse_optional(payload.after, CommunityId::parse)
             .map_err(|_| to_malformed_status("after"))?;
 
-        self.social_usecase.list_communities(context, after)
+        let communities = self.social_usecase.list_communities(context, after)
+            .await
+            .map_err(to_status)?;
+        let users = self.users(communities.iter().flat_map(|community| community.editors.iter().cloned()).collect())
+            .await?;
+
+        Ok(Response::new(api::ListCommunitiesResponse {
+            communities: communities.map(to_community),
+            users,
+        }))
+    }
+
+    async fn list_comments(&self, request: Request<api::ListCommentsRequest>) -> Result<Response<api::ListCommentsResponse>, Status> {
+        let viewer = self.current_user(request.metadata()).await?;
+        let payload = request.into_inner();
+        let after = parse_optional(payload.after, CommentId::parse)
+            .map_err(|_| to_malformed_status("after"))?;
+
+        let result = match payload.parent {
+            Some(api::list_comments_request::Parent::ReplyToId(id)) => {
+                let reply_to = PostId::parse(id.as_str())
+                    .map_err(|_| to_malformed_status("reply_to_id"))?;
+
+                self.social_usecase.list_comments(reply_to, viewer, after, payload.include_removed, payload.replies).await
+            },
+            Some(api::list_comments_request::Parent::ThreadId(id)) => {
+                let thread = CommentId::parse(id.as_str())
+                    .map_err(|_| to_malformed_status("thread_id"))?;
+
+                self.social_usecase.list_comment_thread(thread, viewer, after, payload.include_removed).await
+            },
+            _ =>
+                return Err(to_malformed_status("parent"))
+        };
+
+        let comments = result
+            .map_err(to_status)?;
+        let users = self.users(comments.iter().map(|listing| listing.comment.author.clone()).collect())
+            .await?;
+
+        Ok(Response::new(api::ListCommentsResponse {
+            comments: comments.map(to_comment),
+            users,
+        }))
+    }
+
+    async fn list_post_revisions(&self, request: Request<api::ListPostRevisionsRequest>) -> Result<Response<api::ListPostRevisionsResponse>, Status> {
+        let viewer = self.current_user(request.metadata()).await?;
+        let payload = request.into_inner();
+        let post = PostId::parse(payload.post_id.as_str())
+            .map_err(|_| to_malformed_status("post_id"))?;
+
+        self.social_usecase.list_post_revisions(post, viewer)
+            .await
+            .map_err(to_status)
+            .map(|result|
+                Response::new(api::ListPostRevisionsResponse {
+                    revisions: result.map(to_post_revision),
+                })
+            )
+    }
+
+    async fn list_drafts(&self, request: Request<api::ListDraftsRequest>) -> Result<Response<api::ListDraftsResponse>, Status> {
+        let author = self.current_user(request.metadata()).await?;
+        let payload = request.into_inner();
+        let after = parse_optional(payload.after, PostId::parse)
+            .map_err(|_| to_malformed_status("after"))?;
+
+        self.social_usecase.list_drafts(author, after)
             .await
             .map_err(to_status)
             .map(|result|
-                Response::new(api::ListCommunitiesResponse {
-                    communities: result.map(to_community),
+                Response::new(api::ListDraftsResponse {
+                    posts: result.map(to_post),
                 })
             )
     }
 
-    async fn list_comments(&self, request: Request<api::ListCommentsRequest>) -> Result<Response<api::ListCommentsResponse>, Status> {
+    async fn list_comment_revisions(&self, request: Request<api::ListCommentRevisionsRequest>) -> Result<Response<api::ListCommentRevisionsResponse>, Status> {
+        let viewer = self.current_user(request.metadata()).await?;
         let payload = request.into_inner();
-        let reply_to = PostId::parse(payload.reply_to_id.as_str())
-            .map_err(|_| to_malformed_status("reply_to_id"))?;
-        let after = parse_optional(payload.after, CommentId::parse)
+        let comment = CommentId::parse(payload.comment_id.as_str())
+            .map_err(|_| to_malformed_status("comment_id"))?;
+
+        self.social_usecase.list_comment_revisions(comment, viewer)
+            .await
+            .map_err(to_status)
+            .map(|result|
+                Response::new(api::ListCommentRevisionsResponse {
+                    revisions: result.map(to_comment_revision),
+                })
+            )
+    }
+
+    async fn list_reports(&self, request: Request<api::ListReportsRequest>) -> Result<Response<api::ListReportsResponse>, Status> {
+        let editor = self.current_user(request.metadata()).await?;
+        let payload = request.into_inner();
+        let after = parse_optional(payload.after, ReportId::parse)
             .map_err(|_| to_malformed_status("after"))?;
 
-        self.social_usecase.list_comments(reply_to, after)
+        self.social_usecase.list_reports(editor, after)
             .await
             .map_err(to_status)
             .map(|result|
-                Response::new(api::ListCommentsResponse {
-                    comments: result.map(to_comment),
+                Response::new(api::ListReportsResponse {
+                    reports: result.map(to_report),
                 })
             )
     }
 
     async fn list_feed(&self, request: Request<api::ListFeedRequest>) -> Result<Response<api::ListFeedResponse>, Status> {
-        let person = current_user(request.metadata())?;
+        let person = self.current_user(request.metadata()).await?;
         let payload = request.into_inner();
         let feed = match payload.feed {
             Some(api::list_feed_request::Feed::Memberships(_)) => {
-                Ok(Feed::Memberships(person))
+                Ok(Feed::Memberships(person.clone()))
             },
             Some(api::list_feed_request::Feed::CommunityId(id)) => {
                 CommunityId::parse(id.as_str())
                     .map_err(|_| to_malformed_status("community"))
-                    .map(|community| Feed::Community(community))
+                    .map(Feed::Community)
+            },
+            Some(api::list_feed_request::Feed::Following(_)) => {
+                Ok(Feed::Following(person.clone()))
             },
             _ =>
                 Err(to_malformed_status("feed"))
@@ -112,17 +483,283 @@ impl ApiV1 for ApiService {
         let after = parse_optional(payload.after, PostId::parse)
             .map_err(|_| to_malformed_status("after"))?;
 
-        self.social_usecase.list_feed(feed, after)
+        let result = self.social_usecase.list_feed(feed, person, after, payload.include_removed)
+            .await
+            .map_err(to_status)?;
+        let users = self.users(result.iter().chain(result.pinned()).map(|listing| listing.post.author.clone()).collect())
+            .await?;
+
+        Ok(Response::new(api::ListFeedResponse {
+            listings: result.iter().map(to_feed_listing).collect(),
+            pinned: result.pinned().map(to_feed_listing).collect(),
+            users,
+        }))
+    }
+
+    async fn list_followers(&self, request: Request<api::ListFollowersRequest>) -> Result<Response<api::ListFollowersResponse>, Status> {
+        let payload = request.into_inner();
+        let user = UserId::parse(payload.user_id.as_str())
+            .map_err(|_| to_malformed_status("user_id"))?;
+        let after = parse_optional(payload.after, UserId::parse)
+            .map_err(|_| to_malformed_status("after"))?;
+
+        let follows = self.social_usecase.list_followers(user, after)
+            .await
+            .map_err(to_status)?;
+        let users = self.users(follows.iter().map(|follow| follow.follower.clone()).collect())
+            .await?;
+
+        Ok(Response::new(api::ListFollowersResponse {
+            follows: follows.map(to_follow),
+            users,
+        }))
+    }
+
+    async fn list_following(&self, request: Request<api::ListFollowingRequest>) -> Result<Response<api::ListFollowingResponse>, Status> {
+        let payload = request.into_inner();
+        let user = UserId::parse(payload.user_id.as_str())
+            .map_err(|_| to_malformed_status("user_id"))?;
+        let after = parse_optional(payload.after, UserId::parse)
+            .map_err(|_| to_malformed_status("after"))?;
+
+        let follows = self.social_usecase.list_following(user, after)
+            .await
+            .map_err(to_status)?;
+        let users = self.users(follows.iter().map(|follow| follow.followee.clone()).collect())
+            .await?;
+
+        Ok(Response::new(api::ListFollowingResponse {
+            follows: follows.map(to_follow),
+            users,
+        }))
+    }
+
+    async fn list_blocked_users(&self, request: Request<api::ListBlockedUsersRequest>) -> Result<Response<api::ListBlockedUsersResponse>, Status> {
+        let blocker = self.current_user(request.metadata()).await?;
+        let payload = request.into_inner();
+        let after = parse_optional(payload.after, UserId::parse)
+            .map_err(|_| to_malformed_status("after"))?;
+
+        let blocks = self.social_usecase.list_blocked_users(blocker, after)
+            .await
+            .map_err(to_status)?;
+        let users = self.users(blocks.iter().map(|block| block.user.clone()).collect())
+            .await?;
+
+        Ok(Response::new(api::ListBlockedUsersResponse {
+            blocked: blocks.map(to_blocked_user),
+            users,
+        }))
+    }
+
+    async fn list_posts_by_hashtag(&self, request: Request<api::ListPostsByHashtagRequest>) -> Result<Response<api::ListPostsByHashtagResponse>, Status> {
+        let viewer = self.current_user(request.metadata()).await?;
+        let payload = request.into_inner();
+        let hashtag = Hashtag::parse(payload.hashtag.trim_start_matches('#'))
+            .map_err(|_| to_malformed_status("hashtag"))?;
+        let after = parse_optional(payload.after, PostId::parse)
+            .map_err(|_| to_malformed_status("after"))?;
+
+        let result = self.social_usecase.list_posts_by_hashtag(hashtag, viewer, after)
+            .await
+            .map_err(to_status)?;
+        let users = self.users(result.iter().map(|listing| listing.post.author.clone()).collect())
+            .await?;
+
+        Ok(Response::new(api::ListPostsByHashtagResponse {
+            listings: result.iter().map(to_feed_listing).collect(),
+            users,
+        }))
+    }
+
+    async fn search(&self, request: Request<api::SearchRequest>) -> Result<Response<api::SearchResponse>, Status> {
+        let viewer = self.current_user(request.metadata()).await?;
+        let payload = request.into_inner();
+        let query = SearchQuery::parse(payload.query.as_str())
+            .map_err(|_| to_malformed_status("query"))?;
+        let kinds = payload.kinds
+            .into_iter()
+            .map(parse_search_kind)
+            .collect::<Option<Vec<SearchKind>>>()
+            .ok_or(to_malformed_status("kinds"))?;
+        let after = match payload.after {
+            Some(api::search_request::After::AfterCommunityId(id)) => Some(
+                CommunityId::parse(id.as_str())
+                    .map(SearchTarget::Community)
+                    .map_err(|_| to_malformed_status("after_community_id"))?),
+            Some(api::search_request::After::AfterPostId(id)) => Some(
+                PostId::parse(id.as_str())
+                    .map(SearchTarget::Post)
+                    .map_err(|_| to_malformed_status("after_post_id"))?),
+            Some(api::search_request::After::AfterCommentId(id)) => Some(
+                CommentId::parse(id.as_str())
+                    .map(SearchTarget::Comment)
+                    .map_err(|_| to_malformed_status("after_comment_id"))?),
+            None => None,
+        };
+
+        self.social_usecase.search(query, kinds, viewer, after)
             .await
             .map_err(to_status)
             .map(|result|
-                Response::new(api::ListFeedResponse {
-                    listings: result.iter().map(to_feed_listing).collect(),
+                Response::new(api::SearchResponse {
+                    hits: result.map(to_search_hit),
+                })
+            )
+    }
+
+    async fn list_notifications(&self, request: Request<api::ListNotificationsRequest>) -> Result<Response<api::ListNotificationsResponse>, Status> {
+        let recipient = self.current_user(request.metadata()).await?;
+        let payload = request.into_inner();
+        let after = parse_optional(payload.after, NotificationId::parse)
+            .map_err(|_| to_malformed_status("after"))?;
+
+        let notifications = self.notification_usecase.list_notifications(recipient.clone(), payload.unread_only, after)
+            .await
+            .map_err(to_status)?;
+        let unread = self.notification_usecase.count_unread(recipient)
+            .await
+            .map_err(to_status)?;
+        let users = self.users(notifications.iter().flat_map(|notification| notification.recent_actors().to_vec()).collect())
+            .await?;
+
+        Ok(Response::new(api::ListNotificationsResponse {
+            notifications: notifications.map(to_notification),
+            users,
+            unread,
+        }))
+    }
+
+    type WatchNotificationsStream = Pin<Box<dyn Stream<Item = Result<api::Notification, Status>> + Send>>;
+
+    async fn watch_notifications(&self, request: Request<api::WatchNotificationsRequest>) -> Result<Response<Self::WatchNotificationsStream>, Status> {
+        let recipient = self.current_user(request.metadata()).await?;
+        let receiver = self.notification_usecase.watch_notifications();
+
+        // note: follows the notifications of the recipient until the client disconnects; best-effort,
+        // notifications a slow client falls behind on are skipped here, though still listed in its inbox
+        let live = stream::unfold(receiver, move |mut receiver| {
+            let recipient = recipient.clone();
+            async move {
+                loop {
+                    match receiver.recv().await {
+                        Ok(notification) if notification.recipient == recipient => {
+                            return Some((Ok(to_notification(&notification)), receiver));
+                        },
+                        Ok(_) | Err(RecvError::Lagged(_)) => continue,
+                        Err(RecvError::Closed) => return None,
+                    }
+                }
+            }
+        });
+
+        Ok(Response::new(Box::pin(live)))
+    }
+
+    async fn get_notification_preferences(&self, request: Request<api::GetNotificationPreferencesRequest>) -> Result<Response<api::GetNotificationPreferencesResponse>, Status> {
+        let user = self.current_user(request.metadata()).await?;
+
+        self.notification_usecase.get_preferences(user)
+            .await
+            .map_err(to_status)
+            .map(|preferences|
+                Response::new(api::GetNotificationPreferencesResponse {
+                    muted: preferences.muted.iter().map(|category| to_notification_category(category) as i32).collect(),
                 })
             )
     }
 
     // commands
+    // - profile
+    async fn update_my_profile(&self, request: Request<api::UpdateMyProfileRequest>) -> Result<Response<api::UpdateMyProfileResponse>, Status> {
+        let user = self.current_user(request.metadata()).await?;
+        let payload = request.into_inner();
+        let display_name = DisplayName::parse(payload.display_name.as_str())
+            .map_err(|_| to_malformed_status("display_name"))?;
+        let avatar = parse_optional(payload.avatar_id, ImageId::parse)
+            .map_err(|_| to_malformed_status("avatar_id"))?;
+        let bio = parse_optional(payload.bio.trim(), Bio::parse)
+            .map_err(|_| to_malformed_status("bio"))?;
+        let favourite_club = parse_optional(payload.favourite_club_id, ClubId::parse)
+            .map_err(|_| to_malformed_status("favourite_club_id"))?;
+        let locale = parse_optional(payload.locale, Locale::parse)
+            .map_err(|_| to_malformed_status("locale"))?;
+
+        let command = UpdateProfile {
+            user,
+            display_name,
+            avatar,
+            bio,
+            favourite_club,
+            locale,
+        };
+
+        self.account_usecase.update_profile(command)
+            .await
+            .map_err(to_status)
+            .map(|_|
+                Response::new(api::UpdateMyProfileResponse {})
+            )
+    }
+
+    // - identity
+    async fn offer_identity_link(&self, request: Request<api::OfferIdentityLinkRequest>) -> Result<Response<api::OfferIdentityLinkResponse>, Status> {
+        let account = self.current_user(request.metadata()).await?;
+
+        let command = OfferIdentityLink {
+            account,
+        };
+
+        self.account_usecase.offer_identity_link(command)
+            .await
+            .map_err(to_status)
+            .map(|link|
+                Response::new(api::OfferIdentityLinkResponse {
+                    code: link.code.to_string(),
+                    expires: link.expires.timestamp_millis() as u64,
+                })
+            )
+    }
+
+    async fn link_identity(&self, request: Request<api::LinkIdentityRequest>) -> Result<Response<api::LinkIdentityResponse>, Status> {
+        // note: the identity isn't resolved, that would register it as an account of its own
+        let identity = current_identity(request.metadata(), &self.identity_providers)?;
+        let payload = request.into_inner();
+        let code = LinkCode::parse(payload.code.as_str())
+            .map_err(|_| to_malformed_status("code"))?;
+
+        let command = LinkIdentity {
+            code,
+            identity,
+        };
+
+        self.account_usecase.link_identity(command)
+            .await
+            .map_err(to_status)
+            .map(|_|
+                Response::new(api::LinkIdentityResponse {})
+            )
+    }
+
+    async fn unlink_identity(&self, request: Request<api::UnlinkIdentityRequest>) -> Result<Response<api::UnlinkIdentityResponse>, Status> {
+        let account = self.current_user(request.metadata()).await?;
+        let payload = request.into_inner();
+        let identity = Identity::parse(payload.identity.as_str())
+            .map_err(|_| to_malformed_status("identity"))?;
+
+        let command = UnlinkIdentity {
+            account,
+            identity,
+        };
+
+        self.account_usecase.unlink_identity(command)
+            .await
+            .map_err(to_status)
+            .map(|_|
+                Response::new(api::UnlinkIdentityResponse {})
+            )
+    }
+
     // - club
     async fn new_club(&self, request: Request<api::NewClubRequest>) -> Result<Response<api::NewClubResponse>, Status> {
         let payload = request.into_inner();
@@ -265,10 +902,439 @@ impl ApiV1 for ApiService {
             )
     }
 
-    // - community
-    async fn new_community(&self, request: Request<api::NewCommunityRequest>) -> Result<Response<api::NewCommunityResponse>, Status> {
+    // - squad
+    async fn add_player(&self, request: Request<api::AddPlayerRequest>) -> Result<Response<api::AddPlayerResponse>, Status> {
+        let staff_member = self.current_user(request.metadata()).await?;
         let payload = request.into_inner();
-        let name = CommunityName::parse(payload.name.as_str())
+        let team = TeamId::parse(payload.team_id.as_str())
+            .map_err(|_| to_malformed_status("team_id"))?;
+        let name = PlayerName::parse(payload.name.as_str())
+            .map_err(|_| to_malformed_status("name"))?;
+        let user = parse_optional(payload.user_id, UserId::parse)
+            .map_err(|_| to_malformed_status("user_id"))?;
+        let shirt_number = parse_shirt_number(payload.shirt_number)
+            .map_err(|_| to_malformed_status("shirt_number"))?;
+        let position = parse_player_position(payload.position)
+            .ok_or(to_malformed_status("position"))?;
+
+        let command = crate::domain::team::commands::AddPlayer {
+            team,
+            name,
+            user,
+            shirt_number,
+            position,
+            staff_member,
+        };
+
+        self.team_usecase.add_player(command)
+            .await
+            .map_err(to_status)
+            .map(|result|
+                Response::new(api::AddPlayerResponse {
+                    id: result.id.to_string(),
+                })
+            )
+    }
+
+    async fn transfer_player(&self, request: Request<api::TransferPlayerRequest>) -> Result<Response<api::TransferPlayerResponse>, Status> {
+        let staff_member = self.current_user(request.metadata()).await?;
+        let payload = request.into_inner();
+        let player = PlayerId::parse(payload.player_id.as_str())
+            .map_err(|_| to_malformed_status("player_id"))?;
+        let to = TeamId::parse(payload.team_id.as_str())
+            .map_err(|_| to_malformed_status("team_id"))?;
+        let shirt_number = parse_shirt_number(payload.shirt_number)
+            .map_err(|_| to_malformed_status("shirt_number"))?;
+
+        let command = crate::domain::team::commands::TransferPlayer {
+            player,
+            to,
+            shirt_number,
+            staff_member,
+        };
+
+        self.team_usecase.transfer_player(command)
+            .await
+            .map_err(to_status)
+            .map(|_| Response::new(api::TransferPlayerResponse {}))
+    }
+
+    async fn release_player(&self, request: Request<api::ReleasePlayerRequest>) -> Result<Response<api::ReleasePlayerResponse>, Status> {
+        let staff_member = self.current_user(request.metadata()).await?;
+        let payload = request.into_inner();
+        let player = PlayerId::parse(payload.player_id.as_str())
+            .map_err(|_| to_malformed_status("player_id"))?;
+
+        let command = crate::domain::team::commands::ReleasePlayer {
+            player,
+            staff_member,
+        };
+
+        self.team_usecase.release_player(command)
+            .await
+            .map_err(to_status)
+            .map(|_| Response::new(api::ReleasePlayerResponse {}))
+    }
+
+    async fn set_player_status(&self, request: Request<api::SetPlayerStatusRequest>) -> Result<Response<api::SetPlayerStatusResponse>, Status> {
+        let staff_member = self.current_user(request.metadata()).await?;
+        let payload = request.into_inner();
+        let player = PlayerId::parse(payload.player_id.as_str())
+            .map_err(|_| to_malformed_status("player_id"))?;
+        let status = parse_player_status(payload.status)
+            .ok_or(to_malformed_status("status"))?;
+
+        let command = crate::domain::team::commands::SetPlayerStatus {
+            player,
+            status,
+            staff_member,
+        };
+
+        self.team_usecase.set_player_status(command)
+            .await
+            .map_err(to_status)
+            .map(|_| Response::new(api::SetPlayerStatusResponse {}))
+    }
+
+    // - calendar
+    async fn schedule_team_event(&self, request: Request<api::ScheduleTeamEventRequest>) -> Result<Response<api::ScheduleTeamEventResponse>, Status> {
+        let staff_member = self.current_user(request.metadata()).await?;
+        let payload = request.into_inner();
+        let team = TeamId::parse(payload.team_id.as_str())
+            .map_err(|_| to_malformed_status("team_id"))?;
+        let kind = parse_team_event_kind(payload.kind)
+            .ok_or(to_malformed_status("kind"))?;
+        let title = EventTitle::parse(payload.title.as_str())
+            .map_err(|_| to_malformed_status("title"))?;
+        let start = parse_timestamp(payload.start)
+            .map_err(|_| to_malformed_status("start"))?;
+        let end = parse_timestamp(payload.end)
+            .map_err(|_| to_malformed_status("end"))?;
+        let location = parse_optional(payload.location, Location::parse)
+            .map_err(|_| to_malformed_status("location"))?;
+        let recurrence = match payload.recurrence {
+            Some(recurrence) => Some(parse_recurrence(recurrence)
+                .map_err(|_| to_malformed_status("recurrence"))?),
+            None => None,
+        };
+
+        let command = ScheduleEvent {
+            team,
+            kind,
+            title,
+            start,
+            end,
+            location,
+            recurrence,
+            staff_member,
+        };
+
+        self.calendar_usecase.schedule_event(command)
+            .await
+            .map_err(to_status)
+            .map(|result|
+                Response::new(api::ScheduleTeamEventResponse {
+                    id: result.id.to_string(),
+                })
+            )
+    }
+
+    async fn cancel_team_event(&self, request: Request<api::CancelTeamEventRequest>) -> Result<Response<api::CancelTeamEventResponse>, Status> {
+        let staff_member = self.current_user(request.metadata()).await?;
+        let payload = request.into_inner();
+        let id = TeamEventId::parse(payload.event_id.as_str())
+            .map_err(|_| to_malformed_status("event_id"))?;
+        let occurrence = parse_optional_timestamp(payload.occurrence)
+            .map_err(|_| to_malformed_status("occurrence"))?;
+
+        let command = CancelEvent {
+            id,
+            occurrence,
+            staff_member,
+        };
+
+        self.calendar_usecase.cancel_event(command)
+            .await
+            .map_err(to_status)
+            .map(|_| Response::new(api::CancelTeamEventResponse {}))
+    }
+
+    async fn respond_to_team_event(&self, request: Request<api::RespondToTeamEventRequest>) -> Result<Response<api::RespondToTeamEventResponse>, Status> {
+        let user = self.current_user(request.metadata()).await?;
+        let payload = request.into_inner();
+        let id = TeamEventId::parse(payload.event_id.as_str())
+            .map_err(|_| to_malformed_status("event_id"))?;
+        let occurrence = parse_timestamp(payload.occurrence)
+            .map_err(|_| to_malformed_status("occurrence"))?;
+        let response = parse_rsvp_response(payload.response)
+            .ok_or(to_malformed_status("response"))?;
+
+        let command = RespondToEvent {
+            id,
+            occurrence,
+            response,
+            user,
+        };
+
+        self.calendar_usecase.respond_to_event(command)
+            .await
+            .map_err(to_status)
+            .map(|_| Response::new(api::RespondToTeamEventResponse {}))
+    }
+
+    // - fixture
+    async fn schedule_match(&self, request: Request<api::ScheduleMatchRequest>) -> Result<Response<api::ScheduleMatchResponse>, Status> {
+        let staff_member = self.current_user(request.metadata()).await?;
+        let payload = request.into_inner();
+        let home = TeamId::parse(payload.home_team_id.as_str())
+            .map_err(|_| to_malformed_status("home_team_id"))?;
+        let away = TeamId::parse(payload.away_team_id.as_str())
+            .map_err(|_| to_malformed_status("away_team_id"))?;
+        let kickoff = parse_timestamp(payload.kickoff)
+            .map_err(|_| to_malformed_status("kickoff"))?;
+        let venue = parse_optional(payload.venue, Venue::parse)
+            .map_err(|_| to_malformed_status("venue"))?;
+        let season = parse_optional(payload.season_id, SeasonId::parse)
+            .map_err(|_| to_malformed_status("season_id"))?;
+
+        let command = ScheduleMatch {
+            home,
+            away,
+            kickoff,
+            venue,
+            season,
+            staff_member,
+        };
+
+        self.fixture_usecase.schedule_match(command)
+            .await
+            .map_err(to_status)
+            .map(|result|
+                Response::new(api::ScheduleMatchResponse {
+                    id: result.id.to_string(),
+                })
+            )
+    }
+
+    async fn reschedule_match(&self, request: Request<api::RescheduleMatchRequest>) -> Result<Response<api::RescheduleMatchResponse>, Status> {
+        let staff_member = self.current_user(request.metadata()).await?;
+        let payload = request.into_inner();
+        let id = MatchId::parse(payload.match_id.as_str())
+            .map_err(|_| to_malformed_status("match_id"))?;
+        let kickoff = parse_timestamp(payload.kickoff)
+            .map_err(|_| to_malformed_status("kickoff"))?;
+        let venue = parse_optional(payload.venue, Venue::parse)
+            .map_err(|_| to_malformed_status("venue"))?;
+
+        let command = RescheduleMatch {
+            id,
+            kickoff,
+            venue,
+            staff_member,
+        };
+
+        self.fixture_usecase.reschedule_match(command)
+            .await
+            .map_err(to_status)
+            .map(|_|
+                Response::new(api::RescheduleMatchResponse {})
+            )
+    }
+
+    async fn postpone_match(&self, request: Request<api::PostponeMatchRequest>) -> Result<Response<api::PostponeMatchResponse>, Status> {
+        let staff_member = self.current_user(request.metadata()).await?;
+        let payload = request.into_inner();
+        let id = MatchId::parse(payload.match_id.as_str())
+            .map_err(|_| to_malformed_status("match_id"))?;
+
+        let command = PostponeMatch {
+            id,
+            staff_member,
+        };
+
+        self.fixture_usecase.postpone_match(command)
+            .await
+            .map_err(to_status)
+            .map(|_|
+                Response::new(api::PostponeMatchResponse {})
+            )
+    }
+
+    async fn update_match_score(&self, request: Request<api::UpdateMatchScoreRequest>) -> Result<Response<api::UpdateMatchScoreResponse>, Status> {
+        let staff_member = self.current_user(request.metadata()).await?;
+        let payload = request.into_inner();
+        let id = MatchId::parse(payload.match_id.as_str())
+            .map_err(|_| to_malformed_status("match_id"))?;
+        let score = payload.score
+            .map(parse_score)
+            .ok_or(to_malformed_status("score"))?;
+
+        let command = UpdateScore {
+            id,
+            score,
+            staff_member,
+        };
+
+        self.fixture_usecase.update_score(command)
+            .await
+            .map_err(to_status)
+            .map(|_|
+                Response::new(api::UpdateMatchScoreResponse {})
+            )
+    }
+
+    async fn finalise_match_result(&self, request: Request<api::FinaliseMatchResultRequest>) -> Result<Response<api::FinaliseMatchResultResponse>, Status> {
+        let staff_member = self.current_user(request.metadata()).await?;
+        let payload = request.into_inner();
+        let id = MatchId::parse(payload.match_id.as_str())
+            .map_err(|_| to_malformed_status("match_id"))?;
+        let score = payload.score
+            .map(parse_score)
+            .ok_or(to_malformed_status("score"))?;
+
+        let command = FinaliseResult {
+            id,
+            score,
+            staff_member,
+        };
+
+        self.fixture_usecase.finalise_result(command)
+            .await
+            .map_err(to_status)
+            .map(|_|
+                Response::new(api::FinaliseMatchResultResponse {})
+            )
+    }
+
+    async fn record_match_incident(&self, request: Request<api::RecordMatchIncidentRequest>) -> Result<Response<api::RecordMatchIncidentResponse>, Status> {
+        let staff_member = self.current_user(request.metadata()).await?;
+        let payload = request.into_inner();
+        let id = MatchId::parse(payload.match_id.as_str())
+            .map_err(|_| to_malformed_status("match_id"))?;
+        let incident = payload.incident
+            .ok_or(to_malformed_status("incident"))?;
+        let kind = incident.kind
+            .and_then(|kind| parse_match_incident_kind(kind).ok())
+            .ok_or(to_malformed_status("incident"))?;
+
+        let command = RecordIncident {
+            id,
+            kind,
+            minute: incident.minute,
+            post: payload.post,
+            staff_member,
+        };
+
+        self.fixture_usecase.record_incident(command)
+            .await
+            .map_err(to_status)
+            .map(|_|
+                Response::new(api::RecordMatchIncidentResponse {})
+            )
+    }
+
+    // - competition
+    async fn new_competition(&self, request: Request<api::NewCompetitionRequest>) -> Result<Response<api::NewCompetitionResponse>, Status> {
+        let organiser = self.current_user(request.metadata()).await?;
+        let payload = request.into_inner();
+        let name = CompetitionName::parse(payload.name.as_str())
+            .map_err(|_| to_malformed_status("name"))?;
+
+        let command = crate::domain::competition::commands::New {
+            name,
+            organiser,
+        };
+
+        self.competition_usecase.new(command)
+            .await
+            .map_err(to_status)
+            .map(|result|
+                Response::new(api::NewCompetitionResponse {
+                    id: result.id.to_string(),
+                })
+            )
+    }
+
+    async fn new_season(&self, request: Request<api::NewSeasonRequest>) -> Result<Response<api::NewSeasonResponse>, Status> {
+        let organiser = self.current_user(request.metadata()).await?;
+        let payload = request.into_inner();
+        let competition = CompetitionId::parse(payload.competition_id.as_str())
+            .map_err(|_| to_malformed_status("competition_id"))?;
+        let name = SeasonName::parse(payload.name.as_str())
+            .map_err(|_| to_malformed_status("name"))?;
+        let points = match payload.points {
+            Some(points) => parse_points_rules(points)
+                .map_err(|_| to_malformed_status("points"))?,
+            None => PointsRules::default(),
+        };
+        let tie_breakers = payload.tie_breakers
+            .into_iter()
+            .map(parse_tie_breaker)
+            .collect::<Option<Vec<TieBreaker>>>()
+            .ok_or(to_malformed_status("tie_breakers"))?;
+
+        let command = NewSeason {
+            competition,
+            name,
+            points,
+            tie_breakers,
+            organiser,
+        };
+
+        self.competition_usecase.new_season(command)
+            .await
+            .map_err(to_status)
+            .map(|result|
+                Response::new(api::NewSeasonResponse {
+                    id: result.id.to_string(),
+                })
+            )
+    }
+
+    async fn add_team_to_season(&self, request: Request<api::AddTeamToSeasonRequest>) -> Result<Response<api::AddTeamToSeasonResponse>, Status> {
+        let organiser = self.current_user(request.metadata()).await?;
+        let payload = request.into_inner();
+        let season = SeasonId::parse(payload.season_id.as_str())
+            .map_err(|_| to_malformed_status("season_id"))?;
+        let team = TeamId::parse(payload.team_id.as_str())
+            .map_err(|_| to_malformed_status("team_id"))?;
+
+        let command = AddTeamToSeason {
+            season,
+            team,
+            organiser,
+        };
+
+        self.competition_usecase.add_team_to_season(command)
+            .await
+            .map_err(to_status)
+            .map(|_| Response::new(api::AddTeamToSeasonResponse {}))
+    }
+
+    async fn remove_team_from_season(&self, request: Request<api::RemoveTeamFromSeasonRequest>) -> Result<Response<api::RemoveTeamFromSeasonResponse>, Status> {
+        let organiser = self.current_user(request.metadata()).await?;
+        let payload = request.into_inner();
+        let season = SeasonId::parse(payload.season_id.as_str())
+            .map_err(|_| to_malformed_status("season_id"))?;
+        let team = TeamId::parse(payload.team_id.as_str())
+            .map_err(|_| to_malformed_status("team_id"))?;
+
+        let command = RemoveTeamFromSeason {
+            season,
+            team,
+            organiser,
+        };
+
+        self.competition_usecase.remove_team_from_season(command)
+            .await
+            .map_err(to_status)
+            .map(|_| Response::new(api::RemoveTeamFromSeasonResponse {}))
+    }
+
+    // - community
+    async fn new_community(&self, request: Request<api::NewCommunityRequest>) -> Result<Response<api::NewCommunityResponse>, Status> {
+        let payload = request.into_inner();
+        let name = CommunityName::parse(payload.name.as_str())
             .map_err(|_| to_malformed_status("name"))?;
         let context = match payload.context {
             Some(api::new_community_request::Context::ClubId(id)) => {
@@ -283,6 +1349,12 @@ impl ApiV1 for ApiService {
 
                 Ok(CommunityContext::Team(team))
             },
+            Some(api::new_community_request::Context::CompetitionId(id)) => {
+                let competition = CompetitionId::parse(id.as_str())
+                    .map_err(|_| to_malformed_status("context"))?;
+
+                Ok(CommunityContext::Competition(competition))
+            },
             _ =>
               Err(to_malformed_status("context"))
         }?;
@@ -363,7 +1435,7 @@ impl ApiV1 for ApiService {
     }
 
     async fn join_community(&self, request: Request<api::JoinCommunityRequest>) -> Result<Response<api::JoinCommunityResponse>, Status> {
-        let person = current_user(request.metadata())?;
+        let person = self.current_user(request.metadata()).await?;
         let payload = request.into_inner();
         let community = CommunityId::parse(payload.community_id.as_str())
             .map_err(|_| to_malformed_status("community_id"))?;
@@ -382,7 +1454,7 @@ impl ApiV1 for ApiService {
     }
 
     async fn leave_community(&self, request: Request<api::LeaveCommunityRequest>) -> Result<Response<api::LeaveCommunityResponse>, Status> {
-        let member = current_user(request.metadata())?;
+        let member = self.current_user(request.metadata()).await?;
         let payload = request.into_inner();
         let community = CommunityId::parse(payload.community_id.as_str())
             .map_err(|_| to_malformed_status("community_id"))?;
@@ -400,139 +1472,978 @@ impl ApiV1 for ApiService {
             )
     }
 
-    // - post
-    async fn publish_post(&self, request: Request<api::PublishPostRequest>) -> Result<Response<api::PublishPostResponse>, Status> {
-        let author = current_user(request.metadata())?;
+    async fn decline_community_offer(&self, request: Request<api::DeclineCommunityOfferRequest>) -> Result<Response<api::DeclineCommunityOfferResponse>, Status> {
+        let person = self.current_user(request.metadata()).await?;
         let payload = request.into_inner();
-        let text = PostText::parse(payload.text.as_str())
-            .map_err(|_| to_malformed_status("text"))?;
         let community = CommunityId::parse(payload.community_id.as_str())
             .map_err(|_| to_malformed_status("community_id"))?;
-        let attachment_elements: Result<Vec<PostAttachment>, String> = payload.attachments
-            .into_iter().map(parse_attachment).collect();
-        let attachments = PostAttachments::from_vec(attachment_elements
-            .map_err(|_| to_malformed_status("attachments")) ?);
 
-        let command = domain::social::commands::post::PublishPost {
+        let command = domain::social::commands::community::DeclineOffer {
             community,
-            text,
-            attachments,
-            author
+            person,
         };
 
-        self.social_usecase.publish_post(command)
+        self.social_usecase.decline_offer(command)
             .await
             .map_err(to_status)
-            .map(|result|
-                Response::new(api::PublishPostResponse {
-                    id: result.id.to_string(),
-                })
+            .map(|_|
+                Response::new(api::DeclineCommunityOfferResponse {})
             )
     }
 
-    async fn remove_post(&self, request: Request<api::RemovePostRequest>) -> Result<Response<api::RemovePostResponse>, Status> {
+    async fn remove_community_member(&self, request: Request<api::RemoveCommunityMemberRequest>) -> Result<Response<api::RemoveCommunityMemberResponse>, Status> {
+        let moderator = self.current_user(request.metadata()).await?;
         let payload = request.into_inner();
-        let post = PostId::parse(payload.post_id.as_str())
-            .map_err(|_| to_malformed_status("post_id"))?;
+        let community = CommunityId::parse(payload.community_id.as_str())
+            .map_err(|_| to_malformed_status("community_id"))?;
+        let member = UserId::parse(payload.member_id.as_str())
+            .map_err(|_| to_malformed_status("member_id"))?;
 
-        let command = domain::social::commands::post::RemovePost {
-            post,
+        let command = domain::social::commands::community::RemoveMember {
+            community,
+            member,
+            moderator,
         };
 
-        self.social_usecase.remove_post(command)
+        self.social_usecase.remove_member(command)
             .await
             .map_err(to_status)
             .map(|_|
-                Response::new(api::RemovePostResponse {})
+                Response::new(api::RemoveCommunityMemberResponse {})
             )
     }
 
-    // - post reaction
-    async fn react_to_post(&self, request: Request<api::ReactToPostRequest>) -> Result<Response<api::ReactToPostResponse>, Status> {
-        let author = current_user(request.metadata())?;
+    async fn ban_user_from_community(&self, request: Request<api::BanUserFromCommunityRequest>) -> Result<Response<api::BanUserFromCommunityResponse>, Status> {
+        let moderator = self.current_user(request.metadata()).await?;
         let payload = request.into_inner();
-        let post = PostId::parse(payload.post_id.as_str())
-            .map_err(|_| to_malformed_status("post_id"))?;
-        let reaction = parse_post_reaction(payload.emotion, post, author)
-            .ok_or(to_malformed_status("emotion"))?;
-
-        let command = domain::social::commands::post_reaction::ReactToPost {
-            reaction,
+        let community = CommunityId::parse(payload.community_id.as_str())
+            .map_err(|_| to_malformed_status("community_id"))?;
+        let user = UserId::parse(payload.user_id.as_str())
+            .map_err(|_| to_malformed_status("user_id"))?;
+        let reason = ModerationReason::parse(payload.reason.as_str())
+            .map_err(|_| to_malformed_status("reason"))?;
+        let expires = parse_optional_timestamp(payload.expires)
+            .map_err(|_| to_malformed_status("expires"))?;
+
+        let command = domain::social::commands::community::BanUser {
+            community,
+            user,
+            reason,
+            expires,
+            moderator,
         };
 
-        self.social_usecase.react_to_post(command)
+        self.social_usecase.ban_user(command)
             .await
             .map_err(to_status)
             .map(|_|
-                Response::new(api::ReactToPostResponse{})
+                Response::new(api::BanUserFromCommunityResponse {})
             )
     }
 
-    async fn retract_post_reaction(&self, request: Request<api::RetractPostReactionRequest>) -> Result<Response<api::RetractPostReactionResponse>, Status> {
-        let author = current_user(request.metadata())?;
+    async fn unban_user_from_community(&self, request: Request<api::UnbanUserFromCommunityRequest>) -> Result<Response<api::UnbanUserFromCommunityResponse>, Status> {
+        let moderator = self.current_user(request.metadata()).await?;
         let payload = request.into_inner();
-        let post = PostId::parse(payload.post_id.as_str())
-            .map_err(|_| to_malformed_status("post_id"))?;
-        let reaction = parse_post_reaction(payload.emotion, post, author)
-            .ok_or(to_malformed_status("emotion"))?;
+        let community = CommunityId::parse(payload.community_id.as_str())
+            .map_err(|_| to_malformed_status("community_id"))?;
+        let user = UserId::parse(payload.user_id.as_str())
+            .map_err(|_| to_malformed_status("user_id"))?;
 
-        let command = domain::social::commands::post_reaction::RetractPostReaction {
-            reaction,
+        let command = domain::social::commands::community::UnbanUser {
+            community,
+            user,
+            moderator,
         };
 
-        self.social_usecase.retract_postreaction(command)
+        self.social_usecase.unban_user(command)
             .await
             .map_err(to_status)
             .map(|_|
-                Response::new(api::RetractPostReactionResponse{})
+                Response::new(api::UnbanUserFromCommunityResponse {})
             )
     }
 
-    // - comment
-    async fn publish_comment(&self, request: Request<api::PublishCommentRequest>) -> Result<Response<api::PublishCommentResponse>, Status> {
-        let author = current_user(request.metadata())?;
+    async fn mute_community_member(&self, request: Request<api::MuteCommunityMemberRequest>) -> Result<Response<api::MuteCommunityMemberResponse>, Status> {
+        let moderator = self.current_user(request.metadata()).await?;
         let payload = request.into_inner();
-        let reply_to = PostId::parse(payload.reply_to_id.as_str())
-            .map_err(|_| to_malformed_status("reply_to_id"))?;
-        let text = CommentText::parse(payload.text.as_str())
-            .map_err(|_| to_malformed_status("text"))?;
+        let community = CommunityId::parse(payload.community_id.as_str())
+            .map_err(|_| to_malformed_status("community_id"))?;
+        let member = UserId::parse(payload.member_id.as_str())
+            .map_err(|_| to_malformed_status("member_id"))?;
+        let until = parse_timestamp(payload.until)
+            .map_err(|_| to_malformed_status("until"))?;
 
-        let command = domain::social::commands::comment::PublishComment {
-            reply_to,
+        let command = domain::social::commands::community::MuteMember {
+            community,
+            member,
+            until,
+            moderator,
+        };
+
+        self.social_usecase.mute_member(command)
+            .await
+            .map_err(to_status)
+            .map(|_|
+                Response::new(api::MuteCommunityMemberResponse {})
+            )
+    }
+
+    async fn set_community_word_list(&self, request: Request<api::SetCommunityWordListRequest>) -> Result<Response<api::SetCommunityWordListResponse>, Status> {
+        let editor = self.current_user(request.metadata()).await?;
+        let payload = request.into_inner();
+        let community = CommunityId::parse(payload.community_id.as_str())
+            .map_err(|_| to_malformed_status("community_id"))?;
+        let language = Language::parse(payload.language.as_str())
+            .map_err(|_| to_malformed_status("language"))?;
+        let action = parse_word_list_action(payload.action)
+            .ok_or(to_malformed_status("action"))?;
+
+        let command = domain::social::commands::word_list::SetWordList {
+            community,
+            language,
+            action,
+            words: payload.words,
+            editor,
+        };
+
+        self.social_usecase.set_word_list(command)
+            .await
+            .map_err(to_status)
+            .map(|_|
+                Response::new(api::SetCommunityWordListResponse {})
+            )
+    }
+
+    async fn set_community_reactions(&self, request: Request<api::SetCommunityReactionsRequest>) -> Result<Response<api::SetCommunityReactionsResponse>, Status> {
+        let editor = self.current_user(request.metadata()).await?;
+        let payload = request.into_inner();
+        let community = CommunityId::parse(payload.community_id.as_str())
+            .map_err(|_| to_malformed_status("community_id"))?;
+        let enabled = payload.enabled
+            .iter()
+            .map(|key| ReactionKey::parse(key.as_str()))
+            .collect::<Result<Vec<ReactionKey>, _>>()
+            .map_err(|_| to_malformed_status("enabled"))?;
+        let custom = payload.custom
+            .into_iter()
+            .map(parse_reaction_definition)
+            .collect::<Option<Vec<ReactionDefinition>>>()
+            .ok_or(to_malformed_status("custom"))?;
+
+        let command = domain::social::commands::community::SetReactions {
+            community,
+            enabled,
+            custom,
+            editor,
+        };
+
+        self.social_usecase.set_reactions(command)
+            .await
+            .map_err(to_status)
+            .map(|_|
+                Response::new(api::SetCommunityReactionsResponse {})
+            )
+    }
+
+    async fn list_community_reactions(&self, request: Request<api::ListCommunityReactionsRequest>) -> Result<Response<api::ListCommunityReactionsResponse>, Status> {
+        let payload = request.into_inner();
+        let community = CommunityId::parse(payload.community_id.as_str())
+            .map_err(|_| to_malformed_status("community_id"))?;
+
+        self.social_usecase.list_reactions(community)
+            .await
+            .map_err(to_status)
+            .map(|result|
+                Response::new(api::ListCommunityReactionsResponse {
+                    reactions: result.map(to_reaction_definition),
+                })
+            )
+    }
+
+    async fn list_reaction_catalog(&self, _request: Request<api::ListReactionCatalogRequest>) -> Result<Response<api::ListReactionCatalogResponse>, Status> {
+        Ok(Response::new(api::ListReactionCatalogResponse {
+            reactions: self.social_usecase.list_reaction_catalog().map(to_reaction_definition),
+        }))
+    }
+
+    // - post
+    async fn publish_post(&self, request: Request<api::PublishPostRequest>) -> Result<Response<api::PublishPostResponse>, Status> {
+        let author = self.current_user(request.metadata()).await?;
+        let payload = request.into_inner();
+        let text = PostText::parse(payload.text.as_str())
+            .map_err(|_| to_malformed_status("text"))?;
+        let community = CommunityId::parse(payload.community_id.as_str())
+            .map_err(|_| to_malformed_status("community_id"))?;
+        let attachment_elements: Result<Vec<PostAttachment>, String> = payload.attachments
+            .into_iter().map(parse_attachment).collect();
+        let attachments = PostAttachments::from_vec(attachment_elements
+            .map_err(|_| to_malformed_status("attachments")) ?);
+        let scheduled = parse_optional_timestamp(payload.scheduled)
+            .map_err(|_| to_malformed_status("scheduled"))?;
+
+        let command = domain::social::commands::post::PublishPost {
+            community,
+            text,
+            attachments,
+            author,
+            announcement: payload.announcement,
+            scheduled,
+        };
+
+        self.social_usecase.publish_post(command)
+            .await
+            .map_err(to_status)
+            .map(|result|
+                Response::new(api::PublishPostResponse {
+                    id: result.id.to_string(),
+                })
+            )
+    }
+
+    async fn edit_post(&self, request: Request<api::EditPostRequest>) -> Result<Response<api::EditPostResponse>, Status> {
+        let editor = self.current_user(request.metadata()).await?;
+        let payload = request.into_inner();
+        let post = PostId::parse(payload.post_id.as_str())
+            .map_err(|_| to_malformed_status("post_id"))?;
+        let text = PostText::parse(payload.text.as_str())
+            .map_err(|_| to_malformed_status("text"))?;
+
+        let command = domain::social::commands::post::EditPost {
+            post,
+            text,
+            editor,
+        };
+
+        self.social_usecase.edit_post(command)
+            .await
+            .map_err(to_status)
+            .map(|_|
+                Response::new(api::EditPostResponse {})
+            )
+    }
+
+    async fn remove_post(&self, request: Request<api::RemovePostRequest>) -> Result<Response<api::RemovePostResponse>, Status> {
+        let remover = self.current_user(request.metadata()).await?;
+        let payload = request.into_inner();
+        let post = PostId::parse(payload.post_id.as_str())
+            .map_err(|_| to_malformed_status("post_id"))?;
+        let reason = parse_optional(payload.reason, ModerationReason::parse)
+            .map_err(|_| to_malformed_status("reason"))?;
+
+        let command = domain::social::commands::post::RemovePost {
+            post,
+            reason,
+            remover,
+        };
+
+        self.social_usecase.remove_post(command)
+            .await
+            .map_err(to_status)
+            .map(|_|
+                Response::new(api::RemovePostResponse {})
+            )
+    }
+
+    // - draft
+    async fn save_draft(&self, request: Request<api::SaveDraftRequest>) -> Result<Response<api::SaveDraftResponse>, Status> {
+        let author = self.current_user(request.metadata()).await?;
+        let payload = request.into_inner();
+        let text = PostText::parse(payload.text.as_str())
+            .map_err(|_| to_malformed_status("text"))?;
+        let community = CommunityId::parse(payload.community_id.as_str())
+            .map_err(|_| to_malformed_status("community_id"))?;
+        let attachment_elements: Result<Vec<PostAttachment>, String> = payload.attachments
+            .into_iter().map(parse_attachment).collect();
+        let attachments = PostAttachments::from_vec(attachment_elements
+            .map_err(|_| to_malformed_status("attachments")) ?);
+
+        let command = domain::social::commands::post::SaveDraft {
+            community,
+            text,
+            attachments,
+            author,
+        };
+
+        self.social_usecase.save_draft(command)
+            .await
+            .map_err(to_status)
+            .map(|result|
+                Response::new(api::SaveDraftResponse {
+                    id: result.id.to_string(),
+                })
+            )
+    }
+
+    async fn edit_draft(&self, request: Request<api::EditDraftRequest>) -> Result<Response<api::EditDraftResponse>, Status> {
+        let author = self.current_user(request.metadata()).await?;
+        let payload = request.into_inner();
+        let post = PostId::parse(payload.post_id.as_str())
+            .map_err(|_| to_malformed_status("post_id"))?;
+        let text = PostText::parse(payload.text.as_str())
+            .map_err(|_| to_malformed_status("text"))?;
+        let attachment_elements: Result<Vec<PostAttachment>, String> = payload.attachments
+            .into_iter().map(parse_attachment).collect();
+        let attachments = PostAttachments::from_vec(attachment_elements
+            .map_err(|_| to_malformed_status("attachments")) ?);
+
+        let command = domain::social::commands::post::EditDraft {
+            post,
+            text,
+            attachments,
+            author,
+        };
+
+        self.social_usecase.edit_draft(command)
+            .await
+            .map_err(to_status)
+            .map(|_|
+                Response::new(api::EditDraftResponse {})
+            )
+    }
+
+    async fn publish_draft(&self, request: Request<api::PublishDraftRequest>) -> Result<Response<api::PublishDraftResponse>, Status> {
+        let author = self.current_user(request.metadata()).await?;
+        let payload = request.into_inner();
+        let post = PostId::parse(payload.post_id.as_str())
+            .map_err(|_| to_malformed_status("post_id"))?;
+        let scheduled = parse_optional_timestamp(payload.scheduled)
+            .map_err(|_| to_malformed_status("scheduled"))?;
+
+        let command = domain::social::commands::post::PublishDraft {
+            post,
+            announcement: payload.announcement,
+            scheduled,
+            author,
+        };
+
+        self.social_usecase.publish_draft(command)
+            .await
+            .map_err(to_status)
+            .map(|_|
+                Response::new(api::PublishDraftResponse {})
+            )
+    }
+
+    async fn pin_post(&self, request: Request<api::PinPostRequest>) -> Result<Response<api::PinPostResponse>, Status> {
+        let editor = self.current_user(request.metadata()).await?;
+        let payload = request.into_inner();
+        let post = PostId::parse(payload.post_id.as_str())
+            .map_err(|_| to_malformed_status("post_id"))?;
+
+        let command = domain::social::commands::post::PinPost {
+            post,
+            editor,
+        };
+
+        self.social_usecase.pin_post(command)
+            .await
+            .map_err(to_status)
+            .map(|_|
+                Response::new(api::PinPostResponse {})
+            )
+    }
+
+    async fn unpin_post(&self, request: Request<api::UnpinPostRequest>) -> Result<Response<api::UnpinPostResponse>, Status> {
+        let editor = self.current_user(request.metadata()).await?;
+        let payload = request.into_inner();
+        let post = PostId::parse(payload.post_id.as_str())
+            .map_err(|_| to_malformed_status("post_id"))?;
+
+        let command = domain::social::commands::post::UnpinPost {
+            post,
+            editor,
+        };
+
+        self.social_usecase.unpin_post(command)
+            .await
+            .map_err(to_status)
+            .map(|_|
+                Response::new(api::UnpinPostResponse {})
+            )
+    }
+
+    // - post reaction
+    async fn list_post_reactions(&self, request: Request<api::ListPostReactionsRequest>) -> Result<Response<api::ListPostReactionsResponse>, Status> {
+        let payload = request.into_inner();
+        let post = PostId::parse(payload.post_id.as_str())
+            .map_err(|_| to_malformed_status("post_id"))?;
+        // note: v1 clients don't know of reactions besides the emotions, so these are only listed on request
+        let reactions = match payload.filter {
+            Some(api::list_post_reactions_request::Filter::Emotion(emotion)) => {
+                parse_reaction(emotion, String::new())
+                    .ok_or(to_malformed_status("emotion"))
+                    .map(|reaction| vec![reaction])
+            },
+            Some(api::list_post_reactions_request::Filter::Reaction(reaction)) => {
+                ReactionKey::parse(reaction.as_str())
+                    .map_err(|_| to_malformed_status("reaction"))
+                    .map(|reaction| vec![reaction])
+            },
+            None if payload.include_custom =>
+                Ok(Vec::new()),
+            None =>
+                Ok(emotion_reactions()),
+        }?;
+        let after = parse_optional(payload.after, UserId::parse)
+            .map_err(|_| to_malformed_status("after"))?;
+
+        let reactions = self.social_usecase.list_post_reactions(post, reactions, after)
+            .await
+            .map_err(to_status)?;
+        let users = self.users(reactions.iter().map(|record| record.reaction.author.clone()).collect())
+            .await?;
+
+        Ok(Response::new(api::ListPostReactionsResponse {
+            reactions: reactions.map(to_reaction),
+            users,
+        }))
+    }
+
+    async fn react_to_post(&self, request: Request<api::ReactToPostRequest>) -> Result<Response<api::ReactToPostResponse>, Status> {
+        let author = self.current_user(request.metadata()).await?;
+        let payload = request.into_inner();
+        let post = PostId::parse(payload.post_id.as_str())
+            .map_err(|_| to_malformed_status("post_id"))?;
+        let reaction = parse_post_reaction(payload.emotion, payload.reaction, post, author)
+            .ok_or(to_malformed_status("reaction"))?;
+
+        let command = domain::social::commands::post_reaction::ReactToPost {
+            reaction,
+        };
+
+        self.social_usecase.react_to_post(command)
+            .await
+            .map_err(to_status)
+            .map(|_|
+                Response::new(api::ReactToPostResponse{})
+            )
+    }
+
+    async fn retract_post_reaction(&self, request: Request<api::RetractPostReactionRequest>) -> Result<Response<api::RetractPostReactionResponse>, Status> {
+        let author = self.current_user(request.metadata()).await?;
+        let payload = request.into_inner();
+        let post = PostId::parse(payload.post_id.as_str())
+            .map_err(|_| to_malformed_status("post_id"))?;
+        let reaction = parse_post_reaction(payload.emotion, payload.reaction, post, author)
+            .ok_or(to_malformed_status("reaction"))?;
+
+        let command = domain::social::commands::post_reaction::RetractPostReaction {
+            reaction,
+        };
+
+        self.social_usecase.retract_postreaction(command)
+            .await
+            .map_err(to_status)
+            .map(|_|
+                Response::new(api::RetractPostReactionResponse{})
+            )
+    }
+
+    // - poll
+    async fn vote_in_poll(&self, request: Request<api::VoteInPollRequest>) -> Result<Response<api::VoteInPollResponse>, Status> {
+        let voter = self.current_user(request.metadata()).await?;
+        let payload = request.into_inner();
+        let post = PostId::parse(payload.post_id.as_str())
+            .map_err(|_| to_malformed_status("post_id"))?;
+
+        let command = domain::social::commands::poll::VoteInPoll {
+            post,
+            choices: payload.choices,
+            voter,
+        };
+
+        self.social_usecase.vote_in_poll(command)
+            .await
+            .map_err(to_status)
+            .map(|_|
+                Response::new(api::VoteInPollResponse {})
+            )
+    }
+
+    async fn list_poll_votes(&self, request: Request<api::ListPollVotesRequest>) -> Result<Response<api::ListPollVotesResponse>, Status> {
+        let payload = request.into_inner();
+        let post = PostId::parse(payload.post_id.as_str())
+            .map_err(|_| to_malformed_status("post_id"))?;
+        let choice = payload.filter.map(|filter| match filter {
+            api::list_poll_votes_request::Filter::Choice(choice) => choice,
+        });
+        let after = parse_optional(payload.after, UserId::parse)
+            .map_err(|_| to_malformed_status("after"))?;
+
+        let votes = self.social_usecase.list_poll_votes(post, choice, after)
+            .await
+            .map_err(to_status)?;
+        let users = self.users(votes.iter().map(|vote| vote.voter.clone()).collect())
+            .await?;
+
+        Ok(Response::new(api::ListPollVotesResponse {
+            votes: votes.map(to_poll_vote),
+            users,
+        }))
+    }
+
+    // - comment reaction
+    async fn react_to_comment(&self, request: Request<api::ReactToCommentRequest>) -> Result<Response<api::ReactToCommentResponse>, Status> {
+        let author = self.current_user(request.metadata()).await?;
+        let payload = request.into_inner();
+        let comment = CommentId::parse(payload.comment_id.as_str())
+            .map_err(|_| to_malformed_status("comment_id"))?;
+        let reaction = parse_comment_reaction(payload.emotion, payload.reaction, comment, author)
+            .ok_or(to_malformed_status("reaction"))?;
+
+        let command = domain::social::commands::comment_reaction::ReactToComment {
+            reaction,
+        };
+
+        self.social_usecase.react_to_comment(command)
+            .await
+            .map_err(to_status)
+            .map(|_|
+                Response::new(api::ReactToCommentResponse{})
+            )
+    }
+
+    async fn retract_comment_reaction(&self, request: Request<api::RetractCommentReactionRequest>) -> Result<Response<api::RetractCommentReactionResponse>, Status> {
+        let author = self.current_user(request.metadata()).await?;
+        let payload = request.into_inner();
+        let comment = CommentId::parse(payload.comment_id.as_str())
+            .map_err(|_| to_malformed_status("comment_id"))?;
+        let reaction = parse_comment_reaction(payload.emotion, payload.reaction, comment, author)
+            .ok_or(to_malformed_status("reaction"))?;
+
+        let command = domain::social::commands::comment_reaction::RetractCommentReaction {
+            reaction,
+        };
+
+        self.social_usecase.retract_comment_reaction(command)
+            .await
+            .map_err(to_status)
+            .map(|_|
+                Response::new(api::RetractCommentReactionResponse{})
+            )
+    }
+
+    // - bookmark
+    async fn add_bookmark(&self, request: Request<api::AddBookmarkRequest>) -> Result<Response<api::AddBookmarkResponse>, Status> {
+        let owner = self.current_user(request.metadata()).await?;
+        let payload = request.into_inner();
+        let target = match payload.target {
+            Some(api::add_bookmark_request::Target::PostId(id)) => {
+                PostId::parse(id.as_str())
+                    .map_err(|_| to_malformed_status("post_id"))
+                    .map(BookmarkTarget::Post)
+            },
+            Some(api::add_bookmark_request::Target::CommentId(id)) => {
+                CommentId::parse(id.as_str())
+                    .map_err(|_| to_malformed_status("comment_id"))
+                    .map(BookmarkTarget::Comment)
+            },
+            _ =>
+                Err(to_malformed_status("target"))
+        }?;
+
+        let command = domain::social::commands::bookmark::AddBookmark {
+            target,
+            owner,
+        };
+
+        self.social_usecase.add_bookmark(command)
+            .await
+            .map_err(to_status)
+            .map(|_|
+                Response::new(api::AddBookmarkResponse{})
+            )
+    }
+
+    async fn remove_bookmark(&self, request: Request<api::RemoveBookmarkRequest>) -> Result<Response<api::RemoveBookmarkResponse>, Status> {
+        let owner = self.current_user(request.metadata()).await?;
+        let payload = request.into_inner();
+        let target = match payload.target {
+            Some(api::remove_bookmark_request::Target::PostId(id)) => {
+                PostId::parse(id.as_str())
+                    .map_err(|_| to_malformed_status("post_id"))
+                    .map(BookmarkTarget::Post)
+            },
+            Some(api::remove_bookmark_request::Target::CommentId(id)) => {
+                CommentId::parse(id.as_str())
+                    .map_err(|_| to_malformed_status("comment_id"))
+                    .map(BookmarkTarget::Comment)
+            },
+            _ =>
+                Err(to_malformed_status("target"))
+        }?;
+
+        let command = domain::social::commands::bookmark::RemoveBookmark {
+            target,
+            owner,
+        };
+
+        self.social_usecase.remove_bookmark(command)
+            .await
+            .map_err(to_status)
+            .map(|_|
+                Response::new(api::RemoveBookmarkResponse{})
+            )
+    }
+
+    // - block
+    async fn block_user(&self, request: Request<api::BlockUserRequest>) -> Result<Response<api::BlockUserResponse>, Status> {
+        let blocker = self.current_user(request.metadata()).await?;
+        let payload = request.into_inner();
+        let user = UserId::parse(payload.user_id.as_str())
+            .map_err(|_| to_malformed_status("user_id"))?;
+
+        let command = domain::social::commands::block::BlockUser {
+            blocker,
+            user,
+        };
+
+        self.social_usecase.block_user(command)
+            .await
+            .map_err(to_status)
+            .map(|_|
+                Response::new(api::BlockUserResponse {})
+            )
+    }
+
+    async fn unblock_user(&self, request: Request<api::UnblockUserRequest>) -> Result<Response<api::UnblockUserResponse>, Status> {
+        let blocker = self.current_user(request.metadata()).await?;
+        let payload = request.into_inner();
+        let user = UserId::parse(payload.user_id.as_str())
+            .map_err(|_| to_malformed_status("user_id"))?;
+
+        let command = domain::social::commands::block::UnblockUser {
+            blocker,
+            user,
+        };
+
+        self.social_usecase.unblock_user(command)
+            .await
+            .map_err(to_status)
+            .map(|_|
+                Response::new(api::UnblockUserResponse {})
+            )
+    }
+
+    // - follow
+    async fn follow_user(&self, request: Request<api::FollowUserRequest>) -> Result<Response<api::FollowUserResponse>, Status> {
+        let follower = self.current_user(request.metadata()).await?;
+        let payload = request.into_inner();
+        let followee = UserId::parse(payload.user_id.as_str())
+            .map_err(|_| to_malformed_status("user_id"))?;
+
+        let command = domain::social::commands::follow::FollowUser {
+            follower,
+            followee,
+        };
+
+        self.social_usecase.follow_user(command)
+            .await
+            .map_err(to_status)
+            .map(|_|
+                Response::new(api::FollowUserResponse {})
+            )
+    }
+
+    async fn unfollow_user(&self, request: Request<api::UnfollowUserRequest>) -> Result<Response<api::UnfollowUserResponse>, Status> {
+        let follower = self.current_user(request.metadata()).await?;
+        let payload = request.into_inner();
+        let followee = UserId::parse(payload.user_id.as_str())
+            .map_err(|_| to_malformed_status("user_id"))?;
+
+        let command = domain::social::commands::follow::UnfollowUser {
+            follower,
+            followee,
+        };
+
+        self.social_usecase.unfollow_user(command)
+            .await
+            .map_err(to_status)
+            .map(|_|
+                Response::new(api::UnfollowUserResponse {})
+            )
+    }
+
+    // - comment
+    async fn publish_comment(&self, request: Request<api::PublishCommentRequest>) -> Result<Response<api::PublishCommentResponse>, Status> {
+        let author = self.current_user(request.metadata()).await?;
+        let payload = request.into_inner();
+        let parent = match payload.parent {
+            Some(api::publish_comment_request::Parent::ReplyToId(id)) => {
+                PostId::parse(id.as_str())
+                    .map_err(|_| to_malformed_status("reply_to_id"))
+                    .map(CommentParent::Post)
+            },
+            Some(api::publish_comment_request::Parent::ReplyToCommentId(id)) => {
+                CommentId::parse(id.as_str())
+                    .map_err(|_| to_malformed_status("reply_to_comment_id"))
+                    .map(CommentParent::Comment)
+            },
+            _ =>
+                Err(to_malformed_status("parent"))
+        }?;
+        let text = CommentText::parse(payload.text.as_str())
+            .map_err(|_| to_malformed_status("text"))?;
+
+        let command = domain::social::commands::comment::PublishComment {
+            parent,
             text,
             author
         };
 
-        self.social_usecase.publish_comment(command)
-            .await
+        self.social_usecase.publish_comment(command)
+            .await
+            .map_err(to_status)
+            .map(|result|
+                Response::new(api::PublishCommentResponse {
+                    id: result.id.to_string(),
+                })
+            )
+    }
+
+    async fn edit_comment(&self, request: Request<api::EditCommentRequest>) -> Result<Response<api::EditCommentResponse>, Status> {
+        let editor = self.current_user(request.metadata()).await?;
+        let payload = request.into_inner();
+        let comment = CommentId::parse(payload.comment_id.as_str())
+            .map_err(|_| to_malformed_status("comment_id"))?;
+        let text = CommentText::parse(payload.text.as_str())
+            .map_err(|_| to_malformed_status("text"))?;
+
+        let command = domain::social::commands::comment::EditComment {
+            comment,
+            text,
+            editor,
+        };
+
+        self.social_usecase.edit_comment(command)
+            .await
+            .map_err(to_status)
+            .map(|_|
+                Response::new(api::EditCommentResponse {})
+            )
+    }
+
+    async fn remove_comment(&self, request: Request<api::RemoveCommentRequest>) -> Result<Response<api::RemoveCommentResponse>, Status> {
+        let remover = self.current_user(request.metadata()).await?;
+        let payload = request.into_inner();
+        let comment = CommentId::parse(payload.comment_id.as_str())
+            .map_err(|_| to_malformed_status("comment_id"))?;
+        let reason = parse_optional(payload.reason, ModerationReason::parse)
+            .map_err(|_| to_malformed_status("reason"))?;
+
+        let command = domain::social::commands::comment::RemoveComment {
+            comment,
+            reason,
+            remover,
+        };
+
+        self.social_usecase.remove_comment(command)
+            .await
+            .map_err(to_status)
+            .map(|_|
+                Response::new(api::RemoveCommentResponse {})
+            )
+    }
+
+    // - report
+    async fn report_post(&self, request: Request<api::ReportPostRequest>) -> Result<Response<api::ReportPostResponse>, Status> {
+        let reporter = self.current_user(request.metadata()).await?;
+        let payload = request.into_inner();
+        let post = PostId::parse(payload.post_id.as_str())
+            .map_err(|_| to_malformed_status("post_id"))?;
+        let category = parse_report_category(payload.category)
+            .ok_or(to_malformed_status("category"))?;
+        let text = ReportText::parse(payload.text.as_str())
+            .map_err(|_| to_malformed_status("text"))?;
+
+        let command = domain::social::commands::report::ReportPost {
+            post,
+            reporter,
+            category,
+            text,
+        };
+
+        self.social_usecase.report_post(command)
+            .await
+            .map_err(to_status)
+            .map(|result|
+                Response::new(api::ReportPostResponse {
+                    id: result.id.to_string(),
+                })
+            )
+    }
+
+    async fn report_comment(&self, request: Request<api::ReportCommentRequest>) -> Result<Response<api::ReportCommentResponse>, Status> {
+        let reporter = self.current_user(request.metadata()).await?;
+        let payload = request.into_inner();
+        let comment = CommentId::parse(payload.comment_id.as_str())
+            .map_err(|_| to_malformed_status("comment_id"))?;
+        let category = parse_report_category(payload.category)
+            .ok_or(to_malformed_status("category"))?;
+        let text = ReportText::parse(payload.text.as_str())
+            .map_err(|_| to_malformed_status("text"))?;
+
+        let command = domain::social::commands::report::ReportComment {
+            comment,
+            reporter,
+            category,
+            text,
+        };
+
+        self.social_usecase.report_comment(command)
+            .await
+            .map_err(to_status)
+            .map(|result|
+                Response::new(api::ReportCommentResponse {
+                    id: result.id.to_string(),
+                })
+            )
+    }
+
+    async fn dismiss_report(&self, request: Request<api::DismissReportRequest>) -> Result<Response<api::DismissReportResponse>, Status> {
+        let moderator = self.current_user(request.metadata()).await?;
+        let payload = request.into_inner();
+        let report = ReportId::parse(payload.report_id.as_str())
+            .map_err(|_| to_malformed_status("report_id"))?;
+
+        let command = domain::social::commands::report::DismissReport {
+            report,
+            moderator,
+        };
+
+        self.social_usecase.dismiss_report(command)
+            .await
+            .map_err(to_status)
+            .map(|_|
+                Response::new(api::DismissReportResponse {})
+            )
+    }
+
+    async fn remove_reported_content(&self, request: Request<api::RemoveReportedContentRequest>) -> Result<Response<api::RemoveReportedContentResponse>, Status> {
+        let moderator = self.current_user(request.metadata()).await?;
+        let payload = request.into_inner();
+        let report = ReportId::parse(payload.report_id.as_str())
+            .map_err(|_| to_malformed_status("report_id"))?;
+        let reason = parse_optional(payload.reason, ModerationReason::parse)
+            .map_err(|_| to_malformed_status("reason"))?;
+
+        let command = domain::social::commands::report::RemoveReportedContent {
+            report,
+            reason,
+            moderator,
+        };
+
+        self.social_usecase.remove_reported_content(command)
+            .await
+            .map_err(to_status)
+            .map(|_|
+                Response::new(api::RemoveReportedContentResponse {})
+            )
+    }
+
+    async fn ban_reported_author(&self, request: Request<api::BanReportedAuthorRequest>) -> Result<Response<api::BanReportedAuthorResponse>, Status> {
+        let moderator = self.current_user(request.metadata()).await?;
+        let payload = request.into_inner();
+        let report = ReportId::parse(payload.report_id.as_str())
+            .map_err(|_| to_malformed_status("report_id"))?;
+        let reason = ModerationReason::parse(payload.reason.as_str())
+            .map_err(|_| to_malformed_status("reason"))?;
+        let expires = parse_optional_timestamp(payload.expires)
+            .map_err(|_| to_malformed_status("expires"))?;
+
+        let command = domain::social::commands::report::BanReportedAuthor {
+            report,
+            reason,
+            expires,
+            moderator,
+        };
+
+        self.social_usecase.ban_reported_author(command)
+            .await
+            .map_err(to_status)
+            .map(|_|
+                Response::new(api::BanReportedAuthorResponse {})
+            )
+    }
+
+    // - notification
+    async fn mark_notifications_read(&self, request: Request<api::MarkNotificationsReadRequest>) -> Result<Response<api::MarkNotificationsReadResponse>, Status> {
+        let recipient = self.current_user(request.metadata()).await?;
+        let payload = request.into_inner();
+
+        let result = if payload.all {
+            let command = MarkAllNotificationsRead {
+                recipient,
+            };
+
+            self.notification_usecase.mark_all_notifications_read(command).await
+        } else {
+            let notifications = payload.notification_ids
+                .iter()
+                .map(|id| NotificationId::parse(id.as_str()))
+                .collect::<Result<Vec<NotificationId>, _>>()
+                .map_err(|_| to_malformed_status("notification_ids"))?;
+
+            let command = MarkNotificationsRead {
+                recipient,
+                notifications,
+            };
+
+            self.notification_usecase.mark_notifications_read(command).await
+        };
+
+        result
             .map_err(to_status)
-            .map(|result|
-                Response::new(api::PublishCommentResponse {
-                    id: result.id.to_string(),
-                })
+            .map(|_|
+                Response::new(api::MarkNotificationsReadResponse {})
             )
     }
 
-    async fn remove_comment(&self, request: Request<api::RemoveCommentRequest>) -> Result<Response<api::RemoveCommentResponse>, Status> {
+    async fn update_notification_preferences(&self, request: Request<api::UpdateNotificationPreferencesRequest>) -> Result<Response<api::UpdateNotificationPreferencesResponse>, Status> {
+        let user = self.current_user(request.metadata()).await?;
         let payload = request.into_inner();
-        let comment = CommentId::parse(payload.comment_id.as_str())
-            .map_err(|_| to_malformed_status("comment_id"))?;
-
-        let command = domain::social::commands::comment::RemoveComment {
-            comment,
+        let muted = payload.muted
+            .iter()
+            .map(|category| parse_notification_category(*category))
+            .collect::<Option<Vec<NotificationCategory>>>()
+            .ok_or_else(|| to_malformed_status("muted"))?;
+
+        let command = UpdateNotificationPreferences {
+            user,
+            muted,
         };
 
-        self.social_usecase.remove_comment(command)
+        self.notification_usecase.update_preferences(command)
             .await
             .map_err(to_status)
             .map(|_|
-                Response::new(api::RemoveCommentResponse {})
+                Response::new(api::UpdateNotificationPreferencesResponse {})
             )
     }
 }
 
+impl ApiService {
+    // account of the signed-in user, registered on first sign-in
+    async fn current_user(&self, metadata: &MetadataMap) -> Result<UserId, Status> {
+        let identity = current_identity(metadata, &self.identity_providers)?;
+
+        self.account_usecase.resolve_identity(identity)
+            .await
+            .map_err(to_status)
+    }
+
+    // profile summaries of the users referred to in a response
+    async fn users(&self, ids: Vec<UserId>) -> Result<Vec<api::User>, Status> {
+        self.account_usecase.summaries(ids)
+            .await
+            .map_err(to_status)
+            .map(|summaries| summaries.map(to_user))
+    }
+}
+
 // helpers
-fn current_user(metadata: &MetadataMap) -> Result<UserId, Status> {
+#[allow(clippy::result_large_err)]
+fn current_identity(metadata: &MetadataMap, providers: &IdentityProviders) -> Result<Identity, Status> {
     let error = || Status::unauthenticated("corrucpt authorization data");
 
     let header = metadata
@@ -540,14 +2451,21 @@ fn current_user(metadata: &MetadataMap) -> Result<UserId, Status> {
         .to_str().map_err(|_| error())?
         .strip_prefix("Bearer ").ok_or(error())?;
 
-    let token: Token<Header, RegisteredClaims, _> = Token::parse_unverified(header)
-        .map_err(|_| error())?;
+    parse_identity_token(header, providers).map_err(|_| error())
+}
+
+// note: only for the token of the authorization header, its signature is validated by the gateway
+fn parse_identity_token(token: &str, providers: &IdentityProviders) -> Result<Identity, String> {
+    let token: Token<Header, RegisteredClaims, _> = Token::parse_unverified(token)
+        .map_err(|_| "malformed token")?;
 
-    let subject = token.claims()
+    let claims = token.claims();
+    let subject = claims
         .subject.as_ref()
-        .ok_or(error())?;
+        .ok_or("missing subject")?;
 
-    UserId::parse(&subject).map_err(|_| error())
+    providers.identity(claims.issuer.as_deref(), subject)
+        .map_err(|_| String::from("malformed subject"))
 }
 
 // parse + to transfer objects
@@ -563,19 +2481,218 @@ fn parse_optional<T, E>(input: impl Into<String>, parse: fn(&str) -> Result<T, E
     }
 }
 
-fn parse_post_reaction(emotion: i32, post: PostId, author: UserId) -> Option<PostReaction> {
-    if emotion == api::Emotion::Love as i32 {
-        Some(PostReaction::Love(author, post))
-    } else if emotion == api::Emotion::Funny as i32 {
-        Some(PostReaction::Funny(author, post))
-    } else if emotion == api::Emotion::Celebrate as i32 {
-        Some(PostReaction::Celebrate(author, post))
-    } else if emotion == api::Emotion::Support as i32 {
-        Some(PostReaction::Support(author, post))
-    }  else if emotion == api::Emotion::Insightful as i32 {
-        Some(PostReaction::Insightful(author, post))
-    } else {
-        None
+fn parse_score(score: api::Score) -> Score {
+    Score::new(score.home, score.away)
+}
+
+fn parse_shirt_number(number: u32) -> Result<Option<ShirtNumber>, String> {
+    if number == 0 {
+        return Ok(None);
+    }
+
+    ShirtNumber::parse(number)
+        .map(Some)
+        .map_err(|_| String::from("malformed shirt number value"))
+}
+
+fn parse_player_position(position: i32) -> Option<PlayerPosition> {
+    match api::PlayerPosition::from_i32(position)? {
+        api::PlayerPosition::Goalkeeper => Some(PlayerPosition::Goalkeeper),
+        api::PlayerPosition::Defender => Some(PlayerPosition::Defender),
+        api::PlayerPosition::Midfielder => Some(PlayerPosition::Midfielder),
+        api::PlayerPosition::Forward => Some(PlayerPosition::Forward),
+    }
+}
+
+fn parse_player_status(status: i32) -> Option<PlayerStatus> {
+    match api::PlayerStatus::from_i32(status)? {
+        api::PlayerStatus::Active => Some(PlayerStatus::Active),
+        api::PlayerStatus::Injured => Some(PlayerStatus::Injured),
+        api::PlayerStatus::Loaned => Some(PlayerStatus::Loaned),
+    }
+}
+
+fn parse_team_event_kind(kind: i32) -> Option<TeamEventKind> {
+    match api::TeamEventKind::from_i32(kind)? {
+        api::TeamEventKind::Training => Some(TeamEventKind::Training),
+        api::TeamEventKind::Match => Some(TeamEventKind::Match),
+        api::TeamEventKind::Social => Some(TeamEventKind::Social),
+    }
+}
+
+fn parse_recurrence(recurrence: api::Recurrence) -> Result<Recurrence, String> {
+    let frequency = match api::Frequency::from_i32(recurrence.frequency) {
+        Some(api::Frequency::Weekly) => Frequency::Weekly,
+        Some(api::Frequency::Daily) => Frequency::Daily,
+        None => return Err(String::from("malformed (recurrence) frequency value")),
+    };
+    let until = parse_timestamp(recurrence.until)?;
+
+    Recurrence::new(frequency, recurrence.interval, until)
+        .ok_or(String::from("malformed (recurrence) interval value"))
+}
+
+// note: none is only valid in responses
+fn parse_rsvp_response(response: i32) -> Option<RsvpResponse> {
+    match api::RsvpResponse::from_i32(response)? {
+        api::RsvpResponse::None => None,
+        api::RsvpResponse::Attending => Some(RsvpResponse::Attending),
+        api::RsvpResponse::NotAttending => Some(RsvpResponse::NotAttending),
+        api::RsvpResponse::Maybe => Some(RsvpResponse::Maybe),
+    }
+}
+
+fn parse_points_rules(points: api::PointsRules) -> Result<PointsRules, String> {
+    let bonus = points.bonus
+        .into_iter()
+        .map(|rule| match rule.condition {
+            Some(api::bonus_rule::Condition::ScoredAtLeast(condition)) => Ok(BonusRule::ScoredAtLeast { goals: condition.goals, points: rule.points }),
+            Some(api::bonus_rule::Condition::LostByAtMost(condition)) => Ok(BonusRule::LostByAtMost { margin: condition.margin, points: rule.points }),
+            None => Err(String::from("malformed (bonus) condition value")),
+        })
+        .collect::<Result<Vec<BonusRule>, String>>()?;
+
+    Ok(PointsRules {
+        win: points.win,
+        draw: points.draw,
+        loss: points.loss,
+        bonus,
+    })
+}
+
+fn parse_tie_breaker(tie_breaker: i32) -> Option<TieBreaker> {
+    match api::TieBreaker::from_i32(tie_breaker)? {
+        api::TieBreaker::GoalDifference => Some(TieBreaker::GoalDifference),
+        api::TieBreaker::GoalsScored => Some(TieBreaker::GoalsScored),
+        api::TieBreaker::HeadToHead => Some(TieBreaker::HeadToHead),
+        api::TieBreaker::Wins => Some(TieBreaker::Wins),
+    }
+}
+
+fn parse_match_incident_kind(kind: api::match_incident::Kind) -> Result<MatchIncidentKind, String> {
+    let side = |side: i32| -> Result<Side, String> {
+        match api::Side::from_i32(side) {
+            Some(api::Side::Home) => Ok(Side::Home),
+            Some(api::Side::Away) => Ok(Side::Away),
+            None => Err(String::from("malformed (incident) side value")),
+        }
+    };
+    let player = |name: String| PlayerName::parse(name.as_str())
+        .map_err(|_| String::from("malformed (incident) player value"));
+
+    match kind {
+        api::match_incident::Kind::Goal(goal) => Ok(MatchIncidentKind::Goal {
+            side: side(goal.side)?,
+            scorer: parse_optional(goal.scorer, PlayerName::parse)
+                .map_err(|_| String::from("malformed (incident) scorer value"))?,
+        }),
+        api::match_incident::Kind::Card(card) => Ok(MatchIncidentKind::Card {
+            side: side(card.side)?,
+            player: player(card.player)?,
+            colour: match api::CardColour::from_i32(card.colour) {
+                Some(api::CardColour::Yellow) => CardColour::Yellow,
+                Some(api::CardColour::SecondYellow) => CardColour::SecondYellow,
+                Some(api::CardColour::Red) => CardColour::Red,
+                None => return Err(String::from("malformed (incident) colour value")),
+            },
+        }),
+        api::match_incident::Kind::Substitution(substitution) => Ok(MatchIncidentKind::Substitution {
+            side: side(substitution.side)?,
+            player_in: player(substitution.player_in)?,
+            player_out: player(substitution.player_out)?,
+        }),
+        api::match_incident::Kind::HalfTime(_) => Ok(MatchIncidentKind::HalfTime),
+        api::match_incident::Kind::FullTime(_) => Ok(MatchIncidentKind::FullTime),
+    }
+}
+
+fn parse_timestamp(millis: u64) -> Result<DateTime<Utc>, String> {
+    i64::try_from(millis).ok()
+        .and_then(DateTime::from_timestamp_millis)
+        .ok_or(String::from("malformed timestamp value"))
+}
+
+fn parse_optional_timestamp(millis: u64) -> Result<Option<DateTime<Utc>>, String> {
+    if millis == 0 {
+        return Ok(None);
+    }
+
+    parse_timestamp(millis).map(Some)
+}
+
+fn emotion_reactions() -> Vec<ReactionKey> {
+    [api::Emotion::Love, api::Emotion::Funny, api::Emotion::Celebrate, api::Emotion::Support, api::Emotion::Insightful]
+        .into_iter()
+        .filter_map(|emotion| parse_reaction(emotion as i32, String::new()))
+        .collect()
+}
+
+// note: a reaction key takes precedence over the emotion of v1 clients
+fn parse_reaction(emotion: i32, reaction: String) -> Option<ReactionKey> {
+    if !reaction.is_empty() {
+        return ReactionKey::parse(reaction.as_str()).ok();
+    }
+
+    let key = match api::Emotion::from_i32(emotion)? {
+        api::Emotion::Love => "love",
+        api::Emotion::Funny => "funny",
+        api::Emotion::Celebrate => "celebrate",
+        api::Emotion::Support => "support",
+        api::Emotion::Insightful => "insightful",
+        api::Emotion::Custom => return None,
+    };
+
+    ReactionKey::parse(key).ok()
+}
+
+fn parse_post_reaction(emotion: i32, reaction: String, post: PostId, author: UserId) -> Option<PostReaction> {
+    parse_reaction(emotion, reaction).map(|reaction| PostReaction { author, post, reaction })
+}
+
+fn parse_comment_reaction(emotion: i32, reaction: String, comment: CommentId, author: UserId) -> Option<CommentReaction> {
+    parse_reaction(emotion, reaction).map(|reaction| CommentReaction { author, comment, reaction })
+}
+
+fn parse_reaction_definition(definition: api::ReactionDefinition) -> Option<ReactionDefinition> {
+    Some(ReactionDefinition {
+        key: ReactionKey::parse(definition.key.as_str()).ok()?,
+        emoji: ReactionEmoji::parse(definition.emoji.as_str()).ok()?,
+    })
+}
+
+fn parse_report_category(category: i32) -> Option<ReportCategory> {
+    match api::ReportCategory::from_i32(category)? {
+        api::ReportCategory::Spam => Some(ReportCategory::Spam),
+        api::ReportCategory::Harassment => Some(ReportCategory::Harassment),
+        api::ReportCategory::HateSpeech => Some(ReportCategory::HateSpeech),
+        api::ReportCategory::Violence => Some(ReportCategory::Violence),
+        api::ReportCategory::Misinformation => Some(ReportCategory::Misinformation),
+        api::ReportCategory::Other => Some(ReportCategory::Other),
+    }
+}
+
+fn parse_notification_category(category: i32) -> Option<NotificationCategory> {
+    match api::NotificationCategory::from_i32(category)? {
+        api::NotificationCategory::Comments => Some(NotificationCategory::Comments),
+        api::NotificationCategory::Reactions => Some(NotificationCategory::Reactions),
+        api::NotificationCategory::Mentions => Some(NotificationCategory::Mentions),
+        api::NotificationCategory::Follows => Some(NotificationCategory::Follows),
+        api::NotificationCategory::Communities => Some(NotificationCategory::Communities),
+    }
+}
+
+fn parse_search_kind(kind: i32) -> Option<SearchKind> {
+    match api::SearchKind::from_i32(kind)? {
+        api::SearchKind::Community => Some(SearchKind::Community),
+        api::SearchKind::Post => Some(SearchKind::Post),
+        api::SearchKind::Comment => Some(SearchKind::Comment),
+    }
+}
+
+fn parse_word_list_action(action: i32) -> Option<WordListAction> {
+    match api::WordListAction::from_i32(action)? {
+        api::WordListAction::Flag => Some(WordListAction::Flag),
+        api::WordListAction::Reject => Some(WordListAction::Reject),
     }
 }
 
@@ -583,14 +2700,37 @@ fn parse_attachment(input: api::publish_post_request::Attachment) -> Result<Post
     match input.media  {
         Some(api::publish_post_request::attachment::Media::ImageId(id)) =>
           ImageId::parse(&id)
-              .map(|image| PostAttachment::Image(image))
+              .map(PostAttachment::Image)
               .map_err(|_| String::from("malformed (attachment) image_id value")),
 
+        Some(api::publish_post_request::attachment::Media::Poll(poll)) =>
+            parse_poll(poll)
+                .map(PostAttachment::Poll)
+                .ok_or(String::from("malformed (attachment) poll value")),
+
         _ =>
             Err(String::from("unsupported post attachment"))
     }
 }
 
+fn parse_poll(input: api::Poll) -> Option<Poll> {
+    let options = input.options
+        .iter()
+        .map(|option| PollOption::parse(option.as_str()).ok())
+        .collect::<Option<Vec<PollOption>>>()?;
+    let choice = match input.multiple {
+        true => PollChoice::Multiple,
+        false => PollChoice::Single,
+    };
+    let closes = parse_optional_timestamp(input.closes).ok()?;
+    let visibility = match input.public {
+        true => PollVisibility::Public,
+        false => PollVisibility::Anonymous,
+    };
+
+    Poll::new(options, choice, closes, visibility)
+}
+
 fn to_some_logo(logo: &Option<ImageId>) -> String {
     match logo {
         Some(image) => image.to_string(),
@@ -598,6 +2738,92 @@ fn to_some_logo(logo: &Option<ImageId>) -> String {
     }
 }
 
+fn is_after(moment: &DateTime<Utc>, other: &Option<DateTime<Utc>>) -> bool {
+    match other {
+        Some(other) => moment > other,
+        None => true,
+    }
+}
+
+fn to_some_timestamp(timestamp: &Option<DateTime<Utc>>) -> u64 {
+    match timestamp {
+        Some(value) => value.timestamp_millis() as u64,
+        None => 0,
+    }
+}
+
+fn to_profile(profile: &UserProfile) -> api::Profile {
+    api::Profile {
+        id: profile.id.to_string(),
+        display_name: profile.display_name.to_string(),
+        avatar_id: to_some_logo(&profile.avatar),
+        bio: profile.bio.as_ref().map(|bio| bio.to_string()).unwrap_or_default(),
+        favourite_club_id: profile.favourite_club.as_ref().map(|club| club.to_string()).unwrap_or_default(),
+        locale: profile.locale.as_ref().map(|locale| locale.to_string()).unwrap_or_default(),
+        updated: profile.updated.timestamp_millis() as u64,
+    }
+}
+
+fn to_identity(linked: &LinkedIdentity) -> api::Identity {
+    api::Identity {
+        identity: linked.identity.to_string(),
+        provider: to_identity_provider(&linked.identity.provider()) as i32,
+        subject: linked.identity.subject().to_string(),
+        linked: linked.linked.timestamp_millis() as u64,
+    }
+}
+
+fn to_identity_provider(provider: &IdentityProvider) -> api::IdentityProvider {
+    match provider {
+        IdentityProvider::Firebase => api::IdentityProvider::Firebase,
+        IdentityProvider::Oidc => api::IdentityProvider::Oidc,
+        IdentityProvider::Service => api::IdentityProvider::Service,
+    }
+}
+
+fn to_notification(notification: &Notification) -> api::Notification {
+    let (kind, post, comment, community) = match &notification.kind {
+        NotificationKind::PostCommented { post } => (api::NotificationKind::PostCommented, Some(post), None, None),
+        NotificationKind::CommentReplied { post, comment } => (api::NotificationKind::CommentReplied, Some(post), Some(comment), None),
+        NotificationKind::PostReacted { post } => (api::NotificationKind::PostReacted, Some(post), None, None),
+        NotificationKind::CommentReacted { post, comment } => (api::NotificationKind::CommentReacted, Some(post), Some(comment), None),
+        NotificationKind::Mentioned { post, comment } => (api::NotificationKind::Mentioned, Some(post), comment.as_ref(), None),
+        NotificationKind::Followed => (api::NotificationKind::Followed, None, None, None),
+        NotificationKind::PromotedToEditor { community } => (api::NotificationKind::PromotedToEditor, None, None, Some(community)),
+        NotificationKind::MembershipOffered { community } => (api::NotificationKind::MembershipOffered, None, None, Some(community)),
+    };
+
+    api::Notification {
+        id: notification.id.to_string(),
+        kind: kind as i32,
+        post_id: post.map(|id| id.to_string()).unwrap_or_default(),
+        comment_id: comment.map(|id| id.to_string()).unwrap_or_default(),
+        community_id: community.map(|id| id.to_string()).unwrap_or_default(),
+        actor_ids: notification.recent_actors().iter().map(|actor| actor.to_string()).collect(),
+        actors: notification.actors.len() as u32,
+        updated: notification.updated.timestamp_millis() as u64,
+        read: notification.read,
+    }
+}
+
+fn to_notification_category(category: &NotificationCategory) -> api::NotificationCategory {
+    match category {
+        NotificationCategory::Comments => api::NotificationCategory::Comments,
+        NotificationCategory::Reactions => api::NotificationCategory::Reactions,
+        NotificationCategory::Mentions => api::NotificationCategory::Mentions,
+        NotificationCategory::Follows => api::NotificationCategory::Follows,
+        NotificationCategory::Communities => api::NotificationCategory::Communities,
+    }
+}
+
+fn to_user(summary: &ProfileSummary) -> api::User {
+    api::User {
+        id: summary.id.to_string(),
+        display_name: summary.display_name.to_string(),
+        avatar_id: to_some_logo(&summary.avatar),
+    }
+}
+
 fn to_club(club: &Club) -> api::Club {
     api::Club {
         id: club.id.to_string(),
@@ -616,10 +2842,215 @@ fn to_team(team: &Team) -> api::Team {
     }
 }
 
+fn to_team_event(event: &TeamEvent) -> api::TeamEvent {
+    api::TeamEvent {
+        id: event.id.to_string(),
+        team_id: event.team.to_string(),
+        kind: to_team_event_kind(&event.kind) as i32,
+        title: event.title.to_string(),
+        start: event.start.timestamp_millis() as u64,
+        end: event.end.timestamp_millis() as u64,
+        location: event.location.as_ref().map(|location| location.to_string()).unwrap_or_default(),
+        recurrence: event.recurrence.as_ref().map(|recurrence| api::Recurrence {
+            frequency: match recurrence.frequency {
+                Frequency::Weekly => api::Frequency::Weekly,
+                Frequency::Daily => api::Frequency::Daily,
+            } as i32,
+            interval: recurrence.interval,
+            until: recurrence.until.timestamp_millis() as u64,
+        }),
+        cancelled: event.cancelled,
+        cancelled_occurrences: event.cancelled_occurrences.iter().map(|occurrence| occurrence.timestamp_millis() as u64).collect(),
+    }
+}
+
+fn to_team_event_kind(kind: &TeamEventKind) -> api::TeamEventKind {
+    match kind {
+        TeamEventKind::Training => api::TeamEventKind::Training,
+        TeamEventKind::Match => api::TeamEventKind::Match,
+        TeamEventKind::Social => api::TeamEventKind::Social,
+    }
+}
+
+fn to_availability(availability: &Availability) -> api::Availability {
+    api::Availability {
+        user_id: availability.user.to_string(),
+        player_id: availability.player.as_ref().map(|player| player.to_string()).unwrap_or_default(),
+        player_status: availability.status.as_ref().map(to_player_status).unwrap_or(api::PlayerStatus::Active) as i32,
+        response: match availability.response {
+            Some(RsvpResponse::Attending) => api::RsvpResponse::Attending,
+            Some(RsvpResponse::NotAttending) => api::RsvpResponse::NotAttending,
+            Some(RsvpResponse::Maybe) => api::RsvpResponse::Maybe,
+            None => api::RsvpResponse::None,
+        } as i32,
+    }
+}
+
+fn to_player(player: &Player) -> api::Player {
+    api::Player {
+        id: player.id.to_string(),
+        name: player.name.to_string(),
+        user_id: player.user.as_ref().map(|user| user.to_string()).unwrap_or_default(),
+        team_id: player.team.as_ref().map(|team| team.to_string()).unwrap_or_default(),
+        shirt_number: player.shirt_number.map(|number| number.value()).unwrap_or_default(),
+        position: to_player_position(&player.position) as i32,
+        status: to_player_status(&player.status) as i32,
+        joined: player.joined.timestamp_millis() as u64,
+    }
+}
+
+fn to_player_position(position: &PlayerPosition) -> api::PlayerPosition {
+    match position {
+        PlayerPosition::Goalkeeper => api::PlayerPosition::Goalkeeper,
+        PlayerPosition::Defender => api::PlayerPosition::Defender,
+        PlayerPosition::Midfielder => api::PlayerPosition::Midfielder,
+        PlayerPosition::Forward => api::PlayerPosition::Forward,
+    }
+}
+
+fn to_player_status(status: &PlayerStatus) -> api::PlayerStatus {
+    match status {
+        PlayerStatus::Active => api::PlayerStatus::Active,
+        PlayerStatus::Injured => api::PlayerStatus::Injured,
+        PlayerStatus::Loaned => api::PlayerStatus::Loaned,
+    }
+}
+
+fn to_match(fixture: &Match) -> api::Match {
+    api::Match {
+        id: fixture.id.to_string(),
+        home_team_id: fixture.home.to_string(),
+        away_team_id: fixture.away.to_string(),
+        kickoff: fixture.kickoff.timestamp_millis() as u64,
+        venue: fixture.venue.as_ref().map(|venue| venue.to_string()).unwrap_or_default(),
+        status: to_match_status(&fixture.status) as i32,
+        score: fixture.score.map(|score| api::Score {
+            home: score.home,
+            away: score.away,
+        }),
+        timeline: fixture.timeline.map(to_match_incident),
+        season_id: fixture.season.as_ref().map(|season| season.to_string()).unwrap_or_default(),
+    }
+}
+
+fn to_match_incident(incident: &MatchIncident) -> api::MatchIncident {
+    let kind = match &incident.kind {
+        MatchIncidentKind::Goal { side, scorer } => api::match_incident::Kind::Goal(api::match_incident::Goal {
+            side: to_side(side) as i32,
+            scorer: scorer.as_ref().map(|scorer| scorer.to_string()).unwrap_or_default(),
+        }),
+        MatchIncidentKind::Card { side, player, colour } => api::match_incident::Kind::Card(api::match_incident::Card {
+            side: to_side(side) as i32,
+            player: player.to_string(),
+            colour: to_card_colour(colour) as i32,
+        }),
+        MatchIncidentKind::Substitution { side, player_in, player_out } => api::match_incident::Kind::Substitution(api::match_incident::Substitution {
+            side: to_side(side) as i32,
+            player_in: player_in.to_string(),
+            player_out: player_out.to_string(),
+        }),
+        MatchIncidentKind::HalfTime => api::match_incident::Kind::HalfTime(api::Unit {}),
+        MatchIncidentKind::FullTime => api::match_incident::Kind::FullTime(api::Unit {}),
+    };
+
+    api::MatchIncident {
+        minute: incident.minute,
+        recorded: incident.recorded.timestamp_millis() as u64,
+        kind: Some(kind),
+    }
+}
+
+fn to_side(side: &Side) -> api::Side {
+    match side {
+        Side::Home => api::Side::Home,
+        Side::Away => api::Side::Away,
+    }
+}
+
+fn to_card_colour(colour: &CardColour) -> api::CardColour {
+    match colour {
+        CardColour::Yellow => api::CardColour::Yellow,
+        CardColour::SecondYellow => api::CardColour::SecondYellow,
+        CardColour::Red => api::CardColour::Red,
+    }
+}
+
+fn to_match_status(status: &MatchStatus) -> api::MatchStatus {
+    match status {
+        MatchStatus::Scheduled => api::MatchStatus::Scheduled,
+        MatchStatus::Live => api::MatchStatus::Live,
+        MatchStatus::Finished => api::MatchStatus::Finished,
+        MatchStatus::Postponed => api::MatchStatus::Postponed,
+    }
+}
+
+fn to_competition(competition: &Competition) -> api::Competition {
+    api::Competition {
+        id: competition.id.to_string(),
+        name: competition.name.to_string(),
+        founded: competition.founded.timestamp_millis() as u64,
+        organiser_ids: competition.organisers.iter().map(|o| o.to_string()).collect(),
+    }
+}
+
+fn to_season(season: &Season) -> api::Season {
+    api::Season {
+        id: season.id.to_string(),
+        competition_id: season.competition.to_string(),
+        name: season.name.to_string(),
+        team_ids: season.teams.iter().map(|t| t.to_string()).collect(),
+        points: Some(to_points_rules(&season.points)),
+        tie_breakers: season.tie_breakers.iter().map(|t| to_tie_breaker(t) as i32).collect(),
+    }
+}
+
+fn to_points_rules(points: &PointsRules) -> api::PointsRules {
+    api::PointsRules {
+        win: points.win,
+        draw: points.draw,
+        loss: points.loss,
+        bonus: points.bonus.map(|rule| match rule {
+            BonusRule::ScoredAtLeast { goals, points } => api::BonusRule {
+                points: *points,
+                condition: Some(api::bonus_rule::Condition::ScoredAtLeast(api::bonus_rule::ScoredAtLeast { goals: *goals })),
+            },
+            BonusRule::LostByAtMost { margin, points } => api::BonusRule {
+                points: *points,
+                condition: Some(api::bonus_rule::Condition::LostByAtMost(api::bonus_rule::LostByAtMost { margin: *margin })),
+            },
+        }),
+    }
+}
+
+fn to_tie_breaker(tie_breaker: &TieBreaker) -> api::TieBreaker {
+    match tie_breaker {
+        TieBreaker::GoalDifference => api::TieBreaker::GoalDifference,
+        TieBreaker::GoalsScored => api::TieBreaker::GoalsScored,
+        TieBreaker::HeadToHead => api::TieBreaker::HeadToHead,
+        TieBreaker::Wins => api::TieBreaker::Wins,
+    }
+}
+
+fn to_standing(standing: &Standing) -> api::Standing {
+    api::Standing {
+        position: standing.position,
+        team_id: standing.team.to_string(),
+        played: standing.played,
+        won: standing.won,
+        drawn: standing.drawn,
+        lost: standing.lost,
+        scored: standing.scored,
+        conceded: standing.conceded,
+        goal_difference: standing.goal_difference(),
+        points: standing.points,
+    }
+}
+
 fn to_community(community: &Community) -> api::Community {
     let context = match &community.context {
         CommunityContext::Club(id) => Some(api::community::Context::ClubId(id.to_string())),
         CommunityContext::Team(id) => Some(api::community::Context::TeamId(id.to_string())),
+        CommunityContext::Competition(id) => Some(api::community::Context::CompetitionId(id.to_string())),
     };
 
     api::Community {
@@ -633,12 +3064,37 @@ fn to_community(community: &Community) -> api::Community {
     }
 }
 
-fn to_comment(comment: &Comment) -> api::Comment {
+fn to_comment(listing: &CommentListing) -> api::Comment {
+    let comment = &listing.comment;
+
+    // note: removed comments render as a placeholder
+    let (text, entities) = match &comment.removed {
+        Some(_) => (String::new(), Vec::new()),
+        None => (comment.text.to_string(), comment.entities.iter().map(to_text_entity).collect()),
+    };
+
     api::Comment {
         id: comment.id.to_string(),
-        text: comment.text.to_string(),
+        text,
         author_id: comment.author.to_string(),
         published: comment.published.timestamp_millis() as u64,
+        edited: to_some_timestamp(&comment.edited),
+        removed: comment.removed.as_ref().map(to_tombstone),
+        reply_to_id: comment.reply_to.to_string(),
+        parent_id: comment.parent_comment.as_ref().map(|id| id.to_string()).unwrap_or_default(),
+        depth: comment.depth,
+        replies: listing.replies,
+        reactions: Some(to_reactions(&listing.reactions)),
+        reaction_counts: to_reaction_counts(&listing.reactions),
+        viewer: Some(to_viewer_state(&listing.viewer)),
+        entities,
+    }
+}
+
+fn to_comment_revision(revision: &CommentRevision) -> api::Revision {
+    api::Revision {
+        text: revision.text.to_string(),
+        published: revision.published.timestamp_millis() as u64,
     }
 }
 
@@ -646,18 +3102,143 @@ fn to_post_attachment(attachment: &PostAttachment) -> api::PostAttachment {
     match attachment {
         PostAttachment::Image(id) => api::PostAttachment {
             r#type: Some(api::post_attachment::Type::ImageId(id.to_string())),
-        }
+        },
+        PostAttachment::Poll(poll) => api::PostAttachment {
+            r#type: Some(api::post_attachment::Type::Poll(to_poll(poll))),
+        },
+        PostAttachment::MatchIncident { fixture, incident } => api::PostAttachment {
+            r#type: Some(api::post_attachment::Type::MatchIncident(api::post_attachment::MatchIncident {
+                match_id: fixture.to_string(),
+                incident: Some(to_match_incident(incident)),
+            })),
+        },
+    }
+}
+
+fn to_poll(poll: &Poll) -> api::Poll {
+    api::Poll {
+        options: poll.options().iter().map(|option| option.to_string()).collect(),
+        multiple: poll.choice == PollChoice::Multiple,
+        closes: to_some_timestamp(&poll.closes),
+        public: poll.visibility == PollVisibility::Public,
+    }
+}
+
+fn to_poll_tally(tally: &PollTally, closed: bool) -> api::PollTally {
+    api::PollTally {
+        votes: tally.votes.clone(),
+        voters: tally.voters,
+        viewer_choices: tally.viewer.clone(),
+        closed,
+    }
+}
+
+fn to_blocked_user(block: &Block) -> api::BlockedUser {
+    api::BlockedUser {
+        user_id: block.user.to_string(),
+        blocked: block.blocked.timestamp_millis() as u64,
+    }
+}
+
+fn to_follow(follow: &Follow) -> api::Follow {
+    api::Follow {
+        follower_id: follow.follower.to_string(),
+        followee_id: follow.followee.to_string(),
+        followed: follow.followed.timestamp_millis() as u64,
+    }
+}
+
+fn to_poll_vote(vote: &PollVote) -> api::PollVote {
+    api::PollVote {
+        voter_id: vote.voter.to_string(),
+        choices: vote.choices.clone(),
+        voted: vote.voted.timestamp_millis() as u64,
     }
 }
 
 fn to_post(post: &Post) -> api::Post {
+    // note: removed posts render as a placeholder
+    let (text, attachments, entities) = match &post.removed {
+        Some(_) => (String::new(), Vec::new(), Vec::new()),
+        None => (post.text.to_string(), post.attachments.iter().map(to_post_attachment).collect(), post.entities.iter().map(to_text_entity).collect()),
+    };
+
     api::Post {
         id: post.id.to_string(),
         community_id: post.community.to_string(),
-        text: post.text.to_string(),
-        attachments: post.attachments.iter().map(to_post_attachment).collect(),
+        text,
+        attachments,
         author_id: post.author.to_string(),
         published: post.published.timestamp_millis() as u64,
+        edited: to_some_timestamp(&post.edited),
+        removed: post.removed.as_ref().map(to_tombstone),
+        pinned: post.is_pinned(),
+        announced_until: to_some_timestamp(&post.kind.announced_until()),
+        status: to_post_status(&post.status) as i32,
+        scheduled: to_some_timestamp(&post.status.scheduled()),
+        entities,
+    }
+}
+
+fn to_text_entity(entity: &TextEntity) -> api::TextEntity {
+    let kind = match &entity.kind {
+        TextEntityKind::Hashtag(tag) => api::text_entity::Kind::Hashtag(tag.to_string()),
+        TextEntityKind::Mention(user) => api::text_entity::Kind::MentionId(user.to_string()),
+    };
+
+    api::TextEntity {
+        offset: entity.offset as u32,
+        length: entity.length as u32,
+        kind: Some(kind),
+    }
+}
+
+fn to_search_hit(hit: &SearchHit) -> api::search_response::Hit {
+    let target = match &hit.target {
+        SearchTarget::Community(id) => api::search_response::hit::Target::CommunityId(id.to_string()),
+        SearchTarget::Post(id) => api::search_response::hit::Target::PostId(id.to_string()),
+        SearchTarget::Comment(id) => api::search_response::hit::Target::CommentId(id.to_string()),
+    };
+
+    api::search_response::Hit {
+        target: Some(target),
+        in_community_id: hit.community.to_string(),
+        in_post_id: hit.post.as_ref().map(|post| post.to_string()).unwrap_or_default(),
+        rank: hit.rank,
+        highlight: Some(api::Highlight {
+            snippet: hit.highlight.snippet.clone(),
+            ranges: hit.highlight.ranges.map(|(offset, length)| api::highlight::Range {
+                offset: *offset as u32,
+                length: *length as u32,
+            }),
+        }),
+    }
+}
+
+fn to_post_status(status: &PostStatus) -> api::PostStatus {
+    match status {
+        PostStatus::Published => api::PostStatus::Published,
+        PostStatus::Draft => api::PostStatus::Draft,
+        PostStatus::Scheduled { .. } => api::PostStatus::Scheduled,
+    }
+}
+
+fn to_tombstone(tombstone: &Tombstone) -> api::Tombstone {
+    let role = match tombstone.role {
+        RemoverRole::Author => api::RemoverRole::Author,
+        RemoverRole::Moderator => api::RemoverRole::Moderator,
+    };
+
+    api::Tombstone {
+        removed: tombstone.removed.timestamp_millis() as u64,
+        role: role as i32,
+    }
+}
+
+fn to_post_revision(revision: &PostRevision) -> api::Revision {
+    api::Revision {
+        text: revision.text.to_string(),
+        published: revision.published.timestamp_millis() as u64,
     }
 }
 
@@ -665,16 +3246,108 @@ fn to_feed_listing(listing: &FeedListing) -> api::list_feed_response::FeedListin
     api::list_feed_response::FeedListing {
         post: Some(to_post(&listing.post)),
         comments: listing.comments,
-        reactions: Some(api::list_feed_response::Reactions {
-            love: listing.reactions_love,
-            funny: listing.reactions_funny,
-            celebrate: listing.reactions_celebrate,
-            support: listing.reactions_support,
-            insightful: listing.reactions_insightful,
+        reactions: Some(to_reactions(&listing.reactions)),
+        reaction_counts: to_reaction_counts(&listing.reactions),
+        viewer: Some(to_viewer_state(&listing.viewer)),
+        poll: listing.poll.as_ref().map(|tally| {
+            let closed = listing.post.attachments.poll().map(|poll| poll.is_closed(&Utc::now())).unwrap_or(false);
+            to_poll_tally(tally, closed)
         }),
     }
 }
 
+// note: reactions besides the emotions have none
+fn to_emotion(reaction: &ReactionKey) -> Option<api::Emotion> {
+    match reaction.to_string().as_str() {
+        "love" => Some(api::Emotion::Love),
+        "funny" => Some(api::Emotion::Funny),
+        "celebrate" => Some(api::Emotion::Celebrate),
+        "support" => Some(api::Emotion::Support),
+        "insightful" => Some(api::Emotion::Insightful),
+        _ => None,
+    }
+}
+
+fn to_reactions(counts: &HashMap<ReactionKey, u64>) -> api::list_feed_response::Reactions {
+    let count = |key: &str| counts
+        .iter()
+        .find(|(reaction, _)| reaction.to_string() == key)
+        .map(|(_, count)| *count)
+        .unwrap_or(0);
+
+    api::list_feed_response::Reactions {
+        love: count("love"),
+        funny: count("funny"),
+        celebrate: count("celebrate"),
+        support: count("support"),
+        insightful: count("insightful"),
+    }
+}
+
+fn to_reaction_counts(counts: &HashMap<ReactionKey, u64>) -> HashMap<String, u64> {
+    counts
+        .iter()
+        .map(|(reaction, count)| (reaction.to_string(), *count))
+        .collect()
+}
+
+fn to_reaction(record: &PostReactionRecord) -> api::Reaction {
+    api::Reaction {
+        author_id: record.reaction.author.to_string(),
+        emotion: to_emotion(&record.reaction.reaction).unwrap_or(api::Emotion::Custom) as i32,
+        reacted: record.reacted.timestamp_millis().max(0) as u64,
+        reaction: record.reaction.reaction.to_string(),
+    }
+}
+
+fn to_reaction_definition(definition: &ReactionDefinition) -> api::ReactionDefinition {
+    api::ReactionDefinition {
+        key: definition.key.to_string(),
+        emoji: definition.emoji.to_string(),
+    }
+}
+
+fn to_viewer_state(viewer: &ViewerState) -> api::ViewerState {
+    api::ViewerState {
+        // note: v1 clients can't show reactions besides the emotions, so these are reported as not reacted
+        reacted: viewer.reaction.as_ref().and_then(to_emotion).is_some(),
+        emotion: viewer.reaction.as_ref().and_then(to_emotion).unwrap_or(api::Emotion::Love) as i32,
+        author: viewer.author,
+        can_edit: viewer.can_edit,
+        can_remove: viewer.can_remove,
+        bookmarked: viewer.bookmarked,
+        reaction: viewer.reaction.as_ref().map(|reaction| reaction.to_string()).unwrap_or_default(),
+    }
+}
+
+fn to_report_category(category: &ReportCategory) -> api::ReportCategory {
+    match category {
+        ReportCategory::Spam => api::ReportCategory::Spam,
+        ReportCategory::Harassment => api::ReportCategory::Harassment,
+        ReportCategory::HateSpeech => api::ReportCategory::HateSpeech,
+        ReportCategory::Violence => api::ReportCategory::Violence,
+        ReportCategory::Misinformation => api::ReportCategory::Misinformation,
+        ReportCategory::Other => api::ReportCategory::Other,
+    }
+}
+
+fn to_report(report: &Report) -> api::Report {
+    let target = match &report.target {
+        ReportTarget::Post(id) => Some(api::report::Target::PostId(id.to_string())),
+        ReportTarget::Comment(id) => Some(api::report::Target::CommentId(id.to_string())),
+    };
+
+    api::Report {
+        id: report.id.to_string(),
+        community_id: report.community.to_string(),
+        target,
+        reporter_id: report.reporter.as_ref().map(|r| r.to_string()).unwrap_or_default(),
+        category: to_report_category(&report.category) as i32,
+        text: report.text.to_string(),
+        reported: report.reported.timestamp_millis() as u64,
+    }
+}
+
 // errors
 fn to_status(error: Box<dyn Error + Send + Sync>) -> Status {
     Status::unknown(error.to_string())
@@ -1,23 +1,44 @@
+#[allow(clippy::module_inception)]
 pub mod api;
 
+use std::sync::Arc;
+use crate::domain::account::aggregates::IdentityProviders;
+use crate::domain::account::usecases::AccountUsecase;
+use crate::domain::calendar::usecases::CalendarUsecase;
 use crate::domain::club::usecases::ClubUsecase;
+use crate::domain::competition::usecases::CompetitionUsecase;
+use crate::domain::fixture::usecases::FixtureUsecase;
+use crate::domain::notification::usecases::NotificationUsecase;
 use crate::domain::social::usecases::usecase::SocialUsecase;
 use crate::domain::team::usecases::TeamUsecase;
 
 tonic::include_proto!("api");
 
 pub struct ApiService {
+    identity_providers: IdentityProviders,
+    account_usecase: AccountUsecase,
     club_usecase: ClubUsecase,
     team_usecase: TeamUsecase,
+    fixture_usecase: FixtureUsecase,
+    competition_usecase: CompetitionUsecase,
+    calendar_usecase: CalendarUsecase,
     social_usecase: SocialUsecase,
+    notification_usecase: Arc<NotificationUsecase>, // shared with the job creating the notifications
 }
 
 impl ApiService {
-    pub fn build(club_usecase: ClubUsecase, team_usecase: TeamUsecase, social_usecase: SocialUsecase) -> ApiService {
+    #[allow(clippy::too_many_arguments)]
+    pub fn build(identity_providers: IdentityProviders, account_usecase: AccountUsecase, club_usecase: ClubUsecase, team_usecase: TeamUsecase, fixture_usecase: FixtureUsecase, competition_usecase: CompetitionUsecase, calendar_usecase: CalendarUsecase, social_usecase: SocialUsecase, notification_usecase: Arc<NotificationUsecase>) -> ApiService {
         ApiService {
+            identity_providers,
+            account_usecase,
             club_usecase,
             team_usecase,
+            fixture_usecase,
+            competition_usecase,
+            calendar_usecase,
             social_usecase,
+            notification_usecase,
         }
     }
 }
\ No newline at end of file
@@ -0,0 +1,65 @@
+use std::fmt::Formatter;
+use serde::de::DeserializeOwned;
+
+#[derive(Debug)]
+pub enum EventConsumeError {
+    DeserializationError,
+    PersistentError
+}
+
+impl std::fmt::Display for EventConsumeError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            EventConsumeError::DeserializationError => write!(f,"deserialization error"),
+            EventConsumeError::PersistentError => write!(f,"persistent error"),
+        }
+    }
+}
+
+impl std::error::Error for EventConsumeError {}
+
+// note: position is the order in which events were published
+pub struct StoredEvent {
+    pub position: i64,
+    pub kind: String,
+    pub data: String,
+}
+
+impl StoredEvent {
+    pub fn parse<T: DeserializeOwned>(&self) -> Result<T, EventConsumeError> {
+        serde_json::from_str(self.data.as_str())
+            .map_err(|_| EventConsumeError::DeserializationError)
+    }
+}
+
+#[tonic::async_trait]
+pub trait EventConsumerClient {
+    async fn poll(&self, consumer: &str, limit: i64) -> Result<Vec<StoredEvent>, EventConsumeError>;
+    async fn acknowledge(&self, consumer: &str, position: i64) -> Result<(), EventConsumeError>;
+}
+
+// note: delivers events at least once, consumers keep track of their own position
+pub struct EventConsumer {
+    client: Box<dyn EventConsumerClient + Send + Sync>,
+    name: &'static str,
+}
+
+impl EventConsumer {
+    // number of events handled per poll
+    const BATCH_SIZE: i64 = 100;
+
+    pub fn build(client: Box<dyn EventConsumerClient + Send + Sync>, name: &'static str) -> EventConsumer {
+        EventConsumer {
+            client,
+            name,
+        }
+    }
+
+    pub async fn poll(&self) -> Result<Vec<StoredEvent>, EventConsumeError> {
+        self.client.poll(self.name, EventConsumer::BATCH_SIZE).await
+    }
+
+    pub async fn acknowledge(&self, event: &StoredEvent) -> Result<(), EventConsumeError> {
+        self.client.acknowledge(self.name, event.position).await
+    }
+}
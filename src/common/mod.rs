@@ -1,7 +1,10 @@
 pub mod repository;
 pub mod event_publisher;
+pub mod event_consumer;
 pub mod event;
+pub mod scheduler;
 
 pub use repository::*;
 pub use event_publisher::*;
-pub use event::*;
\ No newline at end of file
+pub use event_consumer::*;
+pub use event::*;
@@ -0,0 +1,17 @@
+use std::future::Future;
+use std::time::Duration;
+
+// runs the task on a fixed interval for as long as the process lives
+pub fn every<F, Fut>(period: Duration, task: F)
+where
+    F: Fn() -> Fut + Send + 'static,
+    Fut: Future<Output = ()> + Send + 'static,
+{
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(period);
+        loop {
+            interval.tick().await;
+            task().await;
+        }
+    });
+}
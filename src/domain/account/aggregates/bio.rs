@@ -0,0 +1,39 @@
+use std::fmt::{Display, Formatter};
+use chumsky::prelude::{end, filter, Parser, Simple};
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, PartialEq, Eq, Clone)]
+pub struct Bio {
+    raw: String,
+}
+
+#[derive(Debug)]
+pub enum ParseError {
+    MalformedInput,
+}
+
+impl Bio {
+    fn parser() -> impl Parser<char, Bio, Error = Simple<char>> {
+        // bio can span multiple lines, other control characters are not allowed
+        // length between 1 and 500 characters (surrounding whitespace is trimmed)
+        filter(|c: &char| !c.is_control() || *c == '\n')
+            .repeated()
+            .at_least(1)
+            .at_most(500)
+            .collect::<String>()
+            .map(|bio| Bio { raw: bio })
+            .then_ignore(end())
+    }
+
+    pub fn parse(input: &str) -> Result<Bio, ParseError> {
+        Bio::parser()
+            .parse(input.trim())
+            .map_err(|_| ParseError::MalformedInput)
+    }
+}
+
+impl Display for Bio {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.raw)
+    }
+}
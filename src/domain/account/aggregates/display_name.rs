@@ -0,0 +1,39 @@
+use std::fmt::{Display, Formatter};
+use chumsky::prelude::{end, filter, Parser, Simple};
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, PartialEq, Eq, Clone)]
+pub struct DisplayName {
+    raw: String,
+}
+
+#[derive(Debug)]
+pub enum ParseError {
+    MalformedInput,
+}
+
+impl DisplayName {
+    fn parser() -> impl Parser<char, DisplayName, Error = Simple<char>> {
+        // display name shoud only contain visible (non-control) characters
+        // length between 2 en 50 characters (surrounding whitespace is trimmed)
+        filter(|c: &char| !c.is_control())
+            .repeated()
+            .at_least(2)
+            .at_most(50)
+            .collect::<String>()
+            .map(|name| DisplayName { raw: name })
+            .then_ignore(end())
+    }
+
+    pub fn parse(input: &str) -> Result<DisplayName, ParseError> {
+        DisplayName::parser()
+            .parse(input.trim())
+            .map_err(|_| ParseError::MalformedInput)
+    }
+}
+
+impl Display for DisplayName {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.raw)
+    }
+}
@@ -0,0 +1,126 @@
+use std::fmt::{Display, Formatter};
+use chumsky::prelude::{end, filter, just, Parser, Simple};
+use serde::{Deserialize, Serialize};
+use std::hash::{Hash, Hasher};
+
+#[derive(Serialize, Deserialize, PartialEq, Eq, Clone, Copy)]
+pub enum IdentityProvider {
+    Firebase,
+    Oidc,
+    Service,
+}
+
+// note: identity of a user at an identity provider, namespaced by provider (and issuer)
+#[derive(Serialize, Deserialize, PartialEq, Eq, Clone)]
+pub struct Identity {
+    raw: String,
+}
+
+#[derive(Debug)]
+pub enum ParseError {
+    MalformedInput,
+}
+
+impl Identity {
+    // longest subject allowed by OpenID Connect
+    const MAX_SUBJECT_LENGTH: usize = 255;
+
+    pub fn parser() -> impl Parser<char, Identity, Error = Simple<char>> {
+        // firebase: firebase auth id of 20-24 alphanumeric ascii characters
+        let firebase = just("firebase:")
+            .ignore_then(filter(|c: &char| c.is_ascii_alphanumeric())
+                .repeated()
+                .at_least(20)
+                .at_most(24)
+                .collect::<String>())
+            .map(|uid| format!("firebase:{}", uid));
+
+        // oidc: issuer (url without scheme, percent-encoded colons) followed by the subject, any visible ascii characters
+        let oidc = just("oidc:")
+            .ignore_then(filter(|c: &char| c.is_ascii_alphanumeric() || *c == '.' || *c == '-' || *c == '_' || *c == '/' || *c == '%')
+                .repeated()
+                .at_least(1)
+                .at_most(100)
+                .collect::<String>())
+            .then_ignore(just(':'))
+            .then(filter(|c: &char| c.is_ascii_graphic())
+                .repeated()
+                .at_least(1)
+                .at_most(Identity::MAX_SUBJECT_LENGTH)
+                .collect::<String>())
+            .map(|(issuer, subject)| format!("oidc:{}:{}", issuer, subject));
+
+        // service: name of the service account, 2-50 lowercase ascii characters, digits or dashes
+        let service = just("service:")
+            .ignore_then(filter(|c: &char| c.is_ascii_lowercase() || c.is_ascii_digit() || *c == '-')
+                .repeated()
+                .at_least(2)
+                .at_most(50)
+                .collect::<String>())
+            .map(|name| format!("service:{}", name));
+
+        firebase
+            .or(oidc)
+            .or(service)
+            .map(|raw| Identity { raw })
+            .then_ignore(end())
+    }
+
+    pub fn parse(input: &str) -> Result<Identity, ParseError> {
+        Identity::parser()
+            .parse(input)
+            .map_err(|_| ParseError::MalformedInput)
+    }
+
+    pub fn firebase(uid: &str) -> Result<Identity, ParseError> {
+        Identity::parse(format!("firebase:{}", uid).as_str())
+    }
+
+    pub fn oidc(issuer: &str, subject: &str) -> Result<Identity, ParseError> {
+        // note: the scheme and trailing slash of the issuer url are dropped;
+        // colons (of a port) are percent-encoded, as a colon separates the issuer from the subject
+        let issuer = issuer
+            .trim_start_matches("https://")
+            .trim_start_matches("http://")
+            .trim_end_matches('/')
+            .replace('%', "%25")
+            .replace(':', "%3A");
+
+        Identity::parse(format!("oidc:{}:{}", issuer, subject).as_str())
+    }
+
+    pub fn service(name: &str) -> Result<Identity, ParseError> {
+        Identity::parse(format!("service:{}", name).as_str())
+    }
+
+    pub fn provider(&self) -> IdentityProvider {
+        if self.raw.starts_with("firebase:") {
+            IdentityProvider::Firebase
+        } else if self.raw.starts_with("oidc:") {
+            IdentityProvider::Oidc
+        } else {
+            IdentityProvider::Service
+        }
+    }
+
+    // subject (user id) at the identity provider
+    pub fn subject(&self) -> &str {
+        let (_, subject) = self.raw.split_once(':').unwrap_or_default();
+        match self.provider() {
+            IdentityProvider::Oidc => subject.split_once(':').map(|(_, subject)| subject).unwrap_or_default(),
+            _ => subject,
+        }
+    }
+}
+
+impl Display for Identity {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.raw)
+    }
+}
+
+impl Hash for Identity {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.raw.hash(state)
+    }
+}
@@ -0,0 +1,64 @@
+use std::fmt::{Display, Formatter};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::domain::account::aggregates::UserId;
+
+// note: secret handed to the signed-in account, redeemed while signed in with the identity to link
+#[derive(Serialize, Deserialize, PartialEq, Eq, Clone)]
+pub struct LinkCode {
+    raw: String,
+}
+
+#[derive(Debug)]
+pub enum ParseError {
+    MalformedInput,
+}
+
+impl LinkCode {
+    pub fn random() -> LinkCode {
+        LinkCode {
+            raw: friendly_id::create(),
+        }
+    }
+
+    pub fn parse(input: &str) -> Result<LinkCode, ParseError> {
+        if input.len() < 18 || input.len() > 22 {
+            return Err(ParseError::MalformedInput);
+        }
+
+        friendly_id::decode(input)
+            .map(|_| LinkCode {
+                raw: String::from(input),
+            })
+            .map_err(|_| ParseError::MalformedInput)
+    }
+}
+
+impl Display for LinkCode {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.raw)
+    }
+}
+
+// note: both identities prove themselves through a validated token, the code ties the two sign-ins together
+#[derive(Serialize, Deserialize)]
+pub struct IdentityLink {
+    pub code: LinkCode,
+    pub account: UserId,
+    pub expires: DateTime<Utc>,
+}
+
+impl IdentityLink {
+    pub fn offer(account: UserId, expires: DateTime<Utc>) -> IdentityLink {
+        IdentityLink {
+            code: LinkCode::random(),
+            account,
+            expires,
+        }
+    }
+
+    pub fn is_expired(&self, at: &DateTime<Utc>) -> bool {
+        &self.expires <= at
+    }
+}
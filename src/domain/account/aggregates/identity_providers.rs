@@ -0,0 +1,21 @@
+use crate::domain::account::aggregates::identity::ParseError;
+use crate::domain::account::aggregates::Identity;
+
+// note: maps the issuer of a (validated) token onto the provider of the identity it carries;
+// issuers other than firebase and the service accounts are considered OpenID Connect providers
+pub struct IdentityProviders {
+    pub firebase_issuer: String,
+    pub service_account_issuer: String,
+}
+
+impl IdentityProviders {
+    pub fn identity(&self, issuer: Option<&str>, subject: &str) -> Result<Identity, ParseError> {
+        match issuer {
+            // note: tokens without issuer predate the support of other providers than firebase
+            None => Identity::firebase(subject),
+            Some(issuer) if issuer == self.firebase_issuer => Identity::firebase(subject),
+            Some(issuer) if issuer == self.service_account_issuer => Identity::service(subject),
+            Some(issuer) => Identity::oidc(issuer, subject),
+        }
+    }
+}
@@ -0,0 +1,34 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use crate::domain::account::aggregates::{Identity, UserId};
+
+// note: an account is registered with its first identity, other identities are linked to it later
+#[derive(Serialize, Deserialize)]
+pub struct LinkedIdentity {
+    pub identity: Identity,
+    pub account: UserId,
+    pub linked: DateTime<Utc>,
+}
+
+impl LinkedIdentity {
+    pub fn register(identity: Identity, linked: DateTime<Utc>) -> LinkedIdentity {
+        LinkedIdentity {
+            account: UserId::from(&identity),
+            identity,
+            linked,
+        }
+    }
+
+    pub fn link(identity: Identity, account: UserId, linked: DateTime<Utc>) -> LinkedIdentity {
+        LinkedIdentity {
+            identity,
+            account,
+            linked,
+        }
+    }
+
+    // note: the identity an account was registered with stays linked
+    pub fn is_registration(&self) -> bool {
+        UserId::from(&self.identity) == self.account
+    }
+}
@@ -0,0 +1,55 @@
+use std::fmt::{Display, Formatter};
+use chumsky::prelude::{end, filter, just, Parser, Simple};
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, PartialEq, Eq, Clone)]
+pub struct Locale {
+    raw: String,
+}
+
+#[derive(Debug)]
+pub enum ParseError {
+    MalformedInput,
+}
+
+impl Locale {
+    fn parser() -> impl Parser<char, Locale, Error = Simple<char>> {
+        // locale is a (simplified) BCP 47 tag: a ISO 639 language of 2-3 lowercase ascii characters,
+        // optionally followed by a dash and a ISO 3166 region (2 uppercase ascii characters) or UN M.49 area (3 digits)
+        let language = filter(|c: &char| c.is_ascii_lowercase())
+            .repeated()
+            .at_least(2)
+            .at_most(3)
+            .collect::<String>();
+        let region = just('-')
+            .ignore_then(
+                filter(|c: &char| c.is_ascii_uppercase()).repeated().exactly(2)
+                    .or(filter(|c: &char| c.is_ascii_digit()).repeated().exactly(3))
+            )
+            .collect::<String>();
+
+        language
+            .then(region.or_not())
+            .map(|(language, region)| match region {
+                Some(region) => Locale { raw: format!("{}-{}", language, region) },
+                None => Locale { raw: language },
+            })
+            .then_ignore(end())
+    }
+
+    pub fn parse(input: &str) -> Result<Locale, ParseError> {
+        Locale::parser()
+            .parse(input)
+            .map_err(|_| ParseError::MalformedInput)
+    }
+
+    pub fn language(&self) -> &str {
+        self.raw.split('-').next().unwrap_or_default()
+    }
+}
+
+impl Display for Locale {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.raw)
+    }
+}
@@ -1,3 +1,19 @@
 pub use user_id::UserId;
+pub use user_profile::{ProfileSummary, UserProfile};
+pub use display_name::DisplayName;
+pub use bio::Bio;
+pub use locale::Locale;
+pub use identity::{Identity, IdentityProvider};
+pub use identity_providers::IdentityProviders;
+pub use linked_identity::LinkedIdentity;
+pub use identity_link::{IdentityLink, LinkCode};
 
 pub mod user_id;
+pub mod user_profile;
+pub mod display_name;
+pub mod bio;
+pub mod locale;
+pub mod identity;
+pub mod identity_providers;
+pub mod linked_identity;
+pub mod identity_link;
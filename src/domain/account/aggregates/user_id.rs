@@ -1,8 +1,9 @@
 use std::fmt::{Display, Formatter};
-use chumsky::prelude::{filter, Parser, Simple};
+use chumsky::prelude::{end, filter, Parser, Simple};
 
 use serde::{Deserialize, Serialize};
 use std::hash::{Hash, Hasher};
+use crate::domain::account::aggregates::{Identity, IdentityProvider};
 
 #[derive(Serialize, Deserialize, PartialEq, Eq, Clone)]
 pub struct UserId {
@@ -16,13 +17,18 @@ pub enum ParseError {
 
 impl UserId {
     fn parser() -> impl Parser<char, UserId, Error = Simple<char>> {
-        // account id (firebase auth) consists of 20-24 alphanumeric ascii characters
-        filter(|c: &char| c.is_ascii_alphanumeric())
+        // account id is the (namespaced) identity the account was registered with, e.g. oidc:<issuer>:<sub>,
+        // except for accounts of firebase auth, which consist of the bare 20-24 alphanumeric ascii characters
+        let legacy = filter(|c: &char| c.is_ascii_alphanumeric())
             .repeated()
             .at_least(20)
             .at_most(24)
             .collect::<String>()
-            .map(|name| UserId { raw: name })
+            .then_ignore(end());
+
+        Identity::parser()
+            .map(|identity| UserId::from(&identity))
+            .or(legacy.map(|name| UserId { raw: name }))
     }
 
     pub fn parse(input: &str) -> Result<UserId, ParseError> {
@@ -32,6 +38,16 @@ impl UserId {
     }
 }
 
+impl From<&Identity> for UserId {
+    fn from(identity: &Identity) -> Self {
+        // note: firebase accounts predate namespaced identities, hence keep their bare id
+        match identity.provider() {
+            IdentityProvider::Firebase => UserId { raw: identity.subject().to_string() },
+            _ => UserId { raw: identity.to_string() },
+        }
+    }
+}
+
 impl Display for UserId {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.raw)
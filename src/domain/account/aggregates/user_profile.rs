@@ -0,0 +1,53 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use crate::domain::account::aggregates::{Bio, DisplayName, Locale, UserId};
+use crate::domain::club::aggregates::ClubId;
+use crate::domain::media::aggregates::ImageId;
+
+#[derive(Serialize, Deserialize)]
+pub struct UserProfile {
+    pub id: UserId,
+    pub display_name: DisplayName,
+    pub avatar: Option<ImageId>,
+    pub bio: Option<Bio>,
+    pub favourite_club: Option<ClubId>,
+    pub locale: Option<Locale>,
+    pub updated: DateTime<Utc>,
+}
+
+// note: the part of a profile shown next to content of the user (posts, comments, member lists, ..)
+pub struct ProfileSummary {
+    pub id: UserId,
+    pub display_name: DisplayName,
+    pub avatar: Option<ImageId>,
+}
+
+impl UserProfile {
+    pub fn new(id: UserId, display_name: DisplayName, updated: DateTime<Utc>) -> UserProfile {
+        UserProfile {
+            id,
+            display_name,
+            avatar: None,
+            bio: None,
+            favourite_club: None,
+            locale: None,
+            updated,
+        }
+    }
+
+    pub fn summary(&self) -> ProfileSummary {
+        ProfileSummary {
+            id: self.id.clone(),
+            display_name: self.display_name.clone(),
+            avatar: self.avatar.clone(),
+        }
+    }
+}
+
+impl PartialEq for UserProfile {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id
+    }
+}
+
+impl Eq for UserProfile {}
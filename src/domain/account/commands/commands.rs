@@ -0,0 +1,28 @@
+use crate::domain::account::aggregates::{Bio, DisplayName, Identity, LinkCode, Locale, UserId};
+use crate::domain::club::aggregates::ClubId;
+use crate::domain::media::aggregates::ImageId;
+
+// note: replaces the whole profile, fields left empty are cleared
+pub struct UpdateProfile {
+    pub user: UserId,
+    pub display_name: DisplayName,
+    pub avatar: Option<ImageId>,
+    pub bio: Option<Bio>,
+    pub favourite_club: Option<ClubId>,
+    pub locale: Option<Locale>,
+}
+
+pub struct OfferIdentityLink {
+    pub account: UserId,
+}
+
+// note: identity is the one signed in with, the code tells the account to link it to
+pub struct LinkIdentity {
+    pub code: LinkCode,
+    pub identity: Identity,
+}
+
+pub struct UnlinkIdentity {
+    pub account: UserId,
+    pub identity: Identity,
+}
@@ -0,0 +1,71 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use crate::common::Event;
+use crate::domain::account::aggregates::{Bio, DisplayName, Identity, Locale, UserId};
+use crate::domain::club::aggregates::ClubId;
+use crate::domain::media::aggregates::ImageId;
+
+#[derive(Serialize, Deserialize)]
+pub struct ProfileUpdatedV1 {
+    pub user: UserId,
+    pub display_name: DisplayName,
+    pub avatar: Option<ImageId>,
+    pub bio: Option<Bio>,
+    pub favourite_club: Option<ClubId>,
+    pub locale: Option<Locale>,
+}
+
+impl Event for ProfileUpdatedV1 {
+    fn kind(&self) -> &'static str {
+        "ProfileUpdatedV1"
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct AccountRegisteredV1 {
+    pub account: UserId,
+    pub identity: Identity,
+}
+
+impl Event for AccountRegisteredV1 {
+    fn kind(&self) -> &'static str {
+        "AccountRegisteredV1"
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct IdentityLinkedV1 {
+    pub account: UserId,
+    pub identity: Identity,
+}
+
+impl Event for IdentityLinkedV1 {
+    fn kind(&self) -> &'static str {
+        "IdentityLinkedV1"
+    }
+}
+
+// note: the code itself is left out, it's a secret of the account
+#[derive(Serialize, Deserialize)]
+pub struct IdentityLinkOfferedV1 {
+    pub account: UserId,
+    pub expires: DateTime<Utc>,
+}
+
+impl Event for IdentityLinkOfferedV1 {
+    fn kind(&self) -> &'static str {
+        "IdentityLinkOfferedV1"
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct IdentityUnlinkedV1 {
+    pub account: UserId,
+    pub identity: Identity,
+}
+
+impl Event for IdentityUnlinkedV1 {
+    fn kind(&self) -> &'static str {
+        "IdentityUnlinkedV1"
+    }
+}
@@ -0,0 +1,3 @@
+pub mod profile_policy;
+
+pub use profile_policy::{ProfilePolicy, ProfilePolicyExecutionContext, ProfilePolicyViolation};
@@ -0,0 +1,16 @@
+use crate::domain::account::aggregates::{UserId, UserProfile};
+
+#[derive(Debug)]
+pub enum ProfilePolicyViolation {
+    InsufficientPermissions,
+}
+
+type ProfilePolicyResult = Result<(), ProfilePolicyViolation>;
+
+pub struct ProfilePolicyExecutionContext {
+    pub user: UserId,
+}
+
+pub trait ProfilePolicy {
+    fn allow_update(context: &ProfilePolicyExecutionContext, profile: &UserProfile) -> ProfilePolicyResult;
+}
@@ -0,0 +1,9 @@
+use crate::common::RepositoryResult;
+use crate::domain::account::aggregates::{IdentityLink, LinkCode};
+
+#[tonic::async_trait]
+pub trait IdentityLinkRepository {
+    async fn get(&self, code: &LinkCode) -> RepositoryResult<Option<IdentityLink>>;
+    async fn set(&self, link: &IdentityLink) -> RepositoryResult<()>;
+    async fn unset(&self, code: &LinkCode) -> RepositoryResult<bool>;
+}
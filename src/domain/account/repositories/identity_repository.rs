@@ -0,0 +1,10 @@
+use crate::common::RepositoryResult;
+use crate::domain::account::aggregates::{Identity, LinkedIdentity, UserId};
+
+#[tonic::async_trait]
+pub trait IdentityRepository {
+    async fn list_by_account(&self, account: &UserId) -> RepositoryResult<Vec<LinkedIdentity>>;
+    async fn get(&self, identity: &Identity) -> RepositoryResult<Option<LinkedIdentity>>;
+    async fn set(&self, identity: &LinkedIdentity) -> RepositoryResult<()>;
+    async fn unset(&self, identity: &Identity) -> RepositoryResult<bool>;
+}
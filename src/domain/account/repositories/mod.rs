@@ -0,0 +1,9 @@
+pub mod profile_repository;
+pub mod identity_repository;
+pub mod identity_link_repository;
+pub mod club_repository;
+
+pub use profile_repository::ProfileRepository;
+pub use identity_repository::IdentityRepository;
+pub use identity_link_repository::IdentityLinkRepository;
+pub use club_repository::ClubRepository;
@@ -0,0 +1,9 @@
+use crate::common::RepositoryResult;
+use crate::domain::account::aggregates::{UserId, UserProfile};
+
+#[tonic::async_trait]
+pub trait ProfileRepository {
+    async fn get(&self, id: &UserId) -> RepositoryResult<Option<UserProfile>>;
+    async fn get_many(&self, ids: &[UserId]) -> RepositoryResult<Vec<UserProfile>>;
+    async fn set(&self, profile: &UserProfile) -> RepositoryResult<()>;
+}
@@ -0,0 +1,28 @@
+use std::fmt::Formatter;
+
+#[derive(Debug)]
+pub enum DomainError {
+    UnknownProfile,
+    UnknownClub,
+    UnknownIdentity,
+    UnknownLinkCode,
+    TooManyProfiles,
+    IdentityInUse,
+    RegistrationIdentity,
+}
+
+impl std::fmt::Display for DomainError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DomainError::UnknownProfile => write!(f, "unknown profile"),
+            DomainError::UnknownClub => write!(f, "unknown club"),
+            DomainError::UnknownIdentity => write!(f, "unknown identity"),
+            DomainError::UnknownLinkCode => write!(f, "unknown or expired link code"),
+            DomainError::TooManyProfiles => write!(f, "too many profiles"),
+            DomainError::IdentityInUse => write!(f, "identity in use"),
+            DomainError::RegistrationIdentity => write!(f, "identity registered the account"),
+        }
+    }
+}
+
+impl std::error::Error for DomainError {}
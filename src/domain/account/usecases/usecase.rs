@@ -0,0 +1,207 @@
+use std::error::Error;
+use std::sync::Arc;
+use chrono::{Duration, Utc};
+use itertools::Itertools;
+use crate::common::EventPublisher;
+use crate::domain::account::aggregates::{Identity, IdentityLink, LinkedIdentity, ProfileSummary, UserId, UserProfile};
+use crate::domain::account::commands::{LinkIdentity, OfferIdentityLink, UnlinkIdentity, UpdateProfile};
+use crate::domain::account::events::{AccountRegisteredV1, IdentityLinkOfferedV1, IdentityLinkedV1, IdentityUnlinkedV1, ProfileUpdatedV1};
+use crate::domain::account::repositories::{ClubRepository, IdentityLinkRepository, IdentityRepository, ProfileRepository};
+use crate::domain::account::usecases::DomainError;
+
+pub type Result<T> = std::result::Result<T, Box<dyn Error + Send + Sync>>;
+
+pub struct AccountUsecase {
+    profile_repository: Box<dyn ProfileRepository + Send + Sync>,
+    identity_repository: Box<dyn IdentityRepository + Send + Sync>,
+    identity_link_repository: Box<dyn IdentityLinkRepository + Send + Sync>,
+    club_repository: Box<dyn ClubRepository + Send + Sync>,
+    event_publisher: Arc<EventPublisher>,
+}
+
+impl AccountUsecase {
+    // most profiles fetched at once by clients
+    const MAX_BATCH_SIZE: usize = 100;
+    // time to sign in with the identity to link, after offering the link
+    const LINK_CODE_MINUTES: i64 = 10;
+
+    pub fn build(
+        profile_repository: Box<dyn ProfileRepository + Send + Sync>,
+        identity_repository: Box<dyn IdentityRepository + Send + Sync>,
+        identity_link_repository: Box<dyn IdentityLinkRepository + Send + Sync>,
+        club_repository: Box<dyn ClubRepository + Send + Sync>,
+        event_publisher: Arc<EventPublisher>) -> AccountUsecase {
+        AccountUsecase {
+            profile_repository,
+            identity_repository,
+            identity_link_repository,
+            club_repository,
+            event_publisher,
+        }
+    }
+
+    // commands
+    pub async fn update_profile(&self, command: UpdateProfile) -> Result<()> {
+        // rule: a favourite club has to exist
+        if let Some(club) = &command.favourite_club {
+            if !self.club_repository.exist(club).await? {
+                return Err(DomainError::UnknownClub.into());
+            }
+        }
+
+        // note: the profile is created on first update
+        let now = Utc::now();
+        let mut profile = self.profile_repository
+            .get(&command.user)
+            .await?
+            .unwrap_or_else(|| UserProfile::new(command.user.clone(), command.display_name.clone(), now));
+
+        profile.display_name = command.display_name.clone();
+        profile.avatar = command.avatar.clone();
+        profile.bio = command.bio.clone();
+        profile.favourite_club = command.favourite_club.clone();
+        profile.locale = command.locale.clone();
+        profile.updated = now;
+        self.profile_repository.set(&profile).await?;
+
+        let event = ProfileUpdatedV1 {
+            user: command.user,
+            display_name: command.display_name,
+            avatar: command.avatar,
+            bio: command.bio,
+            favourite_club: command.favourite_club,
+            locale: command.locale,
+        };
+        self.event_publisher.publish(&event).await?;
+
+        Ok(())
+    }
+
+    // note: resolves the account of a signed-in identity; unknown identities register a new account
+    pub async fn resolve_identity(&self, identity: Identity) -> Result<UserId> {
+        if let Some(linked) = self.identity_repository.get(&identity).await? {
+            return Ok(linked.account);
+        }
+
+        let linked = LinkedIdentity::register(identity, Utc::now());
+        self.identity_repository.set(&linked).await?;
+
+        let event = AccountRegisteredV1 {
+            account: linked.account.clone(),
+            identity: linked.identity,
+        };
+        self.event_publisher.publish(&event).await?;
+
+        Ok(linked.account)
+    }
+
+    pub async fn offer_identity_link(&self, command: OfferIdentityLink) -> Result<IdentityLink> {
+        let link = IdentityLink::offer(command.account.clone(), Utc::now() + Duration::minutes(AccountUsecase::LINK_CODE_MINUTES));
+        self.identity_link_repository.set(&link).await?;
+
+        let event = IdentityLinkOfferedV1 {
+            account: command.account,
+            expires: link.expires,
+        };
+        self.event_publisher.publish(&event).await?;
+
+        Ok(link)
+    }
+
+    pub async fn link_identity(&self, command: LinkIdentity) -> Result<()> {
+        let now = Utc::now();
+        let link = self.identity_link_repository
+            .get(&command.code)
+            .await?
+            .filter(|link| !link.is_expired(&now))
+            .ok_or(DomainError::UnknownLinkCode)?;
+
+        // rule: a link code is used once
+        if !self.identity_link_repository.unset(&command.code).await? {
+            return Err(DomainError::UnknownLinkCode.into());
+        }
+
+        if let Some(linked) = self.identity_repository.get(&command.identity).await? {
+            // rule: identities belong to a single account, accounts aren't merged
+            if linked.account != link.account {
+                return Err(DomainError::IdentityInUse.into());
+            }
+            return Ok(());
+        }
+
+        let linked = LinkedIdentity::link(command.identity.clone(), link.account.clone(), now);
+        self.identity_repository.set(&linked).await?;
+
+        let event = IdentityLinkedV1 {
+            account: link.account,
+            identity: command.identity,
+        };
+        self.event_publisher.publish(&event).await?;
+
+        Ok(())
+    }
+
+    pub async fn unlink_identity(&self, command: UnlinkIdentity) -> Result<()> {
+        let linked = self.identity_repository
+            .get(&command.identity)
+            .await?
+            .filter(|linked| linked.account == command.account)
+            .ok_or(DomainError::UnknownIdentity)?;
+
+        // rule: an account keeps the identity it was registered with
+        if linked.is_registration() {
+            return Err(DomainError::RegistrationIdentity.into());
+        }
+
+        self.identity_repository.unset(&command.identity).await?;
+
+        let event = IdentityUnlinkedV1 {
+            account: command.account,
+            identity: command.identity,
+        };
+        self.event_publisher.publish(&event).await?;
+
+        Ok(())
+    }
+
+    // queries
+    pub async fn list_identities(&self, account: UserId) -> Result<Vec<LinkedIdentity>> {
+        self.identity_repository
+            .list_by_account(&account).await
+            .map_err(|err| err.into())
+    }
+
+    pub async fn get_profile(&self, user: UserId) -> Result<UserProfile> {
+        let profile = self.profile_repository
+            .get(&user)
+            .await?
+            .ok_or(DomainError::UnknownProfile)?;
+
+        Ok(profile)
+    }
+
+    pub async fn batch_get_profiles(&self, users: Vec<UserId>) -> Result<Vec<UserProfile>> {
+        let users = users.into_iter().unique().collect_vec();
+
+        // rule: profiles are fetched in batches of limited size
+        if users.len() > AccountUsecase::MAX_BATCH_SIZE {
+            return Err(DomainError::TooManyProfiles.into());
+        }
+
+        self.profile_repository
+            .get_many(&users).await
+            .map_err(|err| err.into())
+    }
+
+    // note: summaries embedded in listings of other contexts; users without a profile are left out
+    pub async fn summaries(&self, users: Vec<UserId>) -> Result<Vec<ProfileSummary>> {
+        let users = users.into_iter().unique().collect_vec();
+        if users.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let profiles = self.profile_repository.get_many(&users).await?;
+
+        Ok(profiles.iter().map(UserProfile::summary).collect())
+    }
+}
@@ -0,0 +1,45 @@
+use std::collections::HashMap;
+
+use crate::domain::calendar::aggregates::{Rsvp, RsvpResponse};
+use crate::domain::team::aggregates::{Player, PlayerId, PlayerStatus, Team};
+use crate::domain::account::aggregates::UserId;
+
+// note: availability is transient, a summary of the responses of the players (with an account) and staff
+pub struct Availability {
+    pub user: UserId,
+    pub player: Option<PlayerId>,
+    pub status: Option<PlayerStatus>,
+    pub response: Option<RsvpResponse>, // none when not responded (yet)
+}
+
+// note: players are listed in squad order, followed by staff members who are not playing
+pub fn availability(team: &Team, squad: &[Player], rsvps: &[Rsvp]) -> Vec<Availability> {
+    let responses: HashMap<&UserId, RsvpResponse> = rsvps
+        .iter()
+        .map(|rsvp| (&rsvp.user, rsvp.response))
+        .collect();
+
+    let players = squad
+        .iter()
+        .filter_map(|player| Some(Availability {
+            user: player.user.clone()?,
+            player: Some(player.id.clone()),
+            status: Some(player.status),
+            response: responses.get(player.user.as_ref()?).copied(),
+        }));
+
+    let mut staff: Vec<&UserId> = team.staff
+        .iter()
+        .filter(|member| !squad.iter().any(|player| player.user.as_ref() == Some(*member)))
+        .collect();
+    staff.sort_by_key(|member| member.to_string());
+
+    players
+        .chain(staff.into_iter().map(|member| Availability {
+            user: member.clone(),
+            player: None,
+            status: None,
+            response: responses.get(member).copied(),
+        }))
+        .collect()
+}
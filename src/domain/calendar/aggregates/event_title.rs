@@ -0,0 +1,39 @@
+use std::fmt::{Display, Formatter};
+use chumsky::prelude::{end, filter, Parser, Simple};
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, PartialEq, Eq, Clone)]
+pub struct EventTitle {
+    raw: String,
+}
+
+#[derive(Debug)]
+pub enum ParseError {
+    MalformedInput,
+}
+
+impl EventTitle {
+    fn parser() -> impl Parser<char, EventTitle, Error = Simple<char>> {
+        // title shoud only contain visible (non-control) characters
+        // length between 2 en 100 characters (surrounding whitespace is trimmed)
+        filter(|c: &char| !c.is_control())
+            .repeated()
+            .at_least(2)
+            .at_most(100)
+            .collect::<String>()
+            .map(|title| EventTitle { raw: title })
+            .then_ignore(end())
+    }
+
+    pub fn parse(input: &str) -> Result<EventTitle, ParseError> {
+        EventTitle::parser()
+            .parse(input.trim())
+            .map_err(|_| ParseError::MalformedInput)
+    }
+}
+
+impl Display for EventTitle {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.raw)
+    }
+}
@@ -0,0 +1,83 @@
+use chrono::{DateTime, Utc};
+
+use crate::domain::calendar::aggregates::{Frequency, TeamEvent, TeamEventKind};
+use crate::domain::team::aggregates::Team;
+
+// note: iCalendar (RFC 5545) export of the calendar of a team; recurring events are exported
+// as a single event with a recurrence rule, cancelled occurrences as exceptions
+pub fn to_icalendar(team: &Team, events: &[TeamEvent], stamp: &DateTime<Utc>) -> String {
+    let mut lines = vec![
+        String::from("BEGIN:VCALENDAR"),
+        String::from("VERSION:2.0"),
+        String::from("PRODID:-//social-sports-api//team calendar//EN"),
+        String::from("CALSCALE:GREGORIAN"),
+        format!("X-WR-CALNAME:{}", escape(team.name.to_string().as_str())),
+    ];
+
+    for event in events.iter() {
+        lines.push(String::from("BEGIN:VEVENT"));
+        lines.push(format!("UID:{}@{}", event.id, team.id));
+        lines.push(format!("DTSTAMP:{}", to_date_time(stamp)));
+        lines.push(format!("DTSTART:{}", to_date_time(&event.start)));
+        lines.push(format!("DTEND:{}", to_date_time(&event.end)));
+        lines.push(format!("SUMMARY:{}", escape(event.title.to_string().as_str())));
+        if let Some(location) = &event.location {
+            lines.push(format!("LOCATION:{}", escape(location.to_string().as_str())));
+        }
+        lines.push(format!("CATEGORIES:{}", to_category(&event.kind)));
+        if let Some(recurrence) = &event.recurrence {
+            let frequency = match recurrence.frequency {
+                Frequency::Daily => "DAILY",
+                Frequency::Weekly => "WEEKLY",
+            };
+            lines.push(format!("RRULE:FREQ={};INTERVAL={};UNTIL={}", frequency, recurrence.interval, to_date_time(&recurrence.until)));
+        }
+        for occurrence in event.cancelled_occurrences.iter() {
+            lines.push(format!("EXDATE:{}", to_date_time(occurrence)));
+        }
+        lines.push(format!("STATUS:{}", if event.cancelled { "CANCELLED" } else { "CONFIRMED" }));
+        lines.push(String::from("END:VEVENT"));
+    }
+
+    lines.push(String::from("END:VCALENDAR"));
+
+    lines
+        .iter()
+        .map(|line| fold(line))
+        .map(|line| line + "\r\n")
+        .collect()
+}
+
+fn to_date_time(moment: &DateTime<Utc>) -> String {
+    moment.format("%Y%m%dT%H%M%SZ").to_string()
+}
+
+fn to_category(kind: &TeamEventKind) -> &'static str {
+    match kind {
+        TeamEventKind::Training => "TRAINING",
+        TeamEventKind::Match => "MATCH",
+        TeamEventKind::Social => "SOCIAL",
+    }
+}
+
+fn escape(text: &str) -> String {
+    text.replace('\\', "\\\\")
+        .replace(';', "\\;")
+        .replace(',', "\\,")
+}
+
+// note: lines are folded after 75 octets, without splitting (multi-byte) characters
+fn fold(line: &str) -> String {
+    let mut folded = String::new();
+    let mut length = 0;
+    for c in line.chars() {
+        if length + c.len_utf8() > 75 {
+            folded.push_str("\r\n ");
+            length = 1;
+        }
+        folded.push(c);
+        length += c.len_utf8();
+    }
+
+    folded
+}
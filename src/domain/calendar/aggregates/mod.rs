@@ -0,0 +1,17 @@
+pub mod availability;
+pub mod event_title;
+pub mod icalendar;
+pub mod location;
+pub mod recurrence;
+pub mod rsvp;
+pub mod team_event;
+pub mod team_event_id;
+
+pub use availability::{availability, Availability};
+pub use event_title::EventTitle;
+pub use icalendar::to_icalendar;
+pub use location::Location;
+pub use recurrence::{Frequency, Recurrence};
+pub use rsvp::{Rsvp, RsvpResponse};
+pub use team_event::{Occurrence, TeamEvent, TeamEventKind};
+pub use team_event_id::TeamEventId;
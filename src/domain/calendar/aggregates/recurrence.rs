@@ -0,0 +1,36 @@
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+
+// note: occurrences start every `interval` days or weeks, the last one on or before `until`
+#[derive(Serialize, Deserialize, PartialEq, Eq, Clone)]
+pub struct Recurrence {
+    pub frequency: Frequency,
+    pub interval: u32,
+    pub until: DateTime<Utc>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Eq, Clone, Copy)]
+pub enum Frequency {
+    Daily,
+    Weekly,
+}
+
+impl Recurrence {
+    pub const MAX_INTERVAL: u32 = 4;
+    pub const MAX_OCCURRENCES: usize = 260;
+
+    pub fn new(frequency: Frequency, interval: u32, until: DateTime<Utc>) -> Option<Recurrence> {
+        if interval == 0 || interval > Recurrence::MAX_INTERVAL {
+            return None;
+        }
+
+        Some(Recurrence { frequency, interval, until })
+    }
+
+    pub fn step(&self) -> Duration {
+        match self.frequency {
+            Frequency::Daily => Duration::days(self.interval as i64),
+            Frequency::Weekly => Duration::weeks(self.interval as i64),
+        }
+    }
+}
@@ -0,0 +1,22 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::domain::calendar::aggregates::TeamEventId;
+use crate::domain::account::aggregates::UserId;
+
+// note: one response per user and occurrence, the latest response counts
+#[derive(Serialize, Deserialize)]
+pub struct Rsvp {
+    pub event: TeamEventId,
+    pub occurrence: DateTime<Utc>,
+    pub user: UserId,
+    pub response: RsvpResponse,
+    pub responded: DateTime<Utc>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Eq, Clone, Copy)]
+pub enum RsvpResponse {
+    Attending,
+    NotAttending,
+    Maybe,
+}
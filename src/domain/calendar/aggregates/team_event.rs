@@ -0,0 +1,117 @@
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeSet;
+
+use crate::domain::calendar::aggregates::{EventTitle, Location, Recurrence, TeamEventId};
+use crate::domain::team::aggregates::TeamId;
+
+// note: an event is a single occurrence, or a series of occurrences when it recurs;
+// occurrences are identified by their start
+#[derive(Serialize, Deserialize)]
+pub struct TeamEvent {
+    pub id: TeamEventId,
+    pub team: TeamId,
+    pub kind: TeamEventKind,
+    pub title: EventTitle,
+    pub start: DateTime<Utc>, // of the first occurrence
+    pub end: DateTime<Utc>, // of the first occurrence
+    pub location: Option<Location>,
+    pub recurrence: Option<Recurrence>,
+    pub cancelled: bool, // all occurrences
+    pub cancelled_occurrences: BTreeSet<DateTime<Utc>>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Eq, Clone, Copy)]
+pub enum TeamEventKind {
+    Training,
+    Match,
+    Social,
+}
+
+pub struct Occurrence {
+    pub start: DateTime<Utc>,
+    pub end: DateTime<Utc>,
+    pub cancelled: bool,
+}
+
+impl TeamEvent {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(id: TeamEventId, team: TeamId, kind: TeamEventKind, title: EventTitle, start: DateTime<Utc>, end: DateTime<Utc>, location: Option<Location>, recurrence: Option<Recurrence>) -> TeamEvent {
+        TeamEvent {
+            id,
+            team,
+            kind,
+            title,
+            start,
+            end,
+            location,
+            recurrence,
+            cancelled: false,
+            cancelled_occurrences: BTreeSet::new(),
+        }
+    }
+
+    pub fn duration(&self) -> Duration {
+        self.end - self.start
+    }
+
+    // starts of all occurrences, in order
+    pub fn starts(&self) -> Vec<DateTime<Utc>> {
+        match &self.recurrence {
+            Some(recurrence) => (0..Recurrence::MAX_OCCURRENCES)
+                .map(|index| self.start + recurrence.step() * index as i32)
+                .take_while(|start| *start <= recurrence.until)
+                .collect(),
+            None => vec![self.start],
+        }
+    }
+
+    pub fn last_end(&self) -> DateTime<Utc> {
+        let last = self.starts().last().copied().unwrap_or(self.start);
+        last + self.duration()
+    }
+
+    // occurrences overlapping the range, cancelled ones included
+    pub fn occurrences(&self, from: &DateTime<Utc>, until: &DateTime<Utc>) -> Vec<Occurrence> {
+        let duration = self.duration();
+        self.starts()
+            .into_iter()
+            .filter(|start| *start < *until && *start + duration > *from)
+            .map(|start| Occurrence {
+                start,
+                end: start + duration,
+                cancelled: self.is_cancelled(&start),
+            })
+            .collect()
+    }
+
+    pub fn has_occurrence(&self, start: &DateTime<Utc>) -> bool {
+        self.starts().contains(start)
+    }
+
+    pub fn is_cancelled(&self, start: &DateTime<Utc>) -> bool {
+        self.cancelled || self.cancelled_occurrences.contains(start)
+    }
+
+    pub fn cancel(&mut self) -> bool {
+        let changed = !self.cancelled;
+        self.cancelled = true;
+
+        changed
+    }
+
+    pub fn cancel_occurrence(&mut self, start: &DateTime<Utc>) -> bool {
+        if self.cancelled || !self.has_occurrence(start) {
+            return false;
+        }
+        self.cancelled_occurrences.insert(*start)
+    }
+}
+
+impl PartialEq for TeamEvent {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id
+    }
+}
+
+impl Eq for TeamEvent {}
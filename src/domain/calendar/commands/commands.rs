@@ -0,0 +1,32 @@
+use chrono::{DateTime, Utc};
+use crate::domain::calendar::aggregates::{EventTitle, Location, Recurrence, RsvpResponse, TeamEventId, TeamEventKind};
+use crate::domain::team::aggregates::TeamId;
+use crate::domain::account::aggregates::UserId;
+
+pub struct ScheduleEvent {
+    pub team: TeamId,
+    pub kind: TeamEventKind,
+    pub title: EventTitle,
+    pub start: DateTime<Utc>,
+    pub end: DateTime<Utc>,
+    pub location: Option<Location>,
+    pub recurrence: Option<Recurrence>,
+    pub staff_member: UserId,
+}
+
+pub struct ScheduleEventResult {
+    pub id: TeamEventId,
+}
+
+pub struct CancelEvent {
+    pub id: TeamEventId,
+    pub occurrence: Option<DateTime<Utc>>, // all occurrences when none
+    pub staff_member: UserId,
+}
+
+pub struct RespondToEvent {
+    pub id: TeamEventId,
+    pub occurrence: DateTime<Utc>,
+    pub response: RsvpResponse,
+    pub user: UserId,
+}
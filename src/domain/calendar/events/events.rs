@@ -0,0 +1,52 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use crate::common::Event;
+use crate::domain::calendar::aggregates::{EventTitle, Location, Recurrence, RsvpResponse, TeamEventId, TeamEventKind};
+use crate::domain::team::aggregates::TeamId;
+use crate::domain::account::aggregates::UserId;
+
+#[derive(Serialize, Deserialize)]
+pub struct TeamEventScheduledV1 {
+    pub id: TeamEventId,
+    pub team: TeamId,
+    pub kind: TeamEventKind,
+    pub title: EventTitle,
+    pub start: DateTime<Utc>,
+    pub end: DateTime<Utc>,
+    pub location: Option<Location>,
+    pub recurrence: Option<Recurrence>,
+    pub staff_member: UserId,
+}
+
+impl Event for TeamEventScheduledV1 {
+    fn kind(&self) -> &'static str {
+        "TeamEventScheduledV1"
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct TeamEventCancelledV1 {
+    pub id: TeamEventId,
+    pub occurrence: Option<DateTime<Utc>>,
+    pub staff_member: UserId,
+}
+
+impl Event for TeamEventCancelledV1 {
+    fn kind(&self) -> &'static str {
+        "TeamEventCancelledV1"
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct RespondedToTeamEventV1 {
+    pub id: TeamEventId,
+    pub occurrence: DateTime<Utc>,
+    pub response: RsvpResponse,
+    pub user: UserId,
+}
+
+impl Event for RespondedToTeamEventV1 {
+    fn kind(&self) -> &'static str {
+        "RespondedToTeamEventV1"
+    }
+}
@@ -0,0 +1,24 @@
+use chrono::{DateTime, Utc};
+use crate::domain::calendar::aggregates::TeamEvent;
+use crate::domain::team::aggregates::{Player, Team};
+use crate::domain::account::aggregates::UserId;
+
+#[derive(Debug)]
+pub enum CalendarPolicyViolation {
+    InsufficientPermissions,
+}
+
+type CalendarPolicyResult = Result<(), CalendarPolicyViolation>;
+
+pub struct CalendarPolicyExecutionContext {
+    pub team: Team,
+    pub squad: Vec<Player>,
+    pub user: UserId,
+}
+
+pub trait CalendarPolicy {
+    fn allow_schedule(context: &CalendarPolicyExecutionContext) -> CalendarPolicyResult;
+    fn allow_cancel(context: &CalendarPolicyExecutionContext, event: &TeamEvent) -> CalendarPolicyResult;
+    fn allow_respond(context: &CalendarPolicyExecutionContext, event: &TeamEvent, occurrence: &DateTime<Utc>) -> CalendarPolicyResult;
+    fn allow_view_availability(context: &CalendarPolicyExecutionContext, event: &TeamEvent) -> CalendarPolicyResult;
+}
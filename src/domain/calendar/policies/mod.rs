@@ -0,0 +1,3 @@
+pub mod calendar_policy;
+
+pub use calendar_policy::{CalendarPolicy, CalendarPolicyExecutionContext, CalendarPolicyViolation};
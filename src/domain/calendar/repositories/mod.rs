@@ -0,0 +1,9 @@
+pub mod team_event_repository;
+pub mod rsvp_repository;
+pub mod team_repository;
+pub mod player_repository;
+
+pub use team_event_repository::TeamEventRepository;
+pub use rsvp_repository::RsvpRepository;
+pub use team_repository::TeamRepository;
+pub use player_repository::PlayerRepository;
@@ -0,0 +1,7 @@
+use crate::common::RepositoryResult;
+use crate::domain::team::aggregates::{Player, TeamId};
+
+#[tonic::async_trait]
+pub trait PlayerRepository {
+    async fn list_by_team(&self, team: &TeamId) -> RepositoryResult<Vec<Player>>;
+}
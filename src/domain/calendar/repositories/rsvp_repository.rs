@@ -0,0 +1,9 @@
+use chrono::{DateTime, Utc};
+use crate::common::RepositoryResult;
+use crate::domain::calendar::aggregates::{Rsvp, TeamEventId};
+
+#[tonic::async_trait]
+pub trait RsvpRepository {
+    async fn list(&self, event: &TeamEventId, occurrence: &DateTime<Utc>) -> RepositoryResult<Vec<Rsvp>>;
+    async fn set(&self, rsvp: &Rsvp) -> RepositoryResult<()>;
+}
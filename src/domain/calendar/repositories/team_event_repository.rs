@@ -0,0 +1,12 @@
+use chrono::{DateTime, Utc};
+use crate::common::RepositoryResult;
+use crate::domain::calendar::aggregates::{TeamEvent, TeamEventId};
+use crate::domain::team::aggregates::TeamId;
+
+#[tonic::async_trait]
+pub trait TeamEventRepository {
+    // note: events with at least one occurrence in the range (unbounded when none)
+    async fn list(&self, team: &TeamId, from: &Option<DateTime<Utc>>, until: &Option<DateTime<Utc>>) -> RepositoryResult<Vec<TeamEvent>>;
+    async fn get(&self, id: &TeamEventId) -> RepositoryResult<Option<TeamEvent>>;
+    async fn set(&self, event: &TeamEvent) -> RepositoryResult<()>;
+}
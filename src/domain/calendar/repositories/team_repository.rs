@@ -0,0 +1,7 @@
+use crate::common::RepositoryResult;
+use crate::domain::team::aggregates::{Team, TeamId};
+
+#[tonic::async_trait]
+pub trait TeamRepository {
+    async fn get(&self, id: &TeamId) -> RepositoryResult<Option<Team>>;
+}
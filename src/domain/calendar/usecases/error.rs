@@ -0,0 +1,30 @@
+use std::fmt::Formatter;
+
+#[derive(Debug)]
+pub enum DomainError {
+    UnknownTeam,
+    UnknownEvent,
+    UnknownOccurrence,
+    InsufficientPermissions,
+    InvalidDateRange,
+    InvalidRecurrence,
+    EventCancelled,
+    EventStarted,
+}
+
+impl std::fmt::Display for DomainError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DomainError::UnknownTeam => write!(f, "unknown team"),
+            DomainError::UnknownEvent => write!(f, "unknown event"),
+            DomainError::UnknownOccurrence => write!(f, "unknown occurrence"),
+            DomainError::InsufficientPermissions => write!(f, "insufficient permissions"),
+            DomainError::InvalidDateRange => write!(f, "invalid date range"),
+            DomainError::InvalidRecurrence => write!(f, "invalid recurrence"),
+            DomainError::EventCancelled => write!(f, "event cancelled"),
+            DomainError::EventStarted => write!(f, "event started"),
+        }
+    }
+}
+
+impl std::error::Error for DomainError {}
@@ -0,0 +1,236 @@
+use std::error::Error;
+use std::sync::Arc;
+use chrono::{DateTime, Duration, Utc};
+use crate::common::EventPublisher;
+use crate::domain::account::aggregates::UserId;
+use crate::domain::calendar::aggregates::{availability, to_icalendar, Availability, Rsvp, TeamEvent, TeamEventId};
+use crate::domain::calendar::commands::{CancelEvent, RespondToEvent, ScheduleEvent, ScheduleEventResult};
+use crate::domain::calendar::events::{RespondedToTeamEventV1, TeamEventCancelledV1, TeamEventScheduledV1};
+use crate::domain::calendar::repositories::{PlayerRepository, RsvpRepository, TeamEventRepository, TeamRepository};
+use crate::domain::calendar::usecases::DomainError;
+use crate::domain::team::aggregates::{Player, Team, TeamId};
+
+pub type Result<T> = std::result::Result<T, Box<dyn Error + Send + Sync>>;
+
+pub struct CalendarUsecase {
+    team_event_repository: Box<dyn TeamEventRepository + Send + Sync>,
+    rsvp_repository: Box<dyn RsvpRepository + Send + Sync>,
+    team_repository: Box<dyn TeamRepository + Send + Sync>,
+    player_repository: Box<dyn PlayerRepository + Send + Sync>,
+    event_publisher: Arc<EventPublisher>,
+}
+
+impl CalendarUsecase {
+    // longest range of occurrences listed at once
+    const MAX_RANGE_DAYS: i64 = 366;
+
+    pub fn build(
+        team_event_repository: Box<dyn TeamEventRepository + Send + Sync>,
+        rsvp_repository: Box<dyn RsvpRepository + Send + Sync>,
+        team_repository: Box<dyn TeamRepository + Send + Sync>,
+        player_repository: Box<dyn PlayerRepository + Send + Sync>,
+        event_publisher: Arc<EventPublisher>) -> CalendarUsecase {
+        CalendarUsecase {
+            team_event_repository,
+            rsvp_repository,
+            team_repository,
+            player_repository,
+            event_publisher,
+        }
+    }
+
+    // commands
+    pub async fn schedule_event(&self, command: ScheduleEvent) -> Result<ScheduleEventResult> {
+        self.staffed(&command.team, &command.staff_member).await?;
+
+        // rule: events end after they start
+        if command.end <= command.start {
+            return Err(DomainError::InvalidDateRange.into());
+        }
+
+        // rule: occurrences of a recurring event don't overlap, and the series starts with the event
+        if let Some(recurrence) = &command.recurrence {
+            if recurrence.until < command.start || command.end - command.start > recurrence.step() {
+                return Err(DomainError::InvalidRecurrence.into());
+            }
+        }
+
+        let id = TeamEventId::random();
+        let event = TeamEvent::new(id.clone(), command.team, command.kind, command.title, command.start, command.end, command.location, command.recurrence);
+        self.team_event_repository.set(&event).await?;
+
+        let scheduled = TeamEventScheduledV1 {
+            id: event.id,
+            team: event.team,
+            kind: event.kind,
+            title: event.title,
+            start: event.start,
+            end: event.end,
+            location: event.location,
+            recurrence: event.recurrence,
+            staff_member: command.staff_member,
+        };
+        self.event_publisher.publish(&scheduled).await?;
+
+        Ok(ScheduleEventResult {
+            id,
+        })
+    }
+
+    pub async fn cancel_event(&self, command: CancelEvent) -> Result<()> {
+        let mut event = self.event(&command.id).await?;
+        self.staffed(&event.team, &command.staff_member).await?;
+
+        let cancelled = match &command.occurrence {
+            Some(occurrence) => {
+                if !event.has_occurrence(occurrence) {
+                    return Err(DomainError::UnknownOccurrence.into());
+                }
+                event.cancel_occurrence(occurrence)
+            },
+            None => event.cancel(),
+        };
+
+        if !cancelled {
+            return Ok(());
+        }
+        self.team_event_repository.set(&event).await?;
+
+        let cancelled = TeamEventCancelledV1 {
+            id: command.id,
+            occurrence: command.occurrence,
+            staff_member: command.staff_member,
+        };
+        self.event_publisher.publish(&cancelled).await?;
+
+        Ok(())
+    }
+
+    pub async fn respond_to_event(&self, command: RespondToEvent) -> Result<()> {
+        let event = self.event(&command.id).await?;
+        let (team, squad) = self.squad(&event.team).await?;
+
+        // rule: players (with an account) and staff of the team respond
+        if !is_staff(&team, &command.user) && !is_player(&squad, &command.user) {
+            return Err(DomainError::InsufficientPermissions.into());
+        }
+
+        if !event.has_occurrence(&command.occurrence) {
+            return Err(DomainError::UnknownOccurrence.into());
+        }
+
+        if event.is_cancelled(&command.occurrence) {
+            return Err(DomainError::EventCancelled.into());
+        }
+
+        // rule: responses are accepted until the occurrence starts
+        let now = Utc::now();
+        if command.occurrence <= now {
+            return Err(DomainError::EventStarted.into());
+        }
+
+        let rsvp = Rsvp {
+            event: command.id.clone(),
+            occurrence: command.occurrence,
+            user: command.user.clone(),
+            response: command.response,
+            responded: now,
+        };
+        self.rsvp_repository.set(&rsvp).await?;
+
+        let responded = RespondedToTeamEventV1 {
+            id: command.id,
+            occurrence: command.occurrence,
+            response: command.response,
+            user: command.user,
+        };
+        self.event_publisher.publish(&responded).await?;
+
+        Ok(())
+    }
+
+    async fn event(&self, id: &TeamEventId) -> Result<TeamEvent> {
+        let event = self.team_event_repository
+            .get(id)
+            .await?
+            .ok_or(DomainError::UnknownEvent)?;
+
+        Ok(event)
+    }
+
+    // loads a team on behalf of one of its staff members
+    async fn staffed(&self, id: &TeamId, staff_member: &UserId) -> Result<Team> {
+        let team = self.team_repository
+            .get(id)
+            .await?
+            .ok_or(DomainError::UnknownTeam)?;
+
+        // rule: only staff manage the calendar
+        if !is_staff(&team, staff_member) {
+            return Err(DomainError::InsufficientPermissions.into());
+        }
+
+        Ok(team)
+    }
+
+    async fn squad(&self, id: &TeamId) -> Result<(Team, Vec<Player>)> {
+        let team = self.team_repository
+            .get(id)
+            .await?
+            .ok_or(DomainError::UnknownTeam)?;
+        let squad = self.player_repository.list_by_team(id).await?;
+
+        Ok((team, squad))
+    }
+
+    // queries
+    pub async fn list_events(&self, team: TeamId, from: DateTime<Utc>, until: DateTime<Utc>) -> Result<Vec<TeamEvent>> {
+        // rule: the date range can't be reversed, nor span more than a year
+        if from >= until || until - from > Duration::days(CalendarUsecase::MAX_RANGE_DAYS) {
+            return Err(DomainError::InvalidDateRange.into());
+        }
+
+        self.team_event_repository
+            .list(&team, &Some(from), &Some(until)).await
+            .map_err(|err| err.into())
+    }
+
+    pub async fn get_availability(&self, id: TeamEventId, occurrence: DateTime<Utc>, viewer: UserId) -> Result<Vec<Availability>> {
+        let event = self.event(&id).await?;
+        let (team, squad) = self.squad(&event.team).await?;
+
+        // rule: availability is for staff to pick a squad
+        if !is_staff(&team, &viewer) {
+            return Err(DomainError::InsufficientPermissions.into());
+        }
+
+        if !event.has_occurrence(&occurrence) {
+            return Err(DomainError::UnknownOccurrence.into());
+        }
+
+        let rsvps = self.rsvp_repository.list(&id, &occurrence).await?;
+
+        Ok(availability(&team, &squad, &rsvps))
+    }
+
+    pub async fn export_calendar(&self, team: TeamId, viewer: UserId) -> Result<String> {
+        let (team, squad) = self.squad(&team).await?;
+
+        // rule: the calendar is shared with players and staff of the team
+        if !is_staff(&team, &viewer) && !is_player(&squad, &viewer) {
+            return Err(DomainError::InsufficientPermissions.into());
+        }
+
+        let events = self.team_event_repository.list(&team.id, &None, &None).await?;
+
+        Ok(to_icalendar(&team, &events, &Utc::now()))
+    }
+}
+
+fn is_staff(team: &Team, user: &UserId) -> bool {
+    team.staff.contains(user)
+}
+
+fn is_player(squad: &[Player], user: &UserId) -> bool {
+    squad.iter().any(|player| player.user.as_ref() == Some(user))
+}
@@ -26,6 +26,7 @@ impl ClubUsecase {
     }
 
     // commands
+    #[allow(clippy::new_ret_no_self)]
     pub async fn new(&self, command: New) -> Result<NewResult> {
         let id = ClubId::random();
         let name = command.name;
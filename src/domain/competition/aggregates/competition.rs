@@ -0,0 +1,37 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+
+use crate::domain::competition::aggregates::{CompetitionId, CompetitionName};
+use crate::domain::account::aggregates::UserId;
+
+#[derive(Serialize, Deserialize)]
+pub struct Competition {
+    pub id: CompetitionId,
+    pub name: CompetitionName,
+    pub founded: DateTime<Utc>,
+    pub organisers: HashSet<UserId>,
+}
+
+impl Competition {
+    pub fn new(id: CompetitionId, name: CompetitionName, founded: DateTime<Utc>, organiser: UserId) -> Competition {
+        Competition {
+            id,
+            name,
+            founded,
+            organisers: HashSet::from([organiser]),
+        }
+    }
+
+    pub fn is_organiser(&self, user: &UserId) -> bool {
+        self.organisers.contains(user)
+    }
+}
+
+impl PartialEq for Competition {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id
+    }
+}
+
+impl Eq for Competition {}
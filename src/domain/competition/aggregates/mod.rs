@@ -0,0 +1,17 @@
+pub mod competition;
+pub mod competition_id;
+pub mod competition_name;
+pub mod points_rules;
+pub mod season;
+pub mod season_id;
+pub mod season_name;
+pub mod standing;
+
+pub use competition::Competition;
+pub use competition_id::CompetitionId;
+pub use competition_name::CompetitionName;
+pub use points_rules::{BonusRule, PointsRules, TieBreaker};
+pub use season::Season;
+pub use season_id::SeasonId;
+pub use season_name::SeasonName;
+pub use standing::{standings, MatchResult, Standing};
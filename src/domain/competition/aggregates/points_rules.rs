@@ -0,0 +1,60 @@
+use serde::{Deserialize, Serialize};
+
+// note: points awarded per match, bonus points are added on top of the result
+#[derive(Serialize, Deserialize, PartialEq, Eq, Clone)]
+pub struct PointsRules {
+    pub win: u32,
+    pub draw: u32,
+    pub loss: u32,
+    pub bonus: Vec<BonusRule>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Eq, Clone, Copy)]
+pub enum BonusRule {
+    ScoredAtLeast { goals: u32, points: u32 }, // e.g. rugby try bonus
+    LostByAtMost { margin: u32, points: u32 }, // e.g. rugby losing bonus
+}
+
+// note: tie-breakers are applied in order, when teams are level on points
+#[derive(Serialize, Deserialize, PartialEq, Eq, Hash, Clone, Copy)]
+pub enum TieBreaker {
+    GoalDifference,
+    GoalsScored,
+    HeadToHead, // points from the matches between the tied teams
+    Wins,
+}
+
+impl Default for PointsRules {
+    fn default() -> Self {
+        PointsRules {
+            win: 3,
+            draw: 1,
+            loss: 0,
+            bonus: Vec::new(),
+        }
+    }
+}
+
+impl PointsRules {
+    pub const MAX_BONUS_RULES: usize = 5;
+
+    // points for a team that scored `scored` and conceded `conceded`
+    pub fn points(&self, scored: u32, conceded: u32) -> u32 {
+        let result = match scored.cmp(&conceded) {
+            std::cmp::Ordering::Greater => self.win,
+            std::cmp::Ordering::Equal => self.draw,
+            std::cmp::Ordering::Less => self.loss,
+        };
+
+        let bonus: u32 = self.bonus
+            .iter()
+            .map(|rule| match rule {
+                BonusRule::ScoredAtLeast { goals, points } if scored >= *goals => *points,
+                BonusRule::LostByAtMost { margin, points } if scored < conceded && conceded - scored <= *margin => *points,
+                _ => 0,
+            })
+            .sum();
+
+        result + bonus
+    }
+}
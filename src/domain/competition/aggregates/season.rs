@@ -0,0 +1,48 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+
+use crate::domain::competition::aggregates::{CompetitionId, PointsRules, SeasonId, SeasonName, TieBreaker};
+use crate::domain::team::aggregates::TeamId;
+
+#[derive(Serialize, Deserialize)]
+pub struct Season {
+    pub id: SeasonId,
+    pub competition: CompetitionId,
+    pub name: SeasonName,
+    pub teams: HashSet<TeamId>,
+    pub points: PointsRules,
+    pub tie_breakers: Vec<TieBreaker>,
+}
+
+impl Season {
+    pub fn new(id: SeasonId, competition: CompetitionId, name: SeasonName, points: PointsRules, tie_breakers: Vec<TieBreaker>) -> Season {
+        Season {
+            id,
+            competition,
+            name,
+            teams: HashSet::new(),
+            points,
+            tie_breakers,
+        }
+    }
+
+    pub fn add_team(&mut self, team: &TeamId) -> bool {
+        self.teams.insert(team.clone())
+    }
+
+    pub fn remove_team(&mut self, team: &TeamId) -> bool {
+        self.teams.remove(team)
+    }
+
+    pub fn has_team(&self, team: &TeamId) -> bool {
+        self.teams.contains(team)
+    }
+}
+
+impl PartialEq for Season {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id
+    }
+}
+
+impl Eq for Season {}
@@ -0,0 +1,41 @@
+use std::fmt::{Display, Formatter};
+use chumsky::prelude::{end, filter, Parser, Simple};
+use chumsky::text::TextParser;
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, PartialEq, Eq, Clone)]
+pub struct SeasonName {
+    raw: String,
+}
+
+#[derive(Debug)]
+pub enum ParseError {
+    MalformedInput,
+}
+
+impl SeasonName {
+    fn parser() -> impl Parser<char, SeasonName, Error = Simple<char>> {
+        // name can consists of alphanumeric (unicode), space, slashes and dashes (e.g. 2026/27)
+        // length must be between 2 and 50 characters (runes)
+        filter(|c: &char| c.is_alphanumeric() || *c == ' ' || *c == '/' || *c == '-')
+            .repeated()
+            .at_least(2)
+            .at_most(50)
+            .padded()
+            .collect::<String>()
+            .map(|name| SeasonName { raw: name })
+            .then_ignore(end())
+    }
+
+    pub fn parse(input: &str) -> Result<SeasonName, ParseError> {
+        SeasonName::parser()
+            .parse(input)
+            .map_err(|_| ParseError::MalformedInput)
+    }
+}
+
+impl Display for SeasonName {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.raw)
+    }
+}
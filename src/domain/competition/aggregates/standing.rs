@@ -0,0 +1,257 @@
+use std::cmp::{Ordering, Reverse};
+use std::collections::HashMap;
+
+use crate::domain::competition::aggregates::{Season, TieBreaker};
+use crate::domain::fixture::aggregates::Score;
+use crate::domain::team::aggregates::TeamId;
+
+// note: results & standings are transient; the table is a projection over finalised results
+pub struct MatchResult {
+    pub home: TeamId,
+    pub away: TeamId,
+    pub score: Score,
+}
+
+pub struct Standing {
+    pub position: u32,
+    pub team: TeamId,
+    pub played: u32,
+    pub won: u32,
+    pub drawn: u32,
+    pub lost: u32,
+    pub scored: u32,
+    pub conceded: u32,
+    pub points: u32,
+}
+
+impl Standing {
+    fn new(team: TeamId) -> Standing {
+        Standing {
+            position: 0,
+            team,
+            played: 0,
+            won: 0,
+            drawn: 0,
+            lost: 0,
+            scored: 0,
+            conceded: 0,
+            points: 0,
+        }
+    }
+
+    pub fn goal_difference(&self) -> i64 {
+        self.scored as i64 - self.conceded as i64
+    }
+
+    fn record(&mut self, scored: u32, conceded: u32, points: u32) {
+        self.played += 1;
+        self.scored += scored;
+        self.conceded += conceded;
+        self.points += points;
+        match scored.cmp(&conceded) {
+            Ordering::Greater => self.won += 1,
+            Ordering::Equal => self.drawn += 1,
+            Ordering::Less => self.lost += 1,
+        }
+    }
+}
+
+// note: every team of the season is listed, results involving other teams are ignored
+pub fn standings(season: &Season, results: &[MatchResult]) -> Vec<Standing> {
+    let results: Vec<&MatchResult> = results
+        .iter()
+        .filter(|result| season.has_team(&result.home) && season.has_team(&result.away))
+        .collect();
+
+    let mut table: HashMap<TeamId, Standing> = season.teams
+        .iter()
+        .map(|team| (team.clone(), Standing::new(team.clone())))
+        .collect();
+
+    for result in results.iter() {
+        let (home, away) = (result.score.home, result.score.away);
+        if let Some(standing) = table.get_mut(&result.home) {
+            standing.record(home, away, season.points.points(home, away));
+        }
+        if let Some(standing) = table.get_mut(&result.away) {
+            standing.record(away, home, season.points.points(away, home));
+        }
+    }
+
+    let mut standings: Vec<Standing> = table.into_values().collect();
+    standings.sort_by_key(|standing| Reverse(standing.points));
+
+    let mut standings: Vec<Standing> = split(standings, |standing| standing.points as i64)
+        .into_iter()
+        .flat_map(|tied| rank(tied, &season.tie_breakers, season, &results))
+        .collect();
+
+    for (index, standing) in standings.iter_mut().enumerate() {
+        standing.position = index as u32 + 1;
+    }
+
+    standings
+}
+
+// note: orders a group of teams level on points; each tie-breaker ranks the whole group, the teams still level
+// are ranked again from the same tie-breaker (as head-to-head is limited to the matches between them),
+// or the next one when nothing changed. ties left over are ordered by team id, for a stable table
+fn rank(mut tied: Vec<Standing>, tie_breakers: &[TieBreaker], season: &Season, results: &[&MatchResult]) -> Vec<Standing> {
+    if tied.len() <= 1 {
+        return tied;
+    }
+
+    let (tie_breaker, next) = match tie_breakers.split_first() {
+        Some(split) => split,
+        None => {
+            tied.sort_by_key(|standing| standing.team.to_string());
+            return tied;
+        },
+    };
+
+    let values = values(tie_breaker, &tied, season, results);
+    tied.sort_by_key(|standing| Reverse(values[&standing.team]));
+
+    let groups = split(tied, |standing| values[&standing.team]);
+    if groups.len() == 1 {
+        return groups
+            .into_iter()
+            .flat_map(|tied| rank(tied, next, season, results))
+            .collect();
+    }
+
+    groups
+        .into_iter()
+        .flat_map(|tied| rank(tied, tie_breakers, season, results))
+        .collect()
+}
+
+// value of each tied team by the tie-breaker, higher is better
+fn values(tie_breaker: &TieBreaker, tied: &[Standing], season: &Season, results: &[&MatchResult]) -> HashMap<TeamId, i64> {
+    match tie_breaker {
+        TieBreaker::GoalDifference => tied.iter().map(|standing| (standing.team.clone(), standing.goal_difference())).collect(),
+        TieBreaker::GoalsScored => tied.iter().map(|standing| (standing.team.clone(), standing.scored as i64)).collect(),
+        TieBreaker::Wins => tied.iter().map(|standing| (standing.team.clone(), standing.won as i64)).collect(),
+        TieBreaker::HeadToHead => {
+            // note: points from the matches between the tied teams only
+            let mut points: HashMap<TeamId, i64> = tied.iter().map(|standing| (standing.team.clone(), 0)).collect();
+            for result in results.iter() {
+                if !points.contains_key(&result.home) || !points.contains_key(&result.away) {
+                    continue;
+                }
+
+                let (home, away) = (result.score.home, result.score.away);
+                *points.entry(result.home.clone()).or_default() += season.points.points(home, away) as i64;
+                *points.entry(result.away.clone()).or_default() += season.points.points(away, home) as i64;
+            }
+            points
+        },
+    }
+}
+
+// splits sorted standings into groups of equal value
+fn split(standings: Vec<Standing>, value: impl Fn(&Standing) -> i64) -> Vec<Vec<Standing>> {
+    let mut groups: Vec<Vec<Standing>> = Vec::new();
+    for standing in standings {
+        match groups.last_mut() {
+            Some(group) if value(&group[0]) == value(&standing) => group.push(standing),
+            _ => groups.push(vec![standing]),
+        }
+    }
+    groups
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::competition::aggregates::{BonusRule, CompetitionId, PointsRules, SeasonId, SeasonName};
+
+    fn season(teams: &[&TeamId], points: PointsRules, tie_breakers: Vec<TieBreaker>) -> Season {
+        let mut season = Season::new(SeasonId::random(), CompetitionId::random(), SeasonName::parse("2024").unwrap(), points, tie_breakers);
+        for team in teams {
+            season.add_team(team);
+        }
+        season
+    }
+
+    fn result(home: &TeamId, away: &TeamId, home_score: u32, away_score: u32) -> MatchResult {
+        MatchResult {
+            home: home.clone(),
+            away: away.clone(),
+            score: Score::new(home_score, away_score),
+        }
+    }
+
+    fn order(standings: &[Standing]) -> Vec<String> {
+        standings.iter().map(|standing| standing.team.to_string()).collect()
+    }
+
+    fn ids(teams: &[&TeamId]) -> Vec<String> {
+        teams.iter().map(|team| team.to_string()).collect()
+    }
+
+    #[test]
+    fn bonus_points_are_added_to_the_result() {
+        let (a, b) = (TeamId::random(), TeamId::random());
+        let points = PointsRules {
+            win: 4,
+            draw: 2,
+            loss: 0,
+            bonus: vec![
+                BonusRule::ScoredAtLeast { goals: 4, points: 1 },
+                BonusRule::LostByAtMost { margin: 7, points: 1 },
+            ],
+        };
+        let season = season(&[&a, &b], points, vec![]);
+
+        let standings = standings(&season, &[result(&a, &b, 30, 25), result(&b, &a, 4, 20)]);
+
+        assert_eq!(order(&standings), ids(&[&a, &b]));
+        assert_eq!(standings[0].points, 4 + 1 + 4 + 1);
+        // note: b scored at least 4 twice, and lost by at most 7 once
+        assert_eq!(standings[1].points, 1 + 1 + 1);
+    }
+
+    #[test]
+    fn goal_difference_breaks_a_tie_on_points() {
+        let (a, b, c) = (TeamId::random(), TeamId::random(), TeamId::random());
+        let season = season(&[&a, &b, &c], PointsRules::default(), vec![TieBreaker::GoalDifference]);
+
+        let standings = standings(&season, &[result(&a, &c, 1, 0), result(&b, &c, 5, 0)]);
+
+        assert_eq!(order(&standings), ids(&[&b, &a, &c]));
+        assert_eq!(standings.iter().map(|standing| standing.position).collect::<Vec<u32>>(), vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn head_to_head_cycle_falls_through_to_the_next_tie_breaker() {
+        let (a, b, c) = (TeamId::random(), TeamId::random(), TeamId::random());
+        let season = season(&[&a, &b, &c], PointsRules::default(), vec![TieBreaker::HeadToHead, TieBreaker::GoalsScored]);
+
+        // note: a beat b, b beat c, c beat a; level on points, also between them
+        let standings = standings(&season, &[result(&a, &b, 3, 0), result(&b, &c, 2, 0), result(&c, &a, 1, 0)]);
+
+        assert_eq!(order(&standings), ids(&[&a, &b, &c]));
+    }
+
+    #[test]
+    fn head_to_head_ranks_the_whole_group_then_the_teams_still_level() {
+        let (a, b, c, d) = (TeamId::random(), TeamId::random(), TeamId::random(), TeamId::random());
+        let season = season(&[&a, &b, &c, &d], PointsRules::default(), vec![TieBreaker::HeadToHead, TieBreaker::GoalsScored]);
+
+        let standings = standings(&season, &[
+            result(&a, &b, 1, 0),
+            result(&a, &c, 1, 0),
+            result(&b, &c, 0, 0),
+            result(&a, &d, 0, 0),
+            result(&b, &d, 1, 0),
+            result(&d, &b, 0, 1),
+            result(&c, &d, 2, 0),
+            result(&d, &c, 0, 1),
+        ]);
+
+        // note: a, b and c have 7 points; a leads between them, b and c drew
+        assert_eq!(standings.iter().map(|standing| standing.points).collect::<Vec<u32>>(), vec![7, 7, 7, 1]);
+        assert_eq!(order(&standings), ids(&[&a, &c, &b, &d]));
+    }
+}
@@ -0,0 +1,36 @@
+use crate::domain::competition::aggregates::{CompetitionId, CompetitionName, PointsRules, SeasonId, SeasonName, TieBreaker};
+use crate::domain::team::aggregates::TeamId;
+use crate::domain::account::aggregates::UserId;
+
+pub struct New {
+    pub name: CompetitionName,
+    pub organiser: UserId,
+}
+
+pub struct NewResult {
+    pub id: CompetitionId,
+}
+
+pub struct NewSeason {
+    pub competition: CompetitionId,
+    pub name: SeasonName,
+    pub points: PointsRules,
+    pub tie_breakers: Vec<TieBreaker>,
+    pub organiser: UserId,
+}
+
+pub struct NewSeasonResult {
+    pub id: SeasonId,
+}
+
+pub struct AddTeamToSeason {
+    pub season: SeasonId,
+    pub team: TeamId,
+    pub organiser: UserId,
+}
+
+pub struct RemoveTeamFromSeason {
+    pub season: SeasonId,
+    pub team: TeamId,
+    pub organiser: UserId,
+}
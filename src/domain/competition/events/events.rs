@@ -0,0 +1,62 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use crate::common::Event;
+use crate::domain::competition::aggregates::{CompetitionId, CompetitionName, PointsRules, SeasonId, SeasonName, TieBreaker};
+use crate::domain::team::aggregates::TeamId;
+use crate::domain::account::aggregates::UserId;
+
+#[derive(Serialize, Deserialize)]
+pub struct CompetitionAddedV1 {
+    pub id: CompetitionId,
+    pub name: CompetitionName,
+    pub founded: DateTime<Utc>,
+    pub organiser: UserId,
+}
+
+impl Event for CompetitionAddedV1 {
+    fn kind(&self) -> &'static str {
+        "CompetitionAddedV1"
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct SeasonAddedV1 {
+    pub id: SeasonId,
+    pub competition: CompetitionId,
+    pub name: SeasonName,
+    pub points: PointsRules,
+    pub tie_breakers: Vec<TieBreaker>,
+    pub organiser: UserId,
+}
+
+impl Event for SeasonAddedV1 {
+    fn kind(&self) -> &'static str {
+        "SeasonAddedV1"
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct TeamAddedToSeasonV1 {
+    pub season: SeasonId,
+    pub team: TeamId,
+    pub organiser: UserId,
+}
+
+impl Event for TeamAddedToSeasonV1 {
+    fn kind(&self) -> &'static str {
+        "TeamAddedToSeasonV1"
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct TeamRemovedFromSeasonV1 {
+    pub season: SeasonId,
+    pub team: TeamId,
+    pub organiser: UserId,
+}
+
+impl Event for TeamRemovedFromSeasonV1 {
+    fn kind(&self) -> &'static str {
+        "TeamRemovedFromSeasonV1"
+    }
+}
@@ -0,0 +1,21 @@
+use crate::domain::competition::aggregates::{Competition, Season};
+use crate::domain::team::aggregates::TeamId;
+use crate::domain::account::aggregates::UserId;
+
+#[derive(Debug)]
+pub enum CompetitionPolicyViolation {
+    InsufficientPermissions,
+}
+
+type CompetitionPolicyResult = Result<(), CompetitionPolicyViolation>;
+
+pub struct CompetitionPolicyExecutionContext {
+    pub competition: Competition,
+    pub user: UserId,
+}
+
+pub trait CompetitionPolicy {
+    fn allow_new_season(context: &CompetitionPolicyExecutionContext) -> CompetitionPolicyResult;
+    fn allow_add_team(context: &CompetitionPolicyExecutionContext, season: &Season, team: &TeamId) -> CompetitionPolicyResult;
+    fn allow_remove_team(context: &CompetitionPolicyExecutionContext, season: &Season, team: &TeamId) -> CompetitionPolicyResult;
+}
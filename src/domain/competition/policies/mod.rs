@@ -0,0 +1,3 @@
+pub mod competition_policy;
+
+pub use competition_policy::{CompetitionPolicy, CompetitionPolicyExecutionContext, CompetitionPolicyViolation};
@@ -0,0 +1,9 @@
+use crate::common::RepositoryResult;
+use crate::domain::competition::aggregates::{Competition, CompetitionId};
+
+#[tonic::async_trait]
+pub trait CompetitionRepository {
+    async fn list(&self, after: &Option<CompetitionId>) -> RepositoryResult<Vec<Competition>>;
+    async fn get(&self, id: &CompetitionId) -> RepositoryResult<Option<Competition>>;
+    async fn set(&self, competition: &Competition) -> RepositoryResult<()>;
+}
@@ -0,0 +1,9 @@
+pub mod competition_repository;
+pub mod season_repository;
+pub mod result_repository;
+pub mod team_repository;
+
+pub use competition_repository::CompetitionRepository;
+pub use season_repository::SeasonRepository;
+pub use result_repository::ResultRepository;
+pub use team_repository::TeamRepository;
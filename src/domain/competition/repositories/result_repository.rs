@@ -0,0 +1,8 @@
+use crate::common::RepositoryResult;
+use crate::domain::competition::aggregates::{MatchResult, SeasonId};
+
+// note: results of finished matches only
+#[tonic::async_trait]
+pub trait ResultRepository {
+    async fn list(&self, season: &SeasonId) -> RepositoryResult<Vec<MatchResult>>;
+}
@@ -0,0 +1,9 @@
+use crate::common::RepositoryResult;
+use crate::domain::competition::aggregates::{CompetitionId, Season, SeasonId};
+
+#[tonic::async_trait]
+pub trait SeasonRepository {
+    async fn list(&self, competition: &CompetitionId, after: &Option<SeasonId>) -> RepositoryResult<Vec<Season>>;
+    async fn get(&self, id: &SeasonId) -> RepositoryResult<Option<Season>>;
+    async fn set(&self, season: &Season) -> RepositoryResult<()>;
+}
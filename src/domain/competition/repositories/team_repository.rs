@@ -0,0 +1,7 @@
+use crate::common::RepositoryResult;
+use crate::domain::team::aggregates::TeamId;
+
+#[tonic::async_trait]
+pub trait TeamRepository {
+    async fn exist(&self, id: &TeamId) -> RepositoryResult<bool>;
+}
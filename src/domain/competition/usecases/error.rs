@@ -0,0 +1,24 @@
+use std::fmt::Formatter;
+
+#[derive(Debug)]
+pub enum DomainError {
+    UnknownCompetition,
+    UnknownSeason,
+    UnknownTeam,
+    InsufficientPermissions,
+    InvalidPointsRules,
+}
+
+impl std::fmt::Display for DomainError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DomainError::UnknownCompetition => write!(f, "unknown competition"),
+            DomainError::UnknownSeason => write!(f, "unknown season"),
+            DomainError::UnknownTeam => write!(f, "unknown team"),
+            DomainError::InsufficientPermissions => write!(f, "insufficient permissions"),
+            DomainError::InvalidPointsRules => write!(f, "invalid points rules"),
+        }
+    }
+}
+
+impl std::error::Error for DomainError {}
@@ -0,0 +1,179 @@
+use std::error::Error;
+use std::sync::Arc;
+use chrono::Utc;
+use itertools::Itertools;
+use crate::common::EventPublisher;
+use crate::domain::competition::aggregates::{standings, Competition, CompetitionId, PointsRules, Season, SeasonId, Standing};
+use crate::domain::competition::commands::{AddTeamToSeason, New, NewResult, NewSeason, NewSeasonResult, RemoveTeamFromSeason};
+use crate::domain::competition::events::{CompetitionAddedV1, SeasonAddedV1, TeamAddedToSeasonV1, TeamRemovedFromSeasonV1};
+use crate::domain::competition::repositories::{CompetitionRepository, ResultRepository, SeasonRepository, TeamRepository};
+use crate::domain::competition::usecases::DomainError;
+use crate::domain::account::aggregates::UserId;
+
+pub type Result<T> = std::result::Result<T, Box<dyn Error + Send + Sync>>;
+
+pub struct CompetitionUsecase {
+    competition_repository: Box<dyn CompetitionRepository + Send + Sync>,
+    season_repository: Box<dyn SeasonRepository + Send + Sync>,
+    result_repository: Box<dyn ResultRepository + Send + Sync>,
+    team_repository: Box<dyn TeamRepository + Send + Sync>,
+    event_publisher: Arc<EventPublisher>,
+}
+
+impl CompetitionUsecase {
+    pub fn build(
+        competition_repository: Box<dyn CompetitionRepository + Send + Sync>,
+        season_repository: Box<dyn SeasonRepository + Send + Sync>,
+        result_repository: Box<dyn ResultRepository + Send + Sync>,
+        team_repository: Box<dyn TeamRepository + Send + Sync>,
+        event_publisher: Arc<EventPublisher>) -> CompetitionUsecase {
+        CompetitionUsecase {
+            competition_repository,
+            season_repository,
+            result_repository,
+            team_repository,
+            event_publisher,
+        }
+    }
+
+    // commands
+    #[allow(clippy::new_ret_no_self)]
+    pub async fn new(&self, command: New) -> Result<NewResult> {
+        let id = CompetitionId::random();
+        let competition = Competition::new(id.clone(), command.name, Utc::now(), command.organiser.clone());
+        self.competition_repository.set(&competition).await?;
+
+        let event = CompetitionAddedV1 {
+            id: competition.id,
+            name: competition.name,
+            founded: competition.founded,
+            organiser: command.organiser,
+        };
+        self.event_publisher.publish(&event).await?;
+
+        Ok(NewResult {
+            id,
+        })
+    }
+
+    pub async fn new_season(&self, command: NewSeason) -> Result<NewSeasonResult> {
+        self.organised(&command.competition, &command.organiser).await?;
+
+        // rule: a win is worth at least a draw, and a draw at least a loss
+        let points = &command.points;
+        if points.win < points.draw || points.draw < points.loss || points.bonus.len() > PointsRules::MAX_BONUS_RULES {
+            return Err(DomainError::InvalidPointsRules.into());
+        }
+
+        let id = SeasonId::random();
+        let tie_breakers = command.tie_breakers.into_iter().unique().collect();
+        let season = Season::new(id.clone(), command.competition, command.name, command.points, tie_breakers);
+        self.season_repository.set(&season).await?;
+
+        let event = SeasonAddedV1 {
+            id: season.id,
+            competition: season.competition,
+            name: season.name,
+            points: season.points,
+            tie_breakers: season.tie_breakers,
+            organiser: command.organiser,
+        };
+        self.event_publisher.publish(&event).await?;
+
+        Ok(NewSeasonResult {
+            id,
+        })
+    }
+
+    pub async fn add_team_to_season(&self, command: AddTeamToSeason) -> Result<()> {
+        let mut season = self.season(&command.season, &command.organiser).await?;
+
+        if !self.team_repository.exist(&command.team).await? {
+            return Err(DomainError::UnknownTeam.into());
+        }
+
+        if !season.add_team(&command.team) {
+            return Ok(());
+        }
+        self.season_repository.set(&season).await?;
+
+        let event = TeamAddedToSeasonV1 {
+            season: command.season,
+            team: command.team,
+            organiser: command.organiser,
+        };
+        self.event_publisher.publish(&event).await?;
+
+        Ok(())
+    }
+
+    pub async fn remove_team_from_season(&self, command: RemoveTeamFromSeason) -> Result<()> {
+        let mut season = self.season(&command.season, &command.organiser).await?;
+
+        if !season.remove_team(&command.team) {
+            return Ok(());
+        }
+        self.season_repository.set(&season).await?;
+
+        let event = TeamRemovedFromSeasonV1 {
+            season: command.season,
+            team: command.team,
+            organiser: command.organiser,
+        };
+        self.event_publisher.publish(&event).await?;
+
+        Ok(())
+    }
+
+    // loads a competition on behalf of one of its organisers
+    async fn organised(&self, id: &CompetitionId, organiser: &UserId) -> Result<Competition> {
+        let competition = self.competition_repository
+            .get(id)
+            .await?
+            .ok_or(DomainError::UnknownCompetition)?;
+
+        // rule: only organisers manage the competition
+        if !competition.is_organiser(organiser) {
+            return Err(DomainError::InsufficientPermissions.into());
+        }
+
+        Ok(competition)
+    }
+
+    // loads a season on behalf of one of the organisers of its competition
+    async fn season(&self, id: &SeasonId, organiser: &UserId) -> Result<Season> {
+        let season = self.season_repository
+            .get(id)
+            .await?
+            .ok_or(DomainError::UnknownSeason)?;
+
+        self.organised(&season.competition, organiser).await?;
+
+        Ok(season)
+    }
+
+    // queries
+    pub async fn list_competitions(&self, after: Option<CompetitionId>) -> Result<Vec<Competition>> {
+        self.competition_repository
+            .list(&after).await
+            .map_err(|err| err.into())
+    }
+
+    pub async fn list_seasons(&self, competition: CompetitionId, after: Option<SeasonId>) -> Result<Vec<Season>> {
+        self.season_repository
+            .list(&competition, &after).await
+            .map_err(|err| err.into())
+    }
+
+    pub async fn get_standings(&self, season: SeasonId) -> Result<(Season, Vec<Standing>)> {
+        let season = self.season_repository
+            .get(&season)
+            .await?
+            .ok_or(DomainError::UnknownSeason)?;
+
+        let results = self.result_repository.list(&season.id).await?;
+        let standings = standings(&season, &results);
+
+        Ok((season, standings))
+    }
+}
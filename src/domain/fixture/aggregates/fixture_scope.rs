@@ -0,0 +1,8 @@
+use crate::domain::club::aggregates::ClubId;
+use crate::domain::team::aggregates::TeamId;
+
+// note: fixture scope is transient; the matches of a club are those of its teams
+pub enum FixtureScope {
+    Team(TeamId),
+    Club(ClubId),
+}
@@ -0,0 +1,128 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::domain::fixture::aggregates::{MatchId, MatchIncident, MatchIncidentKind, Score, Side, Venue};
+use crate::domain::competition::aggregates::SeasonId;
+use crate::domain::team::aggregates::TeamId;
+
+#[derive(Serialize, Deserialize)]
+pub struct Match {
+    pub id: MatchId,
+    pub home: TeamId,
+    pub away: TeamId,
+    pub kickoff: DateTime<Utc>,
+    pub venue: Option<Venue>,
+    pub status: MatchStatus,
+    pub score: Option<Score>, // none until the match is underway
+    #[serde(default)]
+    pub timeline: Vec<MatchIncident>,
+    #[serde(default)]
+    pub season: Option<SeasonId>, // none for friendlies
+}
+
+// note: scheduled -> live -> finished, a match that is not finished can be postponed (and rescheduled)
+#[derive(Serialize, Deserialize, PartialEq, Eq, Clone, Copy)]
+pub enum MatchStatus {
+    Scheduled,
+    Live,
+    Finished,
+    Postponed,
+}
+
+impl MatchStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            MatchStatus::Scheduled => "scheduled",
+            MatchStatus::Live => "live",
+            MatchStatus::Finished => "finished",
+            MatchStatus::Postponed => "postponed",
+        }
+    }
+}
+
+impl PartialEq for Match {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id
+    }
+}
+
+impl Eq for Match {}
+
+impl Match {
+    pub fn new(id: MatchId, home: TeamId, away: TeamId, kickoff: DateTime<Utc>, venue: Option<Venue>, season: Option<SeasonId>) -> Match {
+        Match {
+            id,
+            home,
+            away,
+            kickoff,
+            venue,
+            status: MatchStatus::Scheduled,
+            score: None,
+            timeline: Vec::new(),
+            season,
+        }
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.status == MatchStatus::Finished
+    }
+
+    pub fn involves(&self, team: &TeamId) -> bool {
+        self.home == *team || self.away == *team
+    }
+
+    pub fn reschedule(&mut self, kickoff: DateTime<Utc>, venue: Option<Venue>) {
+        self.kickoff = kickoff;
+        if venue.is_some() {
+            self.venue = venue;
+        }
+        self.status = MatchStatus::Scheduled;
+        self.score = None;
+        self.timeline.clear();
+    }
+
+    pub fn postpone(&mut self) -> bool {
+        if self.status == MatchStatus::Postponed {
+            return false;
+        }
+
+        self.status = MatchStatus::Postponed;
+        self.score = None;
+        self.timeline.clear();
+        true
+    }
+
+    // note: the first score update puts a scheduled match live
+    pub fn update_score(&mut self, score: Score) -> bool {
+        let changed = self.score != Some(score) || self.status != MatchStatus::Live;
+        self.status = MatchStatus::Live;
+        self.score = Some(score);
+        changed
+    }
+
+    pub fn finalise(&mut self, score: Score) {
+        self.status = MatchStatus::Finished;
+        self.score = Some(score);
+    }
+
+    pub fn can_record(&self) -> bool {
+        matches!(self.status, MatchStatus::Scheduled | MatchStatus::Live)
+    }
+
+    // note: goals count towards the score, full time finalises the result; anything puts the match live
+    pub fn record(&mut self, incident: MatchIncident) {
+        let mut score = self.score.unwrap_or_default();
+        match &incident.kind {
+            MatchIncidentKind::Goal { side: Side::Home, .. } => score.home += 1,
+            MatchIncidentKind::Goal { side: Side::Away, .. } => score.away += 1,
+            _ => {},
+        }
+
+        self.status = match incident.kind {
+            MatchIncidentKind::FullTime => MatchStatus::Finished,
+            _ => MatchStatus::Live,
+        };
+        self.score = Some(score);
+        self.timeline.push(incident);
+    }
+}
@@ -0,0 +1,47 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::domain::fixture::aggregates::PlayerName;
+
+// note: incidents make up the live timeline of a match, in the order they were recorded
+#[derive(Serialize, Deserialize, PartialEq, Eq, Hash, Clone)]
+pub struct MatchIncident {
+    pub kind: MatchIncidentKind,
+    pub minute: u32,
+    pub recorded: DateTime<Utc>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Eq, Hash, Clone)]
+pub enum MatchIncidentKind {
+    Goal { side: Side, scorer: Option<PlayerName> },
+    Card { side: Side, player: PlayerName, colour: CardColour },
+    Substitution { side: Side, player_in: PlayerName, player_out: PlayerName },
+    HalfTime,
+    FullTime,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Eq, Hash, Clone, Copy)]
+pub enum Side {
+    Home,
+    Away,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Eq, Hash, Clone, Copy)]
+pub enum CardColour {
+    Yellow,
+    SecondYellow,
+    Red,
+}
+
+impl MatchIncident {
+    // regular time plus extra time and stoppages
+    pub const MAX_MINUTE: u32 = 150;
+
+    pub fn new(kind: MatchIncidentKind, minute: u32, recorded: DateTime<Utc>) -> Option<MatchIncident> {
+        if minute > MatchIncident::MAX_MINUTE {
+            return None;
+        }
+
+        Some(MatchIncident { kind, minute, recorded })
+    }
+}
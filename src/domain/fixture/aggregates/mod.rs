@@ -0,0 +1,15 @@
+pub mod fixture_scope;
+pub mod r#match;
+pub mod match_id;
+pub mod match_incident;
+pub mod player_name;
+pub mod score;
+pub mod venue;
+
+pub use fixture_scope::FixtureScope;
+pub use r#match::{Match, MatchStatus};
+pub use match_id::MatchId;
+pub use match_incident::{CardColour, MatchIncident, MatchIncidentKind, Side};
+pub use player_name::PlayerName;
+pub use score::Score;
+pub use venue::Venue;
@@ -0,0 +1,48 @@
+use std::fmt::{Display, Formatter};
+use chumsky::prelude::{end, filter, Parser, Simple};
+use chumsky::text::TextParser;
+use serde::{Deserialize, Serialize};
+use std::hash::{Hash, Hasher};
+
+#[derive(Serialize, Deserialize, PartialEq, Eq, Clone)]
+pub struct PlayerName {
+    raw: String,
+}
+
+#[derive(Debug)]
+pub enum ParseError {
+    MalformedInput,
+}
+
+impl PlayerName {
+    fn parser() -> impl Parser<char, PlayerName, Error = Simple<char>> {
+        // name can consists of alphanumeric (unicode), space, single quotes, dots and dashes
+        // length must be between 2 and 100 characters (runes)
+        filter(|c: &char| c.is_alphanumeric() || *c == ' ' || *c == '\'' || *c == '.' || *c == '-')
+            .repeated()
+            .at_least(2)
+            .at_most(100)
+            .padded()
+            .collect::<String>()
+            .map(|name| PlayerName { raw: name })
+            .then_ignore(end())
+    }
+
+    pub fn parse(input: &str) -> Result<PlayerName, ParseError> {
+        PlayerName::parser()
+            .parse(input)
+            .map_err(|_| ParseError::MalformedInput)
+    }
+}
+
+impl Display for PlayerName {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.raw)
+    }
+}
+
+impl Hash for PlayerName {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.raw.hash(state)
+    }
+}
@@ -0,0 +1,20 @@
+use std::fmt::{Display, Formatter};
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, PartialEq, Eq, Clone, Copy, Default)]
+pub struct Score {
+    pub home: u32,
+    pub away: u32,
+}
+
+impl Score {
+    pub fn new(home: u32, away: u32) -> Score {
+        Score { home, away }
+    }
+}
+
+impl Display for Score {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}-{}", self.home, self.away)
+    }
+}
@@ -0,0 +1,50 @@
+use chrono::{DateTime, Utc};
+use crate::domain::fixture::aggregates::{MatchId, MatchIncidentKind, Score, Venue};
+use crate::domain::competition::aggregates::SeasonId;
+use crate::domain::team::aggregates::TeamId;
+use crate::domain::account::aggregates::UserId;
+
+pub struct ScheduleMatch {
+    pub home: TeamId,
+    pub away: TeamId,
+    pub kickoff: DateTime<Utc>,
+    pub venue: Option<Venue>,
+    pub season: Option<SeasonId>,
+    pub staff_member: UserId,
+}
+
+pub struct ScheduleMatchResult {
+    pub id: MatchId,
+}
+
+pub struct RescheduleMatch {
+    pub id: MatchId,
+    pub kickoff: DateTime<Utc>,
+    pub venue: Option<Venue>, // keeps the current venue when absent
+    pub staff_member: UserId,
+}
+
+pub struct PostponeMatch {
+    pub id: MatchId,
+    pub staff_member: UserId,
+}
+
+pub struct UpdateScore {
+    pub id: MatchId,
+    pub score: Score,
+    pub staff_member: UserId,
+}
+
+pub struct FinaliseResult {
+    pub id: MatchId,
+    pub score: Score,
+    pub staff_member: UserId,
+}
+
+pub struct RecordIncident {
+    pub id: MatchId,
+    pub kind: MatchIncidentKind,
+    pub minute: u32,
+    pub post: bool, // also posts the incident into the communities of both teams
+    pub staff_member: UserId,
+}
@@ -0,0 +1,105 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use crate::common::Event;
+use crate::domain::competition::aggregates::SeasonId;
+use crate::domain::fixture::aggregates::{MatchId, MatchIncident, Score, Venue};
+use crate::domain::team::aggregates::TeamId;
+use crate::domain::account::aggregates::UserId;
+
+#[derive(Serialize, Deserialize)]
+pub struct MatchScheduledV1 {
+    pub id: MatchId,
+    pub home: TeamId,
+    pub away: TeamId,
+    pub kickoff: DateTime<Utc>,
+    pub venue: Option<Venue>,
+    pub staff_member: UserId,
+}
+
+impl Event for MatchScheduledV1 {
+    fn kind(&self) -> &'static str {
+        "MatchScheduledV1"
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct MatchScheduledV2 {
+    pub id: MatchId,
+    pub home: TeamId,
+    pub away: TeamId,
+    pub kickoff: DateTime<Utc>,
+    pub venue: Option<Venue>,
+    pub season: Option<SeasonId>,
+    pub staff_member: UserId,
+}
+
+impl Event for MatchScheduledV2 {
+    fn kind(&self) -> &'static str {
+        "MatchScheduledV2"
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct MatchRescheduledV1 {
+    pub id: MatchId,
+    pub kickoff: DateTime<Utc>,
+    pub venue: Option<Venue>,
+    pub staff_member: UserId,
+}
+
+impl Event for MatchRescheduledV1 {
+    fn kind(&self) -> &'static str {
+        "MatchRescheduledV1"
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct MatchPostponedV1 {
+    pub id: MatchId,
+    pub staff_member: UserId,
+}
+
+impl Event for MatchPostponedV1 {
+    fn kind(&self) -> &'static str {
+        "MatchPostponedV1"
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct ScoreUpdatedV1 {
+    pub id: MatchId,
+    pub score: Score,
+    pub staff_member: UserId,
+}
+
+impl Event for ScoreUpdatedV1 {
+    fn kind(&self) -> &'static str {
+        "ScoreUpdatedV1"
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct ResultFinalisedV1 {
+    pub id: MatchId,
+    pub score: Score,
+    pub staff_member: UserId,
+}
+
+impl Event for ResultFinalisedV1 {
+    fn kind(&self) -> &'static str {
+        "ResultFinalisedV1"
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct MatchIncidentRecordedV1 {
+    pub id: MatchId,
+    pub incident: MatchIncident,
+    pub staff_member: UserId,
+}
+
+impl Event for MatchIncidentRecordedV1 {
+    fn kind(&self) -> &'static str {
+        "MatchIncidentRecordedV1"
+    }
+}
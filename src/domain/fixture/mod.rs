@@ -0,0 +1,6 @@
+pub mod aggregates;
+pub mod policies;
+pub mod repositories;
+pub mod commands;
+pub mod events;
+pub mod usecases;
@@ -0,0 +1,25 @@
+use crate::domain::fixture::aggregates::Match;
+use crate::domain::team::aggregates::Team;
+use crate::domain::account::aggregates::UserId;
+
+#[derive(Debug)]
+pub enum FixturePolicyViolation {
+    InsufficientPermissions,
+}
+
+type FixturePolicyResult = Result<(), FixturePolicyViolation>;
+
+// note: teams are the home and away team of the match
+pub struct FixturePolicyExecutionContext {
+    pub teams: Vec<Team>,
+    pub user: UserId,
+}
+
+pub trait FixturePolicy {
+    fn allow_schedule(context: &FixturePolicyExecutionContext) -> FixturePolicyResult;
+    fn allow_reschedule(context: &FixturePolicyExecutionContext, fixture: &Match) -> FixturePolicyResult;
+    fn allow_postpone(context: &FixturePolicyExecutionContext, fixture: &Match) -> FixturePolicyResult;
+    fn allow_update_score(context: &FixturePolicyExecutionContext, fixture: &Match) -> FixturePolicyResult;
+    fn allow_finalise_result(context: &FixturePolicyExecutionContext, fixture: &Match) -> FixturePolicyResult;
+    fn allow_record_incident(context: &FixturePolicyExecutionContext, fixture: &Match) -> FixturePolicyResult;
+}
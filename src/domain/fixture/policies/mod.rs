@@ -0,0 +1,3 @@
+pub mod fixture_policy;
+
+pub use fixture_policy::{FixturePolicy, FixturePolicyExecutionContext, FixturePolicyViolation};
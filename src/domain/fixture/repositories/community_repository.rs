@@ -0,0 +1,8 @@
+use crate::common::RepositoryResult;
+use crate::domain::social::aggregates::CommunityId;
+use crate::domain::team::aggregates::TeamId;
+
+#[tonic::async_trait]
+pub trait CommunityRepository {
+    async fn list_by_team(&self, team: &TeamId) -> RepositoryResult<Vec<CommunityId>>;
+}
@@ -0,0 +1,11 @@
+use chrono::{DateTime, Utc};
+use crate::common::RepositoryResult;
+use crate::domain::fixture::aggregates::{FixtureScope, Match, MatchId};
+
+// note: matches are listed by kickoff, optionally bounded by [from, until)
+#[tonic::async_trait]
+pub trait MatchRepository {
+    async fn list(&self, scope: &FixtureScope, from: &Option<DateTime<Utc>>, until: &Option<DateTime<Utc>>, after: &Option<MatchId>) -> RepositoryResult<Vec<Match>>;
+    async fn get(&self, id: &MatchId) -> RepositoryResult<Option<Match>>;
+    async fn set(&self, fixture: &Match) -> RepositoryResult<()>;
+}
@@ -0,0 +1,11 @@
+pub mod match_repository;
+pub mod team_repository;
+pub mod community_repository;
+pub mod post_repository;
+pub mod season_repository;
+
+pub use match_repository::MatchRepository;
+pub use team_repository::TeamRepository;
+pub use community_repository::CommunityRepository;
+pub use post_repository::PostRepository;
+pub use season_repository::SeasonRepository;
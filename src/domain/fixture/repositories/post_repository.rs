@@ -0,0 +1,8 @@
+use crate::common::RepositoryResult;
+use crate::domain::social::aggregates::Post;
+
+// note: automatic posts about matches are published into the communities of the teams
+#[tonic::async_trait]
+pub trait PostRepository {
+    async fn set(&self, post: &Post) -> RepositoryResult<()>;
+}
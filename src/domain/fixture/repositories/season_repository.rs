@@ -0,0 +1,7 @@
+use crate::common::RepositoryResult;
+use crate::domain::competition::aggregates::{Season, SeasonId};
+
+#[tonic::async_trait]
+pub trait SeasonRepository {
+    async fn get(&self, id: &SeasonId) -> RepositoryResult<Option<Season>>;
+}
@@ -0,0 +1,34 @@
+use std::fmt::Formatter;
+
+#[derive(Debug)]
+pub enum DomainError {
+    UnknownMatch,
+    UnknownTeam,
+    InsufficientPermissions,
+    SameTeam,
+    MatchFinished,
+    InvalidDateRange,
+    InvalidMinute,
+    MatchNotUnderway,
+    UnknownSeason,
+    TeamNotInSeason,
+}
+
+impl std::fmt::Display for DomainError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DomainError::UnknownMatch => write!(f, "unknown match"),
+            DomainError::UnknownTeam => write!(f, "unknown team"),
+            DomainError::InsufficientPermissions => write!(f, "insufficient permissions"),
+            DomainError::SameTeam => write!(f, "home and away team must differ"),
+            DomainError::MatchFinished => write!(f, "match already finished"),
+            DomainError::InvalidDateRange => write!(f, "invalid date range"),
+            DomainError::InvalidMinute => write!(f, "invalid minute"),
+            DomainError::MatchNotUnderway => write!(f, "match is not scheduled or live"),
+            DomainError::UnknownSeason => write!(f, "unknown season"),
+            DomainError::TeamNotInSeason => write!(f, "team does not play in season"),
+        }
+    }
+}
+
+impl std::error::Error for DomainError {}
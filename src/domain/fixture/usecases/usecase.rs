@@ -0,0 +1,343 @@
+use std::error::Error;
+use std::sync::Arc;
+use chrono::{DateTime, Utc};
+use itertools::Itertools;
+use tokio::sync::broadcast;
+use crate::common::EventPublisher;
+use crate::domain::account::aggregates::UserId;
+use crate::domain::fixture::aggregates::{CardColour, FixtureScope, Match, MatchId, MatchIncident, MatchIncidentKind, Side};
+use crate::domain::fixture::commands::{FinaliseResult, PostponeMatch, RecordIncident, RescheduleMatch, ScheduleMatch, ScheduleMatchResult, UpdateScore};
+use crate::domain::fixture::events::{MatchIncidentRecordedV1, MatchPostponedV1, MatchRescheduledV1, MatchScheduledV2, ResultFinalisedV1, ScoreUpdatedV1};
+use crate::domain::fixture::repositories::{CommunityRepository, MatchRepository, PostRepository, SeasonRepository, TeamRepository};
+use crate::domain::fixture::usecases::DomainError;
+use crate::domain::social::aggregates::{Post, PostAttachment, PostAttachments, PostId, PostText};
+use crate::domain::social::events::PostPublishedV1;
+use crate::domain::team::aggregates::{Team, TeamId};
+
+pub type Result<T> = std::result::Result<T, Box<dyn Error + Send + Sync>>;
+
+pub struct FixtureUsecase {
+    match_repository: Box<dyn MatchRepository + Send + Sync>,
+    team_repository: Box<dyn TeamRepository + Send + Sync>,
+    season_repository: Box<dyn SeasonRepository + Send + Sync>,
+    community_repository: Box<dyn CommunityRepository + Send + Sync>,
+    post_repository: Box<dyn PostRepository + Send + Sync>,
+    event_publisher: Arc<EventPublisher>,
+    incidents: broadcast::Sender<(MatchId, MatchIncident)>, // live tickers of this process
+}
+
+impl FixtureUsecase {
+    // number of incidents buffered for slow watchers, before they skip ahead
+    const INCIDENT_CAPACITY: usize = 256;
+
+    pub fn build(
+        match_repository: Box<dyn MatchRepository + Send + Sync>,
+        team_repository: Box<dyn TeamRepository + Send + Sync>,
+        season_repository: Box<dyn SeasonRepository + Send + Sync>,
+        community_repository: Box<dyn CommunityRepository + Send + Sync>,
+        post_repository: Box<dyn PostRepository + Send + Sync>,
+        event_publisher: Arc<EventPublisher>) -> FixtureUsecase {
+        FixtureUsecase {
+            match_repository,
+            team_repository,
+            season_repository,
+            community_repository,
+            post_repository,
+            event_publisher,
+            incidents: broadcast::channel(FixtureUsecase::INCIDENT_CAPACITY).0,
+        }
+    }
+
+    // commands
+    pub async fn schedule_match(&self, command: ScheduleMatch) -> Result<ScheduleMatchResult> {
+        // rule: a team can't play itself
+        if command.home == command.away {
+            return Err(DomainError::SameTeam.into());
+        }
+
+        let (home, away) = self.teams(&command.home, &command.away).await?;
+
+        // rule: staff of either team schedules the match
+        if !is_staff(&[&home, &away], &command.staff_member) {
+            return Err(DomainError::InsufficientPermissions.into());
+        }
+
+        // rule: both teams play in the season of a league match
+        if let Some(season) = &command.season {
+            let season = self.season_repository
+                .get(season)
+                .await?
+                .ok_or(DomainError::UnknownSeason)?;
+
+            if !season.has_team(&command.home) || !season.has_team(&command.away) {
+                return Err(DomainError::TeamNotInSeason.into());
+            }
+        }
+
+        let id = MatchId::random();
+        let fixture = Match::new(id.clone(), command.home, command.away, command.kickoff, command.venue, command.season);
+        self.match_repository.set(&fixture).await?;
+
+        let event = MatchScheduledV2 {
+            id: fixture.id.clone(),
+            home: fixture.home.clone(),
+            away: fixture.away.clone(),
+            kickoff: fixture.kickoff,
+            venue: fixture.venue.clone(),
+            season: fixture.season.clone(),
+            staff_member: command.staff_member.clone(),
+        };
+        self.event_publisher.publish(&event).await?;
+
+        let text = match &fixture.venue {
+            Some(venue) => format!("{} vs {} is scheduled for {} at {}", home.name, away.name, to_kickoff(&fixture.kickoff), venue),
+            None => format!("{} vs {} is scheduled for {}", home.name, away.name, to_kickoff(&fixture.kickoff)),
+        };
+        self.announce(&fixture, text, Vec::new(), &command.staff_member).await?;
+
+        Ok(ScheduleMatchResult {
+            id,
+        })
+    }
+
+    pub async fn reschedule_match(&self, command: RescheduleMatch) -> Result<()> {
+        let (mut fixture, home, away) = self.fixture(&command.id, &command.staff_member).await?;
+
+        fixture.reschedule(command.kickoff, command.venue);
+        self.match_repository.set(&fixture).await?;
+
+        let event = MatchRescheduledV1 {
+            id: command.id,
+            kickoff: fixture.kickoff,
+            venue: fixture.venue.clone(),
+            staff_member: command.staff_member.clone(),
+        };
+        self.event_publisher.publish(&event).await?;
+
+        let text = format!("{} vs {} is rescheduled to {}", home.name, away.name, to_kickoff(&fixture.kickoff));
+        self.announce(&fixture, text, Vec::new(), &command.staff_member).await
+    }
+
+    pub async fn postpone_match(&self, command: PostponeMatch) -> Result<()> {
+        let (mut fixture, home, away) = self.fixture(&command.id, &command.staff_member).await?;
+
+        if !fixture.postpone() {
+            return Ok(());
+        }
+        self.match_repository.set(&fixture).await?;
+
+        let event = MatchPostponedV1 {
+            id: command.id,
+            staff_member: command.staff_member.clone(),
+        };
+        self.event_publisher.publish(&event).await?;
+
+        let text = format!("{} vs {} is postponed", home.name, away.name);
+        self.announce(&fixture, text, Vec::new(), &command.staff_member).await
+    }
+
+    pub async fn update_score(&self, command: UpdateScore) -> Result<()> {
+        let (mut fixture, home, away) = self.fixture(&command.id, &command.staff_member).await?;
+
+        if !fixture.update_score(command.score) {
+            return Ok(());
+        }
+        self.match_repository.set(&fixture).await?;
+
+        let event = ScoreUpdatedV1 {
+            id: command.id,
+            score: command.score,
+            staff_member: command.staff_member.clone(),
+        };
+        self.event_publisher.publish(&event).await?;
+
+        let text = format!("{} {} {}", home.name, command.score, away.name);
+        self.announce(&fixture, text, Vec::new(), &command.staff_member).await
+    }
+
+    pub async fn finalise_result(&self, command: FinaliseResult) -> Result<()> {
+        let (mut fixture, home, away) = self.fixture(&command.id, &command.staff_member).await?;
+
+        fixture.finalise(command.score);
+        self.match_repository.set(&fixture).await?;
+
+        let event = ResultFinalisedV1 {
+            id: command.id,
+            score: command.score,
+            staff_member: command.staff_member.clone(),
+        };
+        self.event_publisher.publish(&event).await?;
+
+        let text = format!("Full time: {} {} {}", home.name, command.score, away.name);
+        self.announce(&fixture, text, Vec::new(), &command.staff_member).await
+    }
+
+    pub async fn record_incident(&self, command: RecordIncident) -> Result<()> {
+        let (mut fixture, home, away) = self.fixture(&command.id, &command.staff_member).await?;
+
+        // rule: incidents are recorded for scheduled or live matches only
+        if !fixture.can_record() {
+            return Err(DomainError::MatchNotUnderway.into());
+        }
+
+        let incident = MatchIncident::new(command.kind, command.minute, Utc::now())
+            .ok_or(DomainError::InvalidMinute)?;
+
+        fixture.record(incident.clone());
+        self.match_repository.set(&fixture).await?;
+
+        let event = MatchIncidentRecordedV1 {
+            id: command.id.clone(),
+            incident: incident.clone(),
+            staff_member: command.staff_member.clone(),
+        };
+        self.event_publisher.publish(&event).await?;
+
+        // note: full time finalises the result as recorded so far
+        if let (MatchIncidentKind::FullTime, Some(score)) = (&incident.kind, fixture.score) {
+            let event = ResultFinalisedV1 {
+                id: command.id.clone(),
+                score,
+                staff_member: command.staff_member.clone(),
+            };
+            self.event_publisher.publish(&event).await?;
+        }
+
+        // note: sending only fails when nobody is watching
+        let _ = self.incidents.send((command.id.clone(), incident.clone()));
+
+        if command.post {
+            let text = to_ticker(&fixture, &incident, &home, &away);
+            let attachment = PostAttachment::MatchIncident { fixture: command.id, incident };
+            self.announce(&fixture, text, vec![attachment], &command.staff_member).await?;
+        }
+
+        Ok(())
+    }
+
+    // loads a match that is not finished yet, together with its teams, on behalf of their staff
+    async fn fixture(&self, id: &MatchId, staff_member: &UserId) -> Result<(Match, Team, Team)> {
+        let fixture = self.match_repository
+            .get(id)
+            .await?
+            .ok_or(DomainError::UnknownMatch)?;
+
+        let (home, away) = self.teams(&fixture.home, &fixture.away).await?;
+
+        // rule: only staff of either team manages the match
+        if !is_staff(&[&home, &away], staff_member) {
+            return Err(DomainError::InsufficientPermissions.into());
+        }
+
+        // rule: finished matches are final
+        if fixture.is_finished() {
+            return Err(DomainError::MatchFinished.into());
+        }
+
+        Ok((fixture, home, away))
+    }
+
+    async fn teams(&self, home: &TeamId, away: &TeamId) -> Result<(Team, Team)> {
+        let home = self.team_repository
+            .get(home)
+            .await?
+            .ok_or(DomainError::UnknownTeam)?;
+
+        let away = self.team_repository
+            .get(away)
+            .await?
+            .ok_or(DomainError::UnknownTeam)?;
+
+        Ok((home, away))
+    }
+
+    // publishes an automatic post, on behalf of the staff member, into the communities of both teams
+    async fn announce(&self, fixture: &Match, text: String, attachments: Vec<PostAttachment>, author: &UserId) -> Result<()> {
+        let text = match PostText::parse(text.as_str()) {
+            Ok(text) => text,
+            Err(_) => return Ok(()), // note: team names are always short enough, skip rather than fail
+        };
+
+        let mut communities = self.community_repository.list_by_team(&fixture.home).await?;
+        communities.extend(self.community_repository.list_by_team(&fixture.away).await?);
+
+        for community in communities.into_iter().unique() {
+            let post = Post::new(PostId::random(), community, text.clone(), PostAttachments::from_vec(attachments.clone()), author.clone(), Utc::now());
+            self.post_repository.set(&post).await?;
+
+            let event = PostPublishedV1 {
+                id: post.id,
+                community: post.community,
+                text: post.text,
+                attachments: post.attachments,
+                author: post.author,
+                published: post.published,
+            };
+            self.event_publisher.publish(&event).await?;
+        }
+
+        Ok(())
+    }
+
+    // queries
+    // note: subscribes before loading, incidents recorded in between may be both in the match and the receiver
+    pub async fn watch_match(&self, id: MatchId) -> Result<(Match, broadcast::Receiver<(MatchId, MatchIncident)>)> {
+        let receiver = self.incidents.subscribe();
+        let fixture = self.match_repository
+            .get(&id)
+            .await?
+            .ok_or(DomainError::UnknownMatch)?;
+
+        Ok((fixture, receiver))
+    }
+
+    pub async fn list_fixtures(&self, scope: FixtureScope, from: Option<DateTime<Utc>>, until: Option<DateTime<Utc>>, after: Option<MatchId>) -> Result<Vec<Match>> {
+        // rule: the date range can't be reversed
+        if let (Some(from), Some(until)) = (from, until) {
+            if from >= until {
+                return Err(DomainError::InvalidDateRange.into());
+            }
+        }
+
+        self.match_repository
+            .list(&scope, &from, &until, &after).await
+            .map_err(|err| err.into())
+    }
+}
+
+fn is_staff(teams: &[&Team], user: &UserId) -> bool {
+    teams.iter().any(|team| team.staff.contains(user))
+}
+
+fn to_ticker(fixture: &Match, incident: &MatchIncident, home: &Team, away: &Team) -> String {
+    let team = |side: &Side| match side {
+        Side::Home => home.name.to_string(),
+        Side::Away => away.name.to_string(),
+    };
+    let score = fixture.score.unwrap_or_default();
+
+    match &incident.kind {
+        MatchIncidentKind::Goal { side, scorer: Some(scorer) } =>
+            format!("{}' Goal for {} by {}! {} {} {}", incident.minute, team(side), scorer, home.name, score, away.name),
+        MatchIncidentKind::Goal { side, scorer: None } =>
+            format!("{}' Goal for {}! {} {} {}", incident.minute, team(side), home.name, score, away.name),
+        MatchIncidentKind::Card { side, player, colour } => {
+            let colour = match colour {
+                CardColour::Yellow => "Yellow card",
+                CardColour::SecondYellow => "Second yellow card",
+                CardColour::Red => "Red card",
+            };
+            format!("{}' {} for {} ({})", incident.minute, colour, player, team(side))
+        },
+        MatchIncidentKind::Substitution { side, player_in, player_out } =>
+            format!("{}' Substitution {}: {} replaces {}", incident.minute, team(side), player_in, player_out),
+        MatchIncidentKind::HalfTime =>
+            format!("Half time: {} {} {}", home.name, score, away.name),
+        MatchIncidentKind::FullTime =>
+            format!("Full time: {} {} {}", home.name, score, away.name),
+    }
+}
+
+fn to_kickoff(kickoff: &DateTime<Utc>) -> String {
+    kickoff.format("%Y-%m-%d %H:%M UTC").to_string()
+}
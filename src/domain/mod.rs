@@ -1,5 +1,9 @@
+pub mod calendar;
 pub mod club;
+pub mod competition;
+pub mod fixture;
 pub mod media;
+pub mod notification;
 pub mod social;
 pub mod team;
 pub mod account;
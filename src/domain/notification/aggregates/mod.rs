@@ -0,0 +1,9 @@
+pub use notification_id::NotificationId;
+pub use notification::{Notification, NotificationKind};
+pub use notification_category::NotificationCategory;
+pub use notification_preferences::NotificationPreferences;
+
+pub mod notification_id;
+pub mod notification;
+pub mod notification_category;
+pub mod notification_preferences;
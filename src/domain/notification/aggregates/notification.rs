@@ -0,0 +1,84 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::domain::account::aggregates::UserId;
+use crate::domain::notification::aggregates::{NotificationCategory, NotificationId};
+use crate::domain::social::aggregates::{CommentId, CommunityId, PostId};
+
+// note: describes what happened, relative to the recipient
+#[derive(Serialize, Deserialize, PartialEq, Eq, Clone)]
+pub enum NotificationKind {
+    PostCommented { post: PostId },
+    CommentReplied { post: PostId, comment: CommentId },
+    PostReacted { post: PostId },
+    CommentReacted { post: PostId, comment: CommentId },
+    Mentioned { post: PostId, comment: Option<CommentId> },
+    Followed,
+    PromotedToEditor { community: CommunityId },
+    MembershipOffered { community: CommunityId },
+}
+
+impl NotificationKind {
+    pub fn category(&self) -> NotificationCategory {
+        match self {
+            NotificationKind::PostCommented { .. } | NotificationKind::CommentReplied { .. } => NotificationCategory::Comments,
+            NotificationKind::PostReacted { .. } | NotificationKind::CommentReacted { .. } => NotificationCategory::Reactions,
+            NotificationKind::Mentioned { .. } => NotificationCategory::Mentions,
+            NotificationKind::Followed => NotificationCategory::Follows,
+            NotificationKind::PromotedToEditor { .. } | NotificationKind::MembershipOffered { .. } => NotificationCategory::Communities,
+        }
+    }
+
+    // note: unread notifications of the same key are aggregated into one
+    pub fn key(&self) -> String {
+        match self {
+            NotificationKind::PostCommented { post } => format!("post_commented:{}", post),
+            NotificationKind::CommentReplied { comment, .. } => format!("comment_replied:{}", comment),
+            NotificationKind::PostReacted { post } => format!("post_reacted:{}", post),
+            NotificationKind::CommentReacted { comment, .. } => format!("comment_reacted:{}", comment),
+            NotificationKind::Mentioned { post, comment: Some(comment) } => format!("mentioned:{}:{}", post, comment),
+            NotificationKind::Mentioned { post, comment: None } => format!("mentioned:{}", post),
+            NotificationKind::Followed => String::from("followed"),
+            NotificationKind::PromotedToEditor { community } => format!("promoted_to_editor:{}", community),
+            NotificationKind::MembershipOffered { community } => format!("membership_offered:{}", community),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct Notification {
+    pub id: NotificationId,
+    pub recipient: UserId,
+    pub kind: NotificationKind,
+    pub actors: Vec<UserId>, // distinct, most recent first; empty when nobody in particular acted
+    pub updated: DateTime<Utc>,
+    pub read: bool,
+}
+
+impl Notification {
+    // number of actors shown along with a notification, e.g. "A, B, C and 9 others reacted to your post"
+    pub const RECENT_ACTORS: usize = 3;
+
+    pub fn new(recipient: UserId, kind: NotificationKind, actor: Option<UserId>, at: DateTime<Utc>) -> Notification {
+        Notification {
+            id: NotificationId::random(),
+            recipient,
+            kind,
+            actors: actor.into_iter().collect(),
+            updated: at,
+            read: false,
+        }
+    }
+
+    pub fn aggregate(&mut self, actor: Option<UserId>, at: DateTime<Utc>) {
+        if let Some(actor) = actor {
+            self.actors.retain(|other| other != &actor);
+            self.actors.insert(0, actor);
+        }
+        self.updated = at;
+    }
+
+    pub fn recent_actors(&self) -> &[UserId] {
+        &self.actors[..self.actors.len().min(Notification::RECENT_ACTORS)]
+    }
+}
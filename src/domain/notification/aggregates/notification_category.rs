@@ -0,0 +1,11 @@
+use serde::{Deserialize, Serialize};
+
+// note: the unit in which users mute notifications
+#[derive(Serialize, Deserialize, PartialEq, Eq, Hash, Clone, Copy)]
+pub enum NotificationCategory {
+    Comments,
+    Reactions,
+    Mentions,
+    Follows,
+    Communities,
+}
@@ -0,0 +1,27 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::domain::account::aggregates::UserId;
+use crate::domain::notification::aggregates::NotificationCategory;
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct NotificationPreferences {
+    pub user: UserId,
+    pub muted: Vec<NotificationCategory>,
+    pub updated: DateTime<Utc>,
+}
+
+impl NotificationPreferences {
+    // note: users without preferences receive every category
+    pub fn new(user: UserId, updated: DateTime<Utc>) -> NotificationPreferences {
+        NotificationPreferences {
+            user,
+            muted: vec![],
+            updated,
+        }
+    }
+
+    pub fn is_muted(&self, category: NotificationCategory) -> bool {
+        self.muted.contains(&category)
+    }
+}
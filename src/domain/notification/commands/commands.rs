@@ -0,0 +1,17 @@
+use crate::domain::account::aggregates::UserId;
+use crate::domain::notification::aggregates::{NotificationCategory, NotificationId};
+
+pub struct MarkNotificationsRead {
+    pub recipient: UserId,
+    pub notifications: Vec<NotificationId>,
+}
+
+pub struct MarkAllNotificationsRead {
+    pub recipient: UserId,
+}
+
+// note: replaces the muted categories, categories left out are unmuted
+pub struct UpdateNotificationPreferences {
+    pub user: UserId,
+    pub muted: Vec<NotificationCategory>,
+}
@@ -0,0 +1,39 @@
+use serde::{Deserialize, Serialize};
+use crate::common::Event;
+use crate::domain::account::aggregates::UserId;
+use crate::domain::notification::aggregates::{NotificationCategory, NotificationId};
+
+#[derive(Serialize, Deserialize)]
+pub struct NotificationsReadV1 {
+    pub recipient: UserId,
+    pub notifications: Vec<NotificationId>,
+}
+
+impl Event for NotificationsReadV1 {
+    fn kind(&self) -> &'static str {
+        "NotificationsReadV1"
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct AllNotificationsReadV1 {
+    pub recipient: UserId,
+}
+
+impl Event for AllNotificationsReadV1 {
+    fn kind(&self) -> &'static str {
+        "AllNotificationsReadV1"
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct NotificationPreferencesUpdatedV1 {
+    pub user: UserId,
+    pub muted: Vec<NotificationCategory>,
+}
+
+impl Event for NotificationPreferencesUpdatedV1 {
+    fn kind(&self) -> &'static str {
+        "NotificationPreferencesUpdatedV1"
+    }
+}
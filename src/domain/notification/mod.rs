@@ -0,0 +1,5 @@
+pub mod aggregates;
+pub mod repositories;
+pub mod commands;
+pub mod events;
+pub mod usecases;
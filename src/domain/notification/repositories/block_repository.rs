@@ -0,0 +1,7 @@
+use crate::common::RepositoryResult;
+use crate::domain::account::aggregates::UserId;
+
+#[tonic::async_trait]
+pub trait BlockRepository {
+    async fn is_blocked(&self, blocker: &UserId, user: &UserId) -> RepositoryResult<bool>;
+}
@@ -0,0 +1,8 @@
+use crate::common::RepositoryResult;
+use crate::domain::social::aggregates::{Comment, CommentId};
+
+// note: comments are looked up to notify their authors
+#[tonic::async_trait]
+pub trait CommentRepository {
+    async fn get(&self, id: &CommentId) -> RepositoryResult<Option<Comment>>;
+}
@@ -0,0 +1,11 @@
+pub mod notification_repository;
+pub mod notification_preferences_repository;
+pub mod post_repository;
+pub mod comment_repository;
+pub mod block_repository;
+
+pub use notification_repository::NotificationRepository;
+pub use notification_preferences_repository::NotificationPreferencesRepository;
+pub use post_repository::PostRepository;
+pub use comment_repository::CommentRepository;
+pub use block_repository::BlockRepository;
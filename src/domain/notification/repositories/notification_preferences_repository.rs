@@ -0,0 +1,9 @@
+use crate::common::RepositoryResult;
+use crate::domain::account::aggregates::UserId;
+use crate::domain::notification::aggregates::NotificationPreferences;
+
+#[tonic::async_trait]
+pub trait NotificationPreferencesRepository {
+    async fn get(&self, user: &UserId) -> RepositoryResult<Option<NotificationPreferences>>;
+    async fn set(&self, preferences: &NotificationPreferences) -> RepositoryResult<()>;
+}
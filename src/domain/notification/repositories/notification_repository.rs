@@ -0,0 +1,14 @@
+use crate::common::RepositoryResult;
+use crate::domain::account::aggregates::UserId;
+use crate::domain::notification::aggregates::{Notification, NotificationId};
+
+#[tonic::async_trait]
+pub trait NotificationRepository {
+    async fn list(&self, recipient: &UserId, unread_only: bool, after: &Option<NotificationId>) -> RepositoryResult<Vec<Notification>>;
+    async fn count_unread(&self, recipient: &UserId) -> RepositoryResult<u64>;
+    // note: the unread notification the next one of the same key aggregates into
+    async fn get_unread(&self, recipient: &UserId, key: &str) -> RepositoryResult<Option<Notification>>;
+    async fn set(&self, notification: &Notification) -> RepositoryResult<()>;
+    async fn mark_read(&self, recipient: &UserId, ids: &[NotificationId]) -> RepositoryResult<u64>;
+    async fn mark_all_read(&self, recipient: &UserId) -> RepositoryResult<u64>;
+}
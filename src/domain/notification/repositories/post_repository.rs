@@ -0,0 +1,8 @@
+use crate::common::RepositoryResult;
+use crate::domain::social::aggregates::{Post, PostId};
+
+// note: posts are looked up to notify their authors
+#[tonic::async_trait]
+pub trait PostRepository {
+    async fn get(&self, id: &PostId) -> RepositoryResult<Option<Post>>;
+}
@@ -0,0 +1,16 @@
+use std::fmt::Formatter;
+
+#[derive(Debug)]
+pub enum DomainError {
+    TooManyNotifications,
+}
+
+impl std::fmt::Display for DomainError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DomainError::TooManyNotifications => write!(f, "too many notifications"),
+        }
+    }
+}
+
+impl std::error::Error for DomainError {}
@@ -0,0 +1,5 @@
+pub mod usecase;
+pub mod error;
+
+pub use error::DomainError;
+pub use usecase::{NotificationUsecase, Result};
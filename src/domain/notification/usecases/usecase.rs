@@ -0,0 +1,265 @@
+use std::error::Error;
+use std::sync::Arc;
+use chrono::{DateTime, Utc};
+use itertools::Itertools;
+use tokio::sync::broadcast;
+use crate::common::{EventConsumeError, EventConsumer, EventConsumerClient, EventPublisher, StoredEvent};
+use crate::domain::account::aggregates::UserId;
+use crate::domain::notification::aggregates::{Notification, NotificationId, NotificationKind, NotificationPreferences};
+use crate::domain::notification::commands::{MarkAllNotificationsRead, MarkNotificationsRead, UpdateNotificationPreferences};
+use crate::domain::notification::events::{AllNotificationsReadV1, NotificationPreferencesUpdatedV1, NotificationsReadV1};
+use crate::domain::notification::repositories::{BlockRepository, CommentRepository, NotificationPreferencesRepository, NotificationRepository, PostRepository};
+use crate::domain::notification::usecases::DomainError;
+use crate::domain::social::aggregates::{CommentId, CommentParent, PostId};
+use crate::domain::social::events::{CommentPublishedV1, CommentPublishedV2, MemberPromotedToEditorV1, MembershipOfferedV1, ReactedToCommentV2, ReactedToPostV1, ReactedToPostV2, UserFollowedV1, UserMentionedV1};
+
+pub type Result<T> = std::result::Result<T, Box<dyn Error + Send + Sync>>;
+
+pub struct NotificationUsecase {
+    notification_repository: Box<dyn NotificationRepository + Send + Sync>,
+    preferences_repository: Box<dyn NotificationPreferencesRepository + Send + Sync>,
+    post_repository: Box<dyn PostRepository + Send + Sync>,
+    comment_repository: Box<dyn CommentRepository + Send + Sync>,
+    block_repository: Box<dyn BlockRepository + Send + Sync>,
+    event_consumer: EventConsumer,
+    event_publisher: Arc<EventPublisher>,
+    notifications: broadcast::Sender<Notification>, // inboxes watched in this process
+}
+
+impl NotificationUsecase {
+    // position in the published events is kept under this name, see the migration starting it
+    const CONSUMER: &'static str = "notifications";
+    // number of notifications buffered for slow watchers, before they skip ahead
+    const NOTIFICATION_CAPACITY: usize = 256;
+    // most notifications marked read at once by clients
+    const MAX_BATCH_SIZE: usize = 100;
+
+    pub fn build(
+        notification_repository: Box<dyn NotificationRepository + Send + Sync>,
+        preferences_repository: Box<dyn NotificationPreferencesRepository + Send + Sync>,
+        post_repository: Box<dyn PostRepository + Send + Sync>,
+        comment_repository: Box<dyn CommentRepository + Send + Sync>,
+        block_repository: Box<dyn BlockRepository + Send + Sync>,
+        event_consumer_client: Box<dyn EventConsumerClient + Send + Sync>,
+        event_publisher: Arc<EventPublisher>) -> NotificationUsecase {
+        NotificationUsecase {
+            notification_repository,
+            preferences_repository,
+            post_repository,
+            comment_repository,
+            block_repository,
+            event_consumer: EventConsumer::build(event_consumer_client, NotificationUsecase::CONSUMER),
+            event_publisher,
+            notifications: broadcast::channel(NotificationUsecase::NOTIFICATION_CAPACITY).0,
+        }
+    }
+
+    // commands
+    pub async fn mark_notifications_read(&self, command: MarkNotificationsRead) -> Result<()> {
+        // rule: limit the amount marked at once
+        if command.notifications.len() > NotificationUsecase::MAX_BATCH_SIZE {
+            return Err(DomainError::TooManyNotifications.into());
+        }
+
+        // note: unknown notifications, and those of others, are skipped
+        let notifications = command.notifications.into_iter().unique().collect_vec();
+        if self.notification_repository.mark_read(&command.recipient, &notifications).await? == 0 {
+            return Ok(());
+        }
+
+        let event = NotificationsReadV1 {
+            recipient: command.recipient,
+            notifications,
+        };
+        self.event_publisher.publish(&event).await?;
+
+        Ok(())
+    }
+
+    pub async fn mark_all_notifications_read(&self, command: MarkAllNotificationsRead) -> Result<()> {
+        if self.notification_repository.mark_all_read(&command.recipient).await? == 0 {
+            return Ok(());
+        }
+
+        let event = AllNotificationsReadV1 {
+            recipient: command.recipient,
+        };
+        self.event_publisher.publish(&event).await?;
+
+        Ok(())
+    }
+
+    pub async fn update_preferences(&self, command: UpdateNotificationPreferences) -> Result<()> {
+        let now = Utc::now();
+        let mut preferences = self.preferences_repository
+            .get(&command.user)
+            .await?
+            .unwrap_or_else(|| NotificationPreferences::new(command.user.clone(), now));
+
+        preferences.muted = command.muted.into_iter().unique().collect();
+        preferences.updated = now;
+        self.preferences_repository.set(&preferences).await?;
+
+        let event = NotificationPreferencesUpdatedV1 {
+            user: command.user,
+            muted: preferences.muted,
+        };
+        self.event_publisher.publish(&event).await?;
+
+        Ok(())
+    }
+
+    // note: handles the events published since the last run; an event failing is retried on the next run
+    pub async fn consume_events(&self) -> Result<()> {
+        for event in self.event_consumer.poll().await? {
+            match self.handle(&event).await {
+                // note: events that can't be read are skipped, rather than holding up the ones after them
+                Err(error) if !error.is::<EventConsumeError>() => return Err(error),
+                _ => self.event_consumer.acknowledge(&event).await?,
+            }
+        }
+
+        Ok(())
+    }
+
+    // queries
+    pub async fn list_notifications(&self, recipient: UserId, unread_only: bool, after: Option<NotificationId>) -> Result<Vec<Notification>> {
+        self.notification_repository
+            .list(&recipient, unread_only, &after).await
+            .map_err(|err| err.into())
+    }
+
+    pub async fn count_unread(&self, recipient: UserId) -> Result<u64> {
+        self.notification_repository
+            .count_unread(&recipient).await
+            .map_err(|err| err.into())
+    }
+
+    pub async fn get_preferences(&self, user: UserId) -> Result<NotificationPreferences> {
+        let preferences = self.preferences_repository
+            .get(&user)
+            .await?
+            .unwrap_or_else(|| NotificationPreferences::new(user, Utc::now()));
+
+        Ok(preferences)
+    }
+
+    // note: notifications of all recipients, as they're created or aggregated by this process
+    pub fn watch_notifications(&self) -> broadcast::Receiver<Notification> {
+        self.notifications.subscribe()
+    }
+
+    // note: events are delivered at least once; aggregation by actor makes handling them again harmless
+    async fn handle(&self, event: &StoredEvent) -> Result<()> {
+        match event.kind.as_str() {
+            "CommentPublishedV1" => {
+                let event: CommentPublishedV1 = event.parse()?;
+                self.notify_post_author(&event.reply_to, NotificationKind::PostCommented { post: event.reply_to.clone() }, event.author, event.published).await
+            },
+            "CommentPublishedV2" => {
+                let event: CommentPublishedV2 = event.parse()?;
+                match event.parent {
+                    CommentParent::Post(post) => {
+                        self.notify_post_author(&post, NotificationKind::PostCommented { post: post.clone() }, event.author, event.published).await
+                    },
+                    CommentParent::Comment(comment) => {
+                        let kind = NotificationKind::CommentReplied { post: event.reply_to, comment: comment.clone() };
+                        self.notify_comment_author(&comment, kind, event.author, event.published).await
+                    },
+                }
+            },
+            "ReactedToPostV1" => {
+                let event: ReactedToPostV1 = event.parse()?;
+                let (author, post) = event.reaction.values();
+                self.notify_post_author(post, NotificationKind::PostReacted { post: post.clone() }, author.clone(), Utc::now()).await
+            },
+            "ReactedToPostV2" => {
+                let event: ReactedToPostV2 = event.parse()?;
+                let post = event.reaction.post;
+                self.notify_post_author(&post, NotificationKind::PostReacted { post: post.clone() }, event.reaction.author, Utc::now()).await
+            },
+            "ReactedToCommentV2" => {
+                let event: ReactedToCommentV2 = event.parse()?;
+                match self.comment_repository.get(&event.reaction.comment).await? {
+                    Some(comment) => {
+                        let kind = NotificationKind::CommentReacted { post: comment.reply_to, comment: comment.id };
+                        self.notify(comment.author, kind, Some(event.reaction.author), Utc::now()).await
+                    },
+                    None => Ok(()),
+                }
+            },
+            "UserMentionedV1" => {
+                let event: UserMentionedV1 = event.parse()?;
+                let kind = NotificationKind::Mentioned { post: event.post, comment: event.comment };
+                self.notify(event.user, kind, Some(event.author), event.mentioned).await
+            },
+            "UserFollowedV1" => {
+                let event: UserFollowedV1 = event.parse()?;
+                self.notify(event.followee, NotificationKind::Followed, Some(event.follower), event.followed).await
+            },
+            "MemberPromotedToEditorV1" => {
+                let event: MemberPromotedToEditorV1 = event.parse()?;
+                self.notify(event.member, NotificationKind::PromotedToEditor { community: event.community }, None, Utc::now()).await
+            },
+            "MembershipOfferedV1" => {
+                let event: MembershipOfferedV1 = event.parse()?;
+                self.notify(event.person, NotificationKind::MembershipOffered { community: event.community }, None, Utc::now()).await
+            },
+            _ => Ok(()),
+        }
+    }
+
+    async fn notify_post_author(&self, post: &PostId, kind: NotificationKind, actor: UserId, at: DateTime<Utc>) -> Result<()> {
+        // note: posts purged in the meantime no longer notify
+        match self.post_repository.get(post).await? {
+            Some(post) => self.notify(post.author, kind, Some(actor), at).await,
+            None => Ok(()),
+        }
+    }
+
+    async fn notify_comment_author(&self, comment: &CommentId, kind: NotificationKind, actor: UserId, at: DateTime<Utc>) -> Result<()> {
+        // note: comments purged in the meantime no longer notify
+        match self.comment_repository.get(comment).await? {
+            Some(comment) => self.notify(comment.author, kind, Some(actor), at).await,
+            None => Ok(()),
+        }
+    }
+
+    async fn notify(&self, recipient: UserId, kind: NotificationKind, actor: Option<UserId>, at: DateTime<Utc>) -> Result<()> {
+        if let Some(actor) = &actor {
+            // rule: users aren't notified of their own actions
+            if actor == &recipient {
+                return Ok(());
+            }
+
+            // rule: users aren't notified of the actions of users they blocked
+            if self.block_repository.is_blocked(&recipient, actor).await? {
+                return Ok(());
+            }
+        }
+
+        // rule: muted categories aren't notified
+        let muted = self.preferences_repository
+            .get(&recipient)
+            .await?
+            .map(|preferences| preferences.is_muted(kind.category()))
+            .unwrap_or(false);
+        if muted {
+            return Ok(());
+        }
+
+        let notification = match self.notification_repository.get_unread(&recipient, kind.key().as_str()).await? {
+            Some(mut notification) => {
+                notification.aggregate(actor, at);
+                notification
+            },
+            None => Notification::new(recipient, kind, actor, at),
+        };
+        self.notification_repository.set(&notification).await?;
+
+        // note: fails only when nobody is watching
+        let _ = self.notifications.send(notification);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,12 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::domain::account::aggregates::UserId;
+
+// note: blocks are private to the blocker, the blocked user isn't told
+#[derive(Serialize, Deserialize, Clone)]
+pub struct Block {
+    pub blocker: UserId,
+    pub user: UserId,
+    pub blocked: DateTime<Utc>,
+}
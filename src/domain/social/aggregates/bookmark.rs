@@ -0,0 +1,28 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::domain::social::aggregates::{CommentId, PostId};
+use crate::domain::account::aggregates::UserId;
+
+// note: bookmarks are private to their owner
+#[derive(Serialize, Deserialize, Clone)]
+pub struct Bookmark {
+    pub owner: UserId,
+    pub target: BookmarkTarget,
+    pub bookmarked: DateTime<Utc>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Eq, Clone)]
+pub enum BookmarkTarget {
+    Post(PostId),
+    Comment(CommentId),
+}
+
+impl BookmarkTarget {
+    pub fn values(&self) -> (&str, String) {
+        match self {
+            BookmarkTarget::Post(id) => ("post", id.to_string()),
+            BookmarkTarget::Comment(id) => ("comment", id.to_string()),
+        }
+    }
+}
@@ -1,7 +1,7 @@
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
-use crate::domain::social::aggregates::{CommentId, CommentText, PostId};
+use crate::domain::social::aggregates::{CommentId, CommentText, PostId, TextEntity, Tombstone};
 use crate::domain::account::aggregates::UserId;
 
 #[derive(Serialize, Deserialize)]
@@ -11,17 +11,96 @@ pub struct Comment {
     pub text: CommentText,
     pub author: UserId,
     pub published: DateTime<Utc>,
+    #[serde(default)]
+    pub parent_comment: Option<CommentId>, // none for top-level comments
+    #[serde(default)]
+    pub depth: u32, // 0 for top-level comments
+    #[serde(default)]
+    pub edited: Option<DateTime<Utc>>,
+    #[serde(default)]
+    pub revisions: Vec<CommentRevision>,
+    #[serde(default)]
+    pub removed: Option<Tombstone>,
+    #[serde(default)]
+    pub entities: Vec<TextEntity>, // hashtags & mentions in text
+}
+
+// note: reply_to always refers to the post a thread belongs to, parent to what is replied to directly
+#[derive(Serialize, Deserialize, Clone)]
+pub enum CommentParent {
+    Post(PostId),
+    Comment(CommentId),
+}
+
+// note: revision holds a former text, and the moment that text went live
+#[derive(Serialize, Deserialize, Clone)]
+pub struct CommentRevision {
+    pub text: CommentText,
+    pub published: DateTime<Utc>,
 }
 
 impl Comment {
     pub fn new(id: CommentId, reply_to: PostId, text: CommentText, author: UserId, published: DateTime<Utc>) -> Comment {
         Comment {
+            entities: text.entities(),
             id,
             reply_to,
             text,
             author,
             published,
+            parent_comment: None,
+            depth: 0,
+            edited: None,
+            revisions: Vec::new(),
+            removed: None,
+        }
+    }
+
+    pub fn reply(&self, id: CommentId, text: CommentText, author: UserId, published: DateTime<Utc>) -> Comment {
+        Comment {
+            parent_comment: Some(self.id.clone()),
+            depth: self.depth + 1,
+            ..Comment::new(id, self.reply_to.clone(), text, author, published)
+        }
+    }
+
+    pub fn parent(&self) -> CommentParent {
+        match &self.parent_comment {
+            Some(comment) => CommentParent::Comment(comment.clone()),
+            None => CommentParent::Post(self.reply_to.clone()),
+        }
+    }
+
+    pub fn edit(&mut self, text: CommentText, at: DateTime<Utc>) {
+        self.entities = text.entities();
+        let revision = CommentRevision {
+            text: std::mem::replace(&mut self.text, text),
+            published: self.edited.unwrap_or(self.published),
+        };
+
+        self.revisions.push(revision);
+        self.edited = Some(at);
+    }
+
+    // note: authors mentioning themselves are not considered
+    pub fn mentions(&self) -> Vec<UserId> {
+        TextEntity::mentions(&self.entities)
+            .into_iter()
+            .filter(|user| *user != self.author)
+            .collect()
+    }
+
+    pub fn is_removed(&self) -> bool {
+        self.removed.is_some()
+    }
+
+    pub fn remove(&mut self, tombstone: Tombstone) -> bool {
+        if self.is_removed() {
+            return false;
         }
+
+        self.removed = Some(tombstone);
+        true
     }
 }
 
@@ -0,0 +1,15 @@
+use std::collections::HashMap;
+use crate::domain::social::aggregates::{Comment, ReactionKey, ViewerState};
+
+// note: comment listings are transient; meaning they should be derived and not persisted
+pub struct CommentListing {
+    pub comment: Comment,
+    pub replies: u64,
+    pub reactions: HashMap<ReactionKey, u64>,
+    pub viewer: ViewerState,
+}
+
+impl CommentListing {
+    // maximum number of replies listed along with each top-level comment
+    pub const MAX_REPLY_PREVIEW: u32 = 10;
+}
@@ -0,0 +1,11 @@
+use serde::{Deserialize, Serialize};
+
+use crate::domain::social::aggregates::{CommentId, ReactionKey};
+use crate::domain::account::aggregates::UserId;
+
+#[derive(Serialize, Deserialize, PartialEq, Eq, Clone)]
+pub struct CommentReaction {
+    pub author: UserId,
+    pub comment: CommentId,
+    pub reaction: ReactionKey,
+}
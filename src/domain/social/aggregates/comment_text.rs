@@ -3,6 +3,8 @@ use chumsky::prelude::{filter, Parser, Simple};
 use chumsky::text::TextParser;
 use serde::{Deserialize, Serialize};
 
+use crate::domain::social::aggregates::TextEntity;
+
 #[derive(Serialize, Deserialize, Clone)]
 pub struct CommentText {
     raw: String,
@@ -31,6 +33,10 @@ impl CommentText {
             .parse(input)
             .map_err(|_| ParseError::MalformedInput)
     }
+
+    pub fn entities(&self) -> Vec<TextEntity> {
+        TextEntity::extract(self.raw.as_str())
+    }
 }
 
 impl Display for CommentText {
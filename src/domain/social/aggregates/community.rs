@@ -3,8 +3,9 @@ use serde::{Deserialize, Serialize};
 use std::collections::HashSet;
 
 use crate::domain::club::aggregates::ClubId;
+use crate::domain::competition::aggregates::CompetitionId;
 use crate::domain::media::aggregates::ImageId;
-use crate::domain::social::aggregates::{CommunityId, CommunityName};
+use crate::domain::social::aggregates::{CommunityId, CommunityName, ModerationReason, ReactionSet};
 use crate::domain::team::aggregates::TeamId;
 use crate::domain::account::aggregates::UserId;
 
@@ -17,6 +18,14 @@ pub struct Community {
     pub logo: Option<ImageId>,
     pub editors: HashSet<UserId>,
     pub members: HashSet<UserId>, // convenient, but limited scalable (inverse if too slow)
+    #[serde(default)]
+    pub bans: Vec<Ban>,
+    #[serde(default)]
+    pub mutes: Vec<Mute>,
+    #[serde(default)]
+    pub reactions: ReactionSet,
+    #[serde(default)]
+    pub offers: HashSet<UserId>, // users offered membership, e.g. players of the team
 }
 
 // note: "ownership" of a community is derived from its context (e.g. club owner = community owner)
@@ -24,6 +33,39 @@ pub struct Community {
 pub enum CommunityContext {
     Club(ClubId),
     Team(TeamId),
+    Competition(CompetitionId),
+}
+
+// note: a ban without expiry is permanent
+#[derive(Serialize, Deserialize, Clone)]
+pub struct Ban {
+    pub user: UserId,
+    pub reason: ModerationReason,
+    pub banned_by: UserId,
+    pub banned: DateTime<Utc>,
+    pub expires: Option<DateTime<Utc>>,
+}
+
+impl Ban {
+    pub fn is_active(&self, at: &DateTime<Utc>) -> bool {
+        match self.expires {
+            Some(expires) => expires > *at,
+            None => true,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct Mute {
+    pub member: UserId,
+    pub muted_by: UserId,
+    pub until: DateTime<Utc>,
+}
+
+impl Mute {
+    pub fn is_active(&self, at: &DateTime<Utc>) -> bool {
+        self.until > *at
+    }
 }
 
 impl Community {
@@ -41,9 +83,29 @@ impl Community {
             logo: Option::None,
             editors: HashSet::new(),
             members: HashSet::new(),
+            bans: Vec::new(),
+            mutes: Vec::new(),
+            reactions: ReactionSet::default(),
+            offers: HashSet::new(),
         }
     }
 
+    pub fn is_editor(&self, user: &UserId) -> bool {
+        self.editors.contains(user)
+    }
+
+    pub fn is_banned(&self, user: &UserId, at: &DateTime<Utc>) -> bool {
+        self.bans.iter().any(|ban| ban.user == *user && ban.is_active(at))
+    }
+
+    pub fn is_muted(&self, member: &UserId, at: &DateTime<Utc>) -> bool {
+        self.mutes.iter().any(|mute| mute.member == *member && mute.is_active(at))
+    }
+
+    pub fn set_reactions(&mut self, reactions: ReactionSet) {
+        self.reactions = reactions
+    }
+
     pub fn set_logo(&mut self, logo: &ImageId) {
         self.logo = Option::Some(logo.clone())
     }
@@ -62,16 +124,66 @@ impl Community {
         self.editors.remove(editor)
     }
 
-    pub fn join(&mut self, member: &UserId) -> bool {
+    pub fn join(&mut self, member: &UserId, at: &DateTime<Utc>) -> bool {
+        // rule: banned users cannot (re)join until their ban expires
+        if self.is_banned(member, at) {
+            return false
+        }
+        self.offers.remove(member);
         self.members.insert(member.clone())
     }
 
+    pub fn offer_membership(&mut self, user: &UserId, at: &DateTime<Utc>) -> bool {
+        // rule: no offers to members or banned users
+        if self.members.contains(user) || self.is_banned(user, at) {
+            return false
+        }
+        self.offers.insert(user.clone())
+    }
+
+    pub fn withdraw_offer(&mut self, user: &UserId) -> bool {
+        self.offers.remove(user)
+    }
+
     pub fn leave(&mut self, member: &UserId) -> bool {
         if self.editors.contains(member) {
             return false
         }
         self.members.remove(member)
     }
+
+    pub fn remove_member(&mut self, member: &UserId) -> bool {
+        self.mutes.retain(|mute| mute.member != *member);
+        self.members.remove(member)
+    }
+
+    pub fn ban(&mut self, ban: Ban) -> bool {
+        self.members.remove(&ban.user);
+        self.offers.remove(&ban.user);
+        self.mutes.retain(|mute| mute.member != ban.user);
+        self.bans.retain(|existing| existing.user != ban.user);
+        self.bans.push(ban);
+
+        true
+    }
+
+    pub fn unban(&mut self, user: &UserId) -> bool {
+        let before = self.bans.len();
+        self.bans.retain(|ban| ban.user != *user);
+
+        self.bans.len() != before
+    }
+
+    pub fn mute(&mut self, mute: Mute) -> bool {
+        // rule: only members can be muted
+        if !self.members.contains(&mute.member) {
+            return false
+        }
+        self.mutes.retain(|existing| existing.member != mute.member);
+        self.mutes.push(mute);
+
+        true
+    }
 }
 
 impl PartialEq for Community {
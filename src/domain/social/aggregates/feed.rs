@@ -1,24 +1,26 @@
-use crate::domain::social::aggregates::{CommunityId, Post};
+use crate::domain::social::aggregates::{CommunityId, Hashtag, PollTally, Post, ReactionKey, ViewerState};
 use crate::domain::account::aggregates::UserId;
+use std::collections::HashMap;
 use std::slice::Iter;
 
 // note: feed & friends are transient; meaning they should be derived and not persisted
 pub enum Feed {
     Memberships(UserId),
     Community(CommunityId),
+    Hashtag(Hashtag),
+    Following(UserId), // posts by the users followed, across communities
 }
 
 pub struct FeedListing {
     pub post: Post,
     pub comments: u64,
-    pub reactions_love: u64,
-    pub reactions_funny: u64,
-    pub reactions_celebrate: u64,
-    pub reactions_support: u64,
-    pub reactions_insightful: u64,
+    pub reactions: HashMap<ReactionKey, u64>,
+    pub viewer: ViewerState,
+    pub poll: Option<PollTally>, // present when the post has a poll attachment
 }
 
 pub struct FeedFragment {
+    pinned: Vec<FeedListing>,
     listings: Vec<FeedListing>,
 }
 
@@ -26,7 +28,13 @@ impl FeedFragment {
     pub const MAX_ELEMENTS: usize = 25;
 
     pub fn from_vec(listings: Vec<FeedListing>) -> FeedFragment {
+        FeedFragment::with_pinned(Vec::new(), listings)
+    }
+
+    // note: pinned listings are shown above the regular listings, on the first fragment only
+    pub fn with_pinned(pinned: Vec<FeedListing>, listings: Vec<FeedListing>) -> FeedFragment {
         FeedFragment {
+            pinned,
             listings: listings
                 .into_iter()
                 .take(FeedFragment::MAX_ELEMENTS)
@@ -37,4 +45,8 @@ impl FeedFragment {
     pub fn iter(&self) -> Iter<'_, FeedListing> {
         self.listings.iter()
     }
+
+    pub fn pinned(&self) -> Iter<'_, FeedListing> {
+        self.pinned.iter()
+    }
 }
@@ -0,0 +1,12 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::domain::account::aggregates::UserId;
+
+// note: follows are public, and independent of community memberships
+#[derive(Serialize, Deserialize, Clone)]
+pub struct Follow {
+    pub follower: UserId,
+    pub followee: UserId,
+    pub followed: DateTime<Utc>,
+}
@@ -0,0 +1,52 @@
+use std::fmt::{Display, Formatter};
+use chumsky::prelude::{end, filter, Parser, Simple};
+use serde::{Deserialize, Serialize};
+use std::hash::{Hash, Hasher};
+
+#[derive(Serialize, Deserialize, PartialEq, Eq, Clone)]
+pub struct Hashtag {
+    raw: String,
+}
+
+#[derive(Debug)]
+pub enum ParseError {
+    MalformedInput,
+}
+
+impl Hashtag {
+    pub const MAX_LENGTH: usize = 64;
+
+    fn parser() -> impl Parser<char, Hashtag, Error = Simple<char>> {
+        // tag (without leading #) consists of letters, digits and underscores
+        // length between 1 and 64 characters, matched case insensitive
+        filter(|c: &char| Hashtag::is_tag_char(c))
+            .repeated()
+            .at_least(1)
+            .at_most(Hashtag::MAX_LENGTH)
+            .collect::<String>()
+            .map(|tag| Hashtag { raw: tag.to_lowercase() })
+            .then_ignore(end())
+    }
+
+    pub fn parse(input: &str) -> Result<Hashtag, ParseError> {
+        Hashtag::parser()
+            .parse(input)
+            .map_err(|_| ParseError::MalformedInput)
+    }
+
+    pub fn is_tag_char(c: &char) -> bool {
+        c.is_alphanumeric() || *c == '_'
+    }
+}
+
+impl Display for Hashtag {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.raw)
+    }
+}
+
+impl Hash for Hashtag {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.raw.hash(state)
+    }
+}
@@ -0,0 +1,44 @@
+use std::fmt::{Display, Formatter};
+use chumsky::prelude::{end, filter, Parser, Simple};
+use serde::{Deserialize, Serialize};
+use std::hash::{Hash, Hasher};
+
+#[derive(Serialize, Deserialize, PartialEq, Eq, Clone)]
+pub struct Language {
+    raw: String,
+}
+
+#[derive(Debug)]
+pub enum ParseError {
+    MalformedInput,
+}
+
+impl Language {
+    fn parser() -> impl Parser<char, Language, Error = Simple<char>> {
+        // language is a ISO 639-1 code, consisting of 2 lowercase ascii characters
+        filter(|c: &char| c.is_ascii_lowercase())
+            .repeated()
+            .exactly(2)
+            .collect::<String>()
+            .map(|code| Language { raw: code })
+            .then_ignore(end())
+    }
+
+    pub fn parse(input: &str) -> Result<Language, ParseError> {
+        Language::parser()
+            .parse(input)
+            .map_err(|_| ParseError::MalformedInput)
+    }
+}
+
+impl Display for Language {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.raw)
+    }
+}
+
+impl Hash for Language {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.raw.hash(state)
+    }
+}
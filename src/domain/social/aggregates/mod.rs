@@ -1,25 +1,73 @@
+pub mod block;
+pub mod bookmark;
 pub mod comment;
 pub mod comment_id;
+pub mod comment_listing;
+pub mod comment_reaction;
 pub mod comment_text;
 pub mod community;
 pub mod community_id;
 pub mod community_name;
 pub mod feed;
+pub mod follow;
+pub mod hashtag;
+pub mod language;
+pub mod moderation_reason;
+pub mod poll;
+pub mod poll_option;
 pub mod post;
 pub mod post_attachment;
 pub mod post_id;
+pub mod post_kind;
+pub mod post_status;
 pub mod post_reaction;
 pub mod post_text;
+pub mod reaction;
+pub mod reaction_emoji;
+pub mod reaction_key;
+pub mod report;
+pub mod report_id;
+pub mod report_text;
+pub mod search;
+pub mod search_query;
+pub mod text_entity;
+pub mod tombstone;
+pub mod viewer_state;
+pub mod word_list;
 
-pub use comment::Comment;
+pub use block::Block;
+pub use bookmark::{Bookmark, BookmarkTarget};
+pub use comment::{Comment, CommentParent, CommentRevision};
 pub use comment_id::CommentId;
+pub use comment_listing::CommentListing;
+pub use comment_reaction::CommentReaction;
 pub use comment_text::CommentText;
-pub use community::{Community, CommunityContext};
+pub use community::{Ban, Community, CommunityContext, Mute};
 pub use community_id::CommunityId;
 pub use community_name::CommunityName;
 pub use feed::{Feed, FeedListing, FeedFragment};
-pub use post::Post;
+pub use follow::Follow;
+pub use hashtag::Hashtag;
+pub use language::Language;
+pub use moderation_reason::ModerationReason;
+pub use poll::{Poll, PollChoice, PollTally, PollVisibility, PollVote};
+pub use poll_option::PollOption;
+pub use post::{Post, PostRevision};
 pub use post_attachment::{PostAttachment, PostAttachments};
 pub use post_id::PostId;
-pub use post_reaction::PostReaction;
+pub use post_kind::PostKind;
+pub use post_status::PostStatus;
+pub use post_reaction::{PostReaction, PostReactionRecord};
 pub use post_text::PostText;
+pub use reaction::{catalog, ReactionDefinition, ReactionSet};
+pub use reaction_emoji::ReactionEmoji;
+pub use reaction_key::ReactionKey;
+pub use report::{Report, ReportAction, ReportAuditEntry, ReportCategory, ReportStatus, ReportTarget};
+pub use report_id::ReportId;
+pub use report_text::ReportText;
+pub use search::{Highlight, SearchHit, SearchKind, SearchTarget};
+pub use search_query::SearchQuery;
+pub use text_entity::{TextEntity, TextEntityKind};
+pub use tombstone::{RemoverRole, Tombstone};
+pub use viewer_state::ViewerState;
+pub use word_list::{WordList, WordListAction};
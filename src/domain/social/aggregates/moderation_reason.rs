@@ -0,0 +1,41 @@
+use std::fmt::{Display, Formatter};
+use chumsky::prelude::{filter, Parser, Simple};
+use chumsky::text::TextParser;
+use serde::{Deserialize, Serialize};
+
+// note: reason moderators give for a ban or removal, shown to the user concerned
+#[derive(Serialize, Deserialize, Clone)]
+pub struct ModerationReason {
+    raw: String,
+}
+
+#[derive(Debug)]
+pub enum ParseError {
+    MalformedInput,
+}
+
+impl ModerationReason {
+    fn parser() -> impl Parser<char, ModerationReason, Error = Simple<char>> {
+        // reason should only contain visible (non-control) characters
+        // length between 2 and 500 characters
+        filter(|c: &char| !c.is_ascii_control())
+            .repeated()
+            .at_least(2)
+            .at_most(500)
+            .padded()
+            .collect::<String>()
+            .map(|reason| ModerationReason { raw: reason })
+    }
+
+    pub fn parse(input: &str) -> Result<ModerationReason, ParseError> {
+        ModerationReason::parser()
+            .parse(input)
+            .map_err(|_| ParseError::MalformedInput)
+    }
+}
+
+impl Display for ModerationReason {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.raw)
+    }
+}
@@ -0,0 +1,78 @@
+use chrono::{DateTime, Utc};
+use itertools::Itertools;
+use serde::{Deserialize, Serialize};
+
+use crate::domain::account::aggregates::UserId;
+use crate::domain::social::aggregates::{PollOption, PostId};
+
+#[derive(Serialize, Deserialize, PartialEq, Eq, Hash, Clone, Copy)]
+pub enum PollChoice {
+    Single,
+    Multiple,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Eq, Hash, Clone, Copy)]
+pub enum PollVisibility {
+    Anonymous, // votes are tallied, voters are never disclosed
+    Public,
+}
+
+// note: poll is a value object (DDD), the post text acts as its question
+#[derive(Serialize, Deserialize, PartialEq, Eq, Hash, Clone)]
+pub struct Poll {
+    options: Vec<PollOption>,
+    pub choice: PollChoice,
+    pub closes: Option<DateTime<Utc>>,
+    pub visibility: PollVisibility,
+}
+
+impl Poll {
+    pub const MIN_OPTIONS: usize = 2;
+    pub const MAX_OPTIONS: usize = 10;
+
+    pub fn new(options: Vec<PollOption>, choice: PollChoice, closes: Option<DateTime<Utc>>, visibility: PollVisibility) -> Option<Poll> {
+        // rule: options are unique, and limited in number
+        let count = options.len();
+        let options: Vec<PollOption> = options.into_iter().unique().collect();
+        if options.len() != count || !(Poll::MIN_OPTIONS..=Poll::MAX_OPTIONS).contains(&count) {
+            return None;
+        }
+
+        Some(Poll { options, choice, closes, visibility })
+    }
+
+    pub fn options(&self) -> &[PollOption] {
+        &self.options
+    }
+
+    pub fn is_closed(&self, at: &DateTime<Utc>) -> bool {
+        self.closes.filter(|closes| closes <= at).is_some()
+    }
+
+    // rule: choices refer to existing options, without duplicates; single choice polls take exactly one
+    pub fn accepts(&self, choices: &[u32]) -> bool {
+        let valid = !choices.is_empty()
+            && choices.iter().all(|choice| (*choice as usize) < self.options.len())
+            && choices.iter().unique().count() == choices.len();
+
+        match self.choice {
+            PollChoice::Single => valid && choices.len() == 1,
+            PollChoice::Multiple => valid,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct PollVote {
+    pub post: PostId,
+    pub voter: UserId,
+    pub choices: Vec<u32>, // option indexes
+    pub voted: DateTime<Utc>,
+}
+
+// note: tally is transient; it is derived from the votes when listing
+pub struct PollTally {
+    pub votes: Vec<u64>, // per option, in option order
+    pub voters: u64,
+    pub viewer: Vec<u32>, // choices of the requesting user, empty when not voted
+}
@@ -0,0 +1,40 @@
+use std::fmt::{Display, Formatter};
+use chumsky::prelude::{filter, Parser, Simple};
+use chumsky::text::TextParser;
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, PartialEq, Eq, Hash, Clone)]
+pub struct PollOption {
+    raw: String,
+}
+
+#[derive(Debug)]
+pub enum ParseError {
+    MalformedInput,
+}
+
+impl PollOption {
+    fn parser() -> impl Parser<char, PollOption, Error = Simple<char>> {
+        // option shoud only contain visible (non-control) characters
+        // length between 1 en 100 characters
+        filter(|c: &char| !c.is_ascii_control())
+            .repeated()
+            .at_least(1)
+            .at_most(100)
+            .padded()
+            .collect::<String>()
+            .map(|option| PollOption { raw: option })
+    }
+
+    pub fn parse(input: &str) -> Result<PollOption, ParseError> {
+        PollOption::parser()
+            .parse(input)
+            .map_err(|_| ParseError::MalformedInput)
+    }
+}
+
+impl Display for PollOption {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.raw)
+    }
+}
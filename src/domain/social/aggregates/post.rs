@@ -2,7 +2,7 @@ use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
 use crate::domain::social::aggregates::CommunityId;
-use crate::domain::social::aggregates::{PostAttachments, PostId, PostText};
+use crate::domain::social::aggregates::{PostAttachments, PostId, PostKind, PostStatus, PostText, TextEntity, Tombstone};
 use crate::domain::account::aggregates::UserId;
 
 #[derive(Serialize, Deserialize)]
@@ -12,10 +12,33 @@ pub struct Post {
     pub text: PostText,
     pub attachments: PostAttachments,
     pub author: UserId,
-    pub published: DateTime<Utc>,
+    pub published: DateTime<Utc>, // moment of creation until the post actually goes live
+    #[serde(default)]
+    pub hidden: bool, // pending moderator review
+    #[serde(default)]
+    pub edited: Option<DateTime<Utc>>,
+    #[serde(default)]
+    pub revisions: Vec<PostRevision>,
+    #[serde(default)]
+    pub removed: Option<Tombstone>,
+    #[serde(default)]
+    pub kind: PostKind,
+    #[serde(default)]
+    pub pinned: Option<DateTime<Utc>>,
+    #[serde(default)]
+    pub status: PostStatus,
+    #[serde(default)]
+    pub entities: Vec<TextEntity>, // hashtags & mentions in text
 }
 // note: reactions + comments have inversed relationship
 
+// note: revision holds a former text, and the moment that text went live
+#[derive(Serialize, Deserialize, Clone)]
+pub struct PostRevision {
+    pub text: PostText,
+    pub published: DateTime<Utc>,
+}
+
 impl PartialEq for Post {
     fn eq(&self, other: &Self) -> bool {
         self.id == other.id
@@ -34,12 +57,139 @@ impl Post {
         published: DateTime<Utc>) -> Post {
 
         Post {
+            entities: text.entities(),
             id,
             community,
             text,
             attachments,
             author,
-            published
+            published,
+            hidden: false,
+            edited: None,
+            revisions: Vec::new(),
+            removed: None,
+            kind: PostKind::Regular,
+            pinned: None,
+            status: PostStatus::Published,
+        }
+    }
+
+    pub fn draft(
+        id: PostId,
+        community: CommunityId,
+        text: PostText,
+        attachments: PostAttachments,
+        author: UserId,
+        created: DateTime<Utc>) -> Post {
+
+        Post {
+            status: PostStatus::Draft,
+            ..Post::new(id, community, text, attachments, author, created)
+        }
+    }
+
+    pub fn is_published(&self) -> bool {
+        self.status.is_published()
+    }
+
+    // note: live posts are published and not removed
+    pub fn is_live(&self) -> bool {
+        self.is_published() && !self.is_removed()
+    }
+
+    pub fn schedule(&mut self, at: DateTime<Utc>) -> bool {
+        if self.is_published() {
+            return false;
+        }
+
+        self.status = PostStatus::Scheduled { at };
+        true
+    }
+
+    pub fn publish(&mut self, at: DateTime<Utc>) -> bool {
+        if self.is_published() {
+            return false;
+        }
+
+        self.status = PostStatus::Published;
+        self.published = at;
+        true
+    }
+
+    // note: drafts are edited in place, without keeping revisions
+    pub fn edit_draft(&mut self, text: PostText, attachments: PostAttachments) -> bool {
+        if self.is_published() {
+            return false;
+        }
+
+        self.entities = text.entities();
+        self.text = text;
+        self.attachments = attachments;
+        true
+    }
+
+    pub fn announce(&mut self, until: DateTime<Utc>) {
+        self.kind = PostKind::Announcement { until };
+    }
+
+    pub fn edit(&mut self, text: PostText, at: DateTime<Utc>) {
+        self.entities = text.entities();
+        let revision = PostRevision {
+            text: std::mem::replace(&mut self.text, text),
+            published: self.edited.unwrap_or(self.published),
+        };
+
+        self.revisions.push(revision);
+        self.edited = Some(at);
+    }
+
+    pub fn is_removed(&self) -> bool {
+        self.removed.is_some()
+    }
+
+    pub fn remove(&mut self, tombstone: Tombstone) -> bool {
+        if self.is_removed() {
+            return false;
+        }
+
+        self.removed = Some(tombstone);
+        true
+    }
+
+    // note: authors mentioning themselves are not considered
+    pub fn mentions(&self) -> Vec<UserId> {
+        TextEntity::mentions(&self.entities)
+            .into_iter()
+            .filter(|user| *user != self.author)
+            .collect()
+    }
+
+    pub fn is_pinned(&self) -> bool {
+        self.pinned.is_some()
+    }
+
+    pub fn pin(&mut self, at: DateTime<Utc>) -> bool {
+        if self.is_pinned() {
+            return false;
         }
+
+        self.pinned = Some(at);
+        true
+    }
+
+    pub fn unpin(&mut self) -> bool {
+        self.pinned.take().is_some()
+    }
+
+    pub fn hide(&mut self) -> bool {
+        let changed = !self.hidden;
+        self.hidden = true;
+        changed
+    }
+
+    pub fn unhide(&mut self) -> bool {
+        let changed = self.hidden;
+        self.hidden = false;
+        changed
     }
 }
\ No newline at end of file
@@ -2,11 +2,15 @@ use itertools::Itertools;
 use serde::{Deserialize, Serialize};
 use std::slice::Iter;
 
+use crate::domain::fixture::aggregates::{MatchId, MatchIncident};
 use crate::domain::media::aggregates::ImageId;
+use crate::domain::social::aggregates::Poll;
 
 #[derive(Serialize, Deserialize, PartialEq, Eq, Hash, Clone)]
 pub enum PostAttachment {
     Image(ImageId), //TODO: Video(VideoId)
+    Poll(Poll),
+    MatchIncident { fixture: MatchId, incident: MatchIncident }, // system posts of live tickers only
 }
 
 // note: attachment is value object (DDD)
@@ -20,17 +24,29 @@ impl PostAttachments {
     pub const MAX_ELEMENTS: usize = 10;
 
     pub fn from_vec(elements: Vec<PostAttachment>) -> PostAttachments {
-        // this silently drops > MAX_ELEMENTS elements
+        // this silently drops > MAX_ELEMENTS elements, and any poll besides the first
         // alternatively, could use Result + specialised error to signal behaviour to account
+        let mut polls = 0;
         PostAttachments {
             elements: elements
                 .into_iter()
                 .unique()
+                .filter(|element| match element {
+                    PostAttachment::Poll(_) => { polls += 1; polls == 1 },
+                    _ => true,
+                })
                 .take(PostAttachments::MAX_ELEMENTS)
                 .collect(),
         }
     }
 
+    pub fn poll(&self) -> Option<&Poll> {
+        self.elements.iter().find_map(|element| match element {
+            PostAttachment::Poll(poll) => Some(poll),
+            _ => None,
+        })
+    }
+
     pub fn iter(&self) -> Iter<'_, PostAttachment> {
         self.elements.iter()
     }
@@ -0,0 +1,23 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Clone, Default)]
+pub enum PostKind {
+    #[default]
+    Regular,
+    // note: announcements are published by editors and surface in member timelines until expiry
+    Announcement { until: DateTime<Utc> },
+}
+
+impl PostKind {
+    pub fn is_announcement(&self) -> bool {
+        matches!(self, PostKind::Announcement { .. })
+    }
+
+    pub fn announced_until(&self) -> Option<DateTime<Utc>> {
+        match self {
+            PostKind::Announcement { until } => Some(*until),
+            PostKind::Regular => None,
+        }
+    }
+}
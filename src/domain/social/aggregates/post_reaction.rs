@@ -1,25 +1,19 @@
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
-use crate::domain::social::aggregates::PostId;
+use crate::domain::social::aggregates::{PostId, ReactionKey};
 use crate::domain::account::aggregates::UserId;
 
 #[derive(Serialize, Deserialize, PartialEq, Eq, Clone)]
-pub enum PostReaction {
-    Love(UserId, PostId),
-    Funny(UserId, PostId),
-    Celebrate(UserId, PostId),
-    Support(UserId, PostId),
-    Insightful(UserId, PostId),
+pub struct PostReaction {
+    pub author: UserId,
+    pub post: PostId,
+    pub reaction: ReactionKey,
 }
 
-impl PostReaction {
-    pub fn values(&self) -> (&str, &UserId, &PostId){
-        match self {
-            PostReaction::Love(user, post) => ("love", user, post),
-            PostReaction::Funny(user, post) => ("funny", user, post),
-            PostReaction::Celebrate(user, post) => ("celebrate", user, post),
-            PostReaction::Support(user, post) => ("support", user, post),
-            PostReaction::Insightful(user, post) => ("insightful", user, post),
-        }
-    }
-}
\ No newline at end of file
+// note: record is a reaction as persisted, along with the moment it was given
+#[derive(Serialize, Deserialize, Clone)]
+pub struct PostReactionRecord {
+    pub reaction: PostReaction,
+    pub reacted: DateTime<Utc>,
+}
@@ -0,0 +1,32 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Clone, Default)]
+pub enum PostStatus {
+    // note: drafts & scheduled posts are only visible to their author
+    Draft,
+    Scheduled { at: DateTime<Utc> },
+    #[default]
+    Published,
+}
+
+impl PostStatus {
+    pub fn is_published(&self) -> bool {
+        matches!(self, PostStatus::Published)
+    }
+
+    pub fn scheduled(&self) -> Option<DateTime<Utc>> {
+        match self {
+            PostStatus::Scheduled { at } => Some(*at),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> &str {
+        match self {
+            PostStatus::Draft => "draft",
+            PostStatus::Scheduled { .. } => "scheduled",
+            PostStatus::Published => "published",
+        }
+    }
+}
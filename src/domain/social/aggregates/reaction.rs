@@ -0,0 +1,100 @@
+use serde::{Deserialize, Serialize};
+
+use crate::domain::social::aggregates::{ReactionEmoji, ReactionKey};
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct ReactionDefinition {
+    pub key: ReactionKey,
+    pub emoji: ReactionEmoji,
+}
+
+// note: global catalog, available to every community; the first five form the original (v1) set
+const CATALOG: [(&str, &str); 12] = [
+    ("love", "❤️"),
+    ("funny", "😂"),
+    ("celebrate", "🎉"),
+    ("support", "🤝"),
+    ("insightful", "💡"),
+    ("clap", "👏"),
+    ("fire", "🔥"),
+    ("goal", "⚽"),
+    ("save", "🧤"),
+    ("yellow-card", "🟨"),
+    ("red-card", "🟥"),
+    ("trophy", "🏆"),
+];
+
+const DEFAULT_ENABLED: usize = 5;
+
+pub fn catalog() -> Vec<ReactionDefinition> {
+    CATALOG
+        .iter()
+        .filter_map(|(key, emoji)| Some(ReactionDefinition {
+            key: ReactionKey::parse(key).ok()?,
+            emoji: ReactionEmoji::parse(emoji).ok()?,
+        }))
+        .collect()
+}
+
+// note: reaction set is the subset of the catalog a community enabled, plus its own custom reactions
+#[derive(Serialize, Deserialize, Clone)]
+pub struct ReactionSet {
+    enabled: Vec<ReactionKey>,
+    custom: Vec<ReactionDefinition>,
+}
+
+impl Default for ReactionSet {
+    fn default() -> Self {
+        ReactionSet {
+            enabled: catalog()
+                .into_iter()
+                .take(DEFAULT_ENABLED)
+                .map(|definition| definition.key)
+                .collect(),
+            custom: Vec::new(),
+        }
+    }
+}
+
+impl ReactionSet {
+    pub const MAX_ELEMENTS: usize = 20;
+
+    pub fn new(enabled: Vec<ReactionKey>, custom: Vec<ReactionDefinition>) -> Option<ReactionSet> {
+        let catalog = catalog();
+        let in_catalog = |key: &ReactionKey| catalog.iter().any(|definition| definition.key == *key);
+
+        // rule: enabled reactions must exist in the catalog, custom reactions must not shadow it
+        if !enabled.iter().all(in_catalog) || custom.iter().any(|definition| in_catalog(&definition.key)) {
+            return None;
+        }
+
+        // rule: keys are unique and the set is limited in size
+        let mut keys: Vec<&ReactionKey> = enabled.iter()
+            .chain(custom.iter().map(|definition| &definition.key))
+            .collect();
+        let count = keys.len();
+        keys.sort();
+        keys.dedup();
+        if keys.len() != count || count == 0 || count > ReactionSet::MAX_ELEMENTS {
+            return None;
+        }
+
+        Some(ReactionSet { enabled, custom })
+    }
+
+    pub fn allows(&self, key: &ReactionKey) -> bool {
+        self.enabled.contains(key) || self.custom.iter().any(|definition| definition.key == *key)
+    }
+
+    pub fn definitions(&self) -> Vec<ReactionDefinition> {
+        catalog()
+            .into_iter()
+            .filter(|definition| self.enabled.contains(&definition.key))
+            .chain(self.custom.iter().cloned())
+            .collect()
+    }
+
+    pub fn is_custom(&self, key: &ReactionKey) -> bool {
+        self.custom.iter().any(|definition| definition.key == *key)
+    }
+}
@@ -0,0 +1,39 @@
+use std::fmt::{Display, Formatter};
+use chumsky::prelude::{end, filter, Parser, Simple};
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, PartialEq, Eq, Clone)]
+pub struct ReactionEmoji {
+    raw: String,
+}
+
+#[derive(Debug)]
+pub enum ParseError {
+    MalformedInput,
+}
+
+impl ReactionEmoji {
+    fn parser() -> impl Parser<char, ReactionEmoji, Error = Simple<char>> {
+        // emoji should only contain visible characters, without whitespace
+        // length between 1 and 8 characters (to allow for modifiers & joiners)
+        filter(|c: &char| !c.is_whitespace() && !c.is_control())
+            .repeated()
+            .at_least(1)
+            .at_most(8)
+            .collect::<String>()
+            .map(|emoji| ReactionEmoji { raw: emoji })
+            .then_ignore(end())
+    }
+
+    pub fn parse(input: &str) -> Result<ReactionEmoji, ParseError> {
+        ReactionEmoji::parser()
+            .parse(input)
+            .map_err(|_| ParseError::MalformedInput)
+    }
+}
+
+impl Display for ReactionEmoji {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.raw)
+    }
+}
@@ -0,0 +1,46 @@
+use std::fmt::{Display, Formatter};
+use chumsky::prelude::{end, filter, Parser, Simple};
+use serde::{Deserialize, Serialize};
+use std::hash::{Hash, Hasher};
+
+#[derive(Serialize, Deserialize, PartialEq, Eq, Clone, PartialOrd, Ord)]
+pub struct ReactionKey {
+    raw: String,
+}
+
+#[derive(Debug)]
+pub enum ParseError {
+    MalformedInput,
+}
+
+impl ReactionKey {
+    fn parser() -> impl Parser<char, ReactionKey, Error = Simple<char>> {
+        // key is a slug (e.g. "red-card"), consisting of lowercase ascii characters, digits and dashes
+        // length between 2 and 32 characters
+        filter(|c: &char| c.is_ascii_lowercase() || c.is_ascii_digit() || *c == '-')
+            .repeated()
+            .at_least(2)
+            .at_most(32)
+            .collect::<String>()
+            .map(|key| ReactionKey { raw: key })
+            .then_ignore(end())
+    }
+
+    pub fn parse(input: &str) -> Result<ReactionKey, ParseError> {
+        ReactionKey::parser()
+            .parse(input)
+            .map_err(|_| ParseError::MalformedInput)
+    }
+}
+
+impl Display for ReactionKey {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.raw)
+    }
+}
+
+impl Hash for ReactionKey {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.raw.hash(state)
+    }
+}
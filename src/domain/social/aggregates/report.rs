@@ -0,0 +1,176 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::domain::social::aggregates::{CommentId, CommunityId, PostId, ReportId, ReportText};
+use crate::domain::account::aggregates::UserId;
+
+#[derive(Serialize, Deserialize)]
+pub struct Report {
+    pub id: ReportId,
+    pub target: ReportTarget,
+    pub community: CommunityId,
+    pub reporter: Option<UserId>, // absent when flagged by the content filter
+    pub category: ReportCategory,
+    pub text: ReportText,
+    pub reported: DateTime<Utc>,
+    pub status: ReportStatus,
+    pub audit: Vec<ReportAuditEntry>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Eq, Clone)]
+pub enum ReportTarget {
+    Post(PostId),
+    Comment(CommentId),
+}
+
+impl ReportTarget {
+    pub fn values(&self) -> (&str, String) {
+        match self {
+            ReportTarget::Post(id) => ("post", id.to_string()),
+            ReportTarget::Comment(id) => ("comment", id.to_string()),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Eq, Clone, Copy)]
+pub enum ReportCategory {
+    Spam,
+    Harassment,
+    HateSpeech,
+    Violence,
+    Misinformation,
+    Other,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Eq, Clone, Copy)]
+pub enum ReportStatus {
+    Open,
+    Dismissed,
+    ContentRemoved,
+    AuthorBanned,
+}
+
+impl ReportStatus {
+    pub fn as_str(&self) -> &str {
+        match self {
+            ReportStatus::Open => "open",
+            ReportStatus::Dismissed => "dismissed",
+            ReportStatus::ContentRemoved => "content_removed",
+            ReportStatus::AuthorBanned => "author_banned",
+        }
+    }
+}
+
+// note: audit trail of everything that happened to a report, actor is absent for automated actions
+#[derive(Serialize, Deserialize, Clone)]
+pub enum ReportAction {
+    Filed,
+    ContentHidden,
+    Dismissed,
+    ContentRemoved,
+    AuthorBanned,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct ReportAuditEntry {
+    pub action: ReportAction,
+    pub actor: Option<UserId>,
+    pub at: DateTime<Utc>,
+}
+
+impl Report {
+    pub fn new(
+        id: ReportId,
+        target: ReportTarget,
+        community: CommunityId,
+        reporter: UserId,
+        category: ReportCategory,
+        text: ReportText,
+        reported: DateTime<Utc>,
+    ) -> Report {
+        let filed = ReportAuditEntry {
+            action: ReportAction::Filed,
+            actor: Some(reporter.clone()),
+            at: reported,
+        };
+
+        Report {
+            id,
+            target,
+            community,
+            reporter: Some(reporter),
+            category,
+            text,
+            reported,
+            status: ReportStatus::Open,
+            audit: vec![filed],
+        }
+    }
+
+    pub fn flagged(
+        id: ReportId,
+        target: ReportTarget,
+        community: CommunityId,
+        category: ReportCategory,
+        text: ReportText,
+        reported: DateTime<Utc>,
+    ) -> Report {
+        let filed = ReportAuditEntry {
+            action: ReportAction::Filed,
+            actor: None,
+            at: reported,
+        };
+
+        Report {
+            id,
+            target,
+            community,
+            reporter: None,
+            category,
+            text,
+            reported,
+            status: ReportStatus::Open,
+            audit: vec![filed],
+        }
+    }
+
+    pub fn is_open(&self) -> bool {
+        self.status == ReportStatus::Open
+    }
+
+    pub fn record_content_hidden(&mut self, at: DateTime<Utc>) {
+        self.audit.push(ReportAuditEntry { action: ReportAction::ContentHidden, actor: None, at });
+    }
+
+    pub fn dismiss(&mut self, moderator: &UserId, at: DateTime<Utc>) -> bool {
+        self.resolve(ReportStatus::Dismissed, ReportAction::Dismissed, moderator, at)
+    }
+
+    pub fn resolve_content_removed(&mut self, moderator: &UserId, at: DateTime<Utc>) -> bool {
+        self.resolve(ReportStatus::ContentRemoved, ReportAction::ContentRemoved, moderator, at)
+    }
+
+    pub fn resolve_author_banned(&mut self, moderator: &UserId, at: DateTime<Utc>) -> bool {
+        self.resolve(ReportStatus::AuthorBanned, ReportAction::AuthorBanned, moderator, at)
+    }
+
+    fn resolve(&mut self, status: ReportStatus, action: ReportAction, moderator: &UserId, at: DateTime<Utc>) -> bool {
+        // rule: a report can only be resolved once
+        if !self.is_open() {
+            return false
+        }
+
+        self.status = status;
+        self.audit.push(ReportAuditEntry { action, actor: Some(moderator.clone()), at });
+
+        true
+    }
+}
+
+impl PartialEq for Report {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id
+    }
+}
+
+impl Eq for Report {}
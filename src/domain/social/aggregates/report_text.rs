@@ -0,0 +1,39 @@
+use std::fmt::{Display, Formatter};
+use chumsky::prelude::{filter, Parser, Simple};
+use chumsky::text::TextParser;
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Clone, Default)]
+pub struct ReportText {
+    raw: String,
+}
+
+#[derive(Debug)]
+pub enum ParseError {
+    MalformedInput,
+}
+
+impl ReportText {
+    fn parser() -> impl Parser<char, ReportText, Error = Simple<char>> {
+        // text shoud only contain visible (non-control) characters
+        // length at most 1000 characters, may be empty (category says it all)
+        filter(|c: &char| !c.is_ascii_control())
+            .repeated()
+            .at_most(1000)
+            .padded()
+            .collect::<String>()
+            .map(|text| ReportText { raw: text })
+    }
+
+    pub fn parse(input: &str) -> Result<ReportText, ParseError> {
+        ReportText::parser()
+            .parse(input)
+            .map_err(|_| ParseError::MalformedInput)
+    }
+}
+
+impl Display for ReportText {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.raw)
+    }
+}
@@ -0,0 +1,84 @@
+use crate::domain::social::aggregates::{CommentId, CommunityId, PostId};
+
+// note: search hits & friends are transient; meaning they should be derived and not persisted
+#[derive(PartialEq, Eq, Hash, Clone, Copy)]
+pub enum SearchKind {
+    Community,
+    Post,
+    Comment,
+}
+
+#[derive(PartialEq, Eq, Clone)]
+pub enum SearchTarget {
+    Community(CommunityId),
+    Post(PostId),
+    Comment(CommentId),
+}
+
+impl SearchTarget {
+    pub fn kind(&self) -> SearchKind {
+        match self {
+            SearchTarget::Community(_) => SearchKind::Community,
+            SearchTarget::Post(_) => SearchKind::Post,
+            SearchTarget::Comment(_) => SearchKind::Comment,
+        }
+    }
+
+    pub fn values(&self) -> (&str, String) {
+        match self {
+            SearchTarget::Community(id) => ("community", id.to_string()),
+            SearchTarget::Post(id) => ("post", id.to_string()),
+            SearchTarget::Comment(id) => ("comment", id.to_string()),
+        }
+    }
+}
+
+pub struct SearchHit {
+    pub target: SearchTarget,
+    pub community: CommunityId,
+    pub post: Option<PostId>, // the post a comment replies to, or the post itself
+    pub rank: f32,
+    pub highlight: Highlight,
+}
+
+impl SearchHit {
+    pub const MAX_ELEMENTS: usize = 25;
+}
+
+// note: ranges are character offsets into the snippet, like text entities
+pub struct Highlight {
+    pub snippet: String,
+    pub ranges: Vec<(usize, usize)>, // (offset, length)
+}
+
+impl Highlight {
+    // note: snippets mark matches with these (control) characters, which never occur in content
+    pub const START: char = '\u{2}';
+    pub const STOP: char = '\u{3}';
+
+    pub fn from_marked(marked: &str) -> Highlight {
+        let mut snippet = String::new();
+        let mut ranges = Vec::new();
+        let mut offset = 0;
+        let mut start = None;
+
+        for c in marked.chars() {
+            match c {
+                Highlight::START => start = Some(offset),
+                Highlight::STOP => {
+                    if let Some(start) = start.take() {
+                        if offset > start {
+                            ranges.push((start, offset - start));
+                        }
+                    }
+                },
+                _ => {
+                    snippet.push(c);
+                    offset += 1;
+                },
+            }
+        }
+
+        Highlight { snippet, ranges }
+    }
+}
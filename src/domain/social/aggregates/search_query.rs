@@ -0,0 +1,48 @@
+use std::fmt::{Display, Formatter};
+use chumsky::prelude::{end, filter, Parser, Simple};
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, PartialEq, Eq, Clone)]
+pub struct SearchQuery {
+    raw: String,
+}
+
+#[derive(Debug)]
+pub enum ParseError {
+    MalformedInput,
+}
+
+impl SearchQuery {
+    fn parser() -> impl Parser<char, SearchQuery, Error = Simple<char>> {
+        // query shoud only contain visible (non-control) characters
+        // length between 2 en 200 characters (surrounding whitespace is trimmed)
+        filter(|c: &char| !c.is_control())
+            .repeated()
+            .at_least(2)
+            .at_most(200)
+            .collect::<String>()
+            .map(|query| SearchQuery { raw: query })
+            .then_ignore(end())
+    }
+
+    pub fn parse(input: &str) -> Result<SearchQuery, ParseError> {
+        SearchQuery::parser()
+            .parse(input.trim())
+            .map_err(|_| ParseError::MalformedInput)
+    }
+
+    // note: terms are matched case insensitive, punctuation separates terms
+    pub fn terms(&self) -> Vec<String> {
+        self.raw
+            .split(|c: char| !c.is_alphanumeric())
+            .filter(|term| !term.is_empty())
+            .map(|term| term.to_lowercase())
+            .collect()
+    }
+}
+
+impl Display for SearchQuery {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.raw)
+    }
+}
@@ -0,0 +1,99 @@
+use std::ops::Range;
+use chumsky::prelude::{any, choice, end, filter, just, Parser, Simple};
+use itertools::Itertools;
+use serde::{Deserialize, Serialize};
+
+use crate::domain::account::aggregates::UserId;
+use crate::domain::social::aggregates::Hashtag;
+
+#[derive(Serialize, Deserialize, Clone)]
+pub enum TextEntityKind {
+    Hashtag(Hashtag),
+    Mention(UserId),
+}
+
+// note: entity marks a #hashtag or @mention within a text; offset & length are counted in characters
+#[derive(Serialize, Deserialize, Clone)]
+pub struct TextEntity {
+    pub kind: TextEntityKind,
+    pub offset: usize,
+    pub length: usize,
+}
+
+type Candidate = Option<(TextEntityKind, Range<usize>)>;
+
+impl TextEntity {
+    fn parser() -> impl Parser<char, Vec<Candidate>, Error = Simple<char>> {
+        // hashtag: # followed by 1 to 64 letters, digits or underscores
+        let hashtag = just('#')
+            .ignore_then(filter(|c: &char| Hashtag::is_tag_char(c))
+                .repeated()
+                .at_least(1)
+                .at_most(Hashtag::MAX_LENGTH)
+                .collect::<String>())
+            .try_map(|tag, span: Range<usize>| match Hashtag::parse(tag.as_str()) {
+                Ok(tag) => Ok((TextEntityKind::Hashtag(tag), span)),
+                Err(_) => Err(Simple::custom(span, "malformed hashtag")),
+            });
+
+        // mention: @ followed by an account id
+        let mention = just('@')
+            .ignore_then(filter(|c: &char| c.is_ascii_alphanumeric())
+                .repeated()
+                .at_least(20)
+                .at_most(24)
+                .collect::<String>())
+            .try_map(|id, span: Range<usize>| match UserId::parse(id.as_str()) {
+                Ok(user) => Ok((TextEntityKind::Mention(user), span)),
+                Err(_) => Err(Simple::custom(span, "malformed mention")),
+            });
+
+        // anything else is plain text
+        choice((hashtag, mention))
+            .map(Some)
+            .or(any().to(None))
+            .repeated()
+            .then_ignore(end())
+    }
+
+    pub fn extract(text: &str) -> Vec<TextEntity> {
+        let chars: Vec<char> = text.chars().collect();
+        let is_word_char = |index: usize| chars.get(index).filter(|c| Hashtag::is_tag_char(c)).is_some();
+
+        TextEntity::parser()
+            .parse(text)
+            .unwrap_or_default()
+            .into_iter()
+            .flatten()
+            // rule: entities stand on their own, e.g. no e-mail addresses or truncated tags
+            .filter(|(_, span)| (span.start == 0 || !is_word_char(span.start - 1)) && !is_word_char(span.end))
+            .map(|(kind, span)| TextEntity {
+                kind,
+                offset: span.start,
+                length: span.end - span.start,
+            })
+            .collect()
+    }
+
+    pub fn hashtags(entities: &[TextEntity]) -> Vec<Hashtag> {
+        entities
+            .iter()
+            .filter_map(|entity| match &entity.kind {
+                TextEntityKind::Hashtag(tag) => Some(tag.clone()),
+                _ => None,
+            })
+            .unique()
+            .collect()
+    }
+
+    pub fn mentions(entities: &[TextEntity]) -> Vec<UserId> {
+        entities
+            .iter()
+            .filter_map(|entity| match &entity.kind {
+                TextEntityKind::Mention(user) => Some(user.clone()),
+                _ => None,
+            })
+            .unique()
+            .collect()
+    }
+}
@@ -0,0 +1,20 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::domain::account::aggregates::UserId;
+use crate::domain::social::aggregates::ModerationReason;
+
+// note: tombstone replaces removed content until the retention period purges it
+#[derive(Serialize, Deserialize, Clone)]
+pub struct Tombstone {
+    pub removed_by: UserId,
+    pub role: RemoverRole,
+    pub reason: Option<ModerationReason>,
+    pub removed: DateTime<Utc>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum RemoverRole {
+    Author,
+    Moderator,
+}
@@ -0,0 +1,10 @@
+use crate::domain::social::aggregates::ReactionKey;
+
+// note: viewer state is transient; it describes a listing from the perspective of the requesting user
+pub struct ViewerState {
+    pub reaction: Option<ReactionKey>,
+    pub author: bool,
+    pub can_edit: bool,
+    pub can_remove: bool,
+    pub bookmarked: bool,
+}
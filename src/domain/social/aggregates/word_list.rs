@@ -0,0 +1,44 @@
+use itertools::Itertools;
+use serde::{Deserialize, Serialize};
+use std::slice::Iter;
+
+use crate::domain::social::aggregates::{CommunityId, Language};
+
+// note: word lists are configured per community and language, matched case-insensitive on whole words or phrases
+#[derive(Serialize, Deserialize)]
+pub struct WordList {
+    pub community: CommunityId,
+    pub language: Language,
+    pub action: WordListAction,
+    words: Vec<String>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Eq, Clone, Copy)]
+pub enum WordListAction {
+    Flag,
+    Reject,
+}
+
+impl WordList {
+    pub const MAX_ELEMENTS: usize = 1000;
+
+    pub fn new(community: CommunityId, language: Language, action: WordListAction, words: Vec<String>) -> WordList {
+        // this silently drops empty, duplicate and > MAX_ELEMENTS words
+        WordList {
+            community,
+            language,
+            action,
+            words: words
+                .into_iter()
+                .map(|word| word.trim().to_lowercase())
+                .filter(|word| !word.is_empty())
+                .unique()
+                .take(WordList::MAX_ELEMENTS)
+                .collect(),
+        }
+    }
+
+    pub fn iter(&self) -> Iter<'_, String> {
+        self.words.iter()
+    }
+}
@@ -0,0 +1,11 @@
+use crate::domain::account::aggregates::UserId;
+
+pub struct BlockUser {
+    pub blocker: UserId,
+    pub user: UserId,
+}
+
+pub struct UnblockUser {
+    pub blocker: UserId,
+    pub user: UserId,
+}
@@ -0,0 +1,12 @@
+use crate::domain::social::aggregates::BookmarkTarget;
+use crate::domain::account::aggregates::UserId;
+
+pub struct AddBookmark {
+    pub target: BookmarkTarget,
+    pub owner: UserId,
+}
+
+pub struct RemoveBookmark {
+    pub target: BookmarkTarget,
+    pub owner: UserId,
+}
@@ -1,8 +1,8 @@
-use crate::domain::social::aggregates::{CommentId, CommentText, PostId};
+use crate::domain::social::aggregates::{CommentId, CommentParent, CommentText, ModerationReason};
 use crate::domain::account::aggregates::UserId;
 
 pub struct PublishComment {
-    pub reply_to: PostId,
+    pub parent: CommentParent,
     pub text: CommentText,
     pub author: UserId,
 }
@@ -12,5 +12,13 @@ pub struct PublishCommentResult {
 }
 
 pub struct RemoveComment {
-    pub comment: CommentId
-}
\ No newline at end of file
+    pub comment: CommentId,
+    pub reason: Option<ModerationReason>,
+    pub remover: UserId,
+}
+
+pub struct EditComment {
+    pub comment: CommentId,
+    pub text: CommentText,
+    pub editor: UserId,
+}
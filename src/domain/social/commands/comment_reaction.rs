@@ -0,0 +1,9 @@
+use crate::domain::social::aggregates::{CommentReaction};
+
+pub struct ReactToComment {
+    pub reaction: CommentReaction,
+}
+
+pub struct RetractCommentReaction {
+    pub reaction: CommentReaction,
+}
\ No newline at end of file
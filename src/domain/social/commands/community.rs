@@ -1,5 +1,6 @@
+use chrono::{DateTime, Utc};
 use crate::domain::media::aggregates::ImageId;
-use crate::domain::social::aggregates::{CommunityContext, CommunityId, CommunityName};
+use crate::domain::social::aggregates::{CommunityContext, CommunityId, CommunityName, ModerationReason, ReactionDefinition, ReactionKey};
 use crate::domain::account::aggregates::UserId;
 
 pub struct New {
@@ -31,7 +32,46 @@ pub struct Join {
     pub person: UserId,
 }
 
+pub struct DeclineOffer {
+    pub community: CommunityId,
+    pub person: UserId,
+}
+
 pub struct Leave {
     pub community: CommunityId,
     pub member: UserId,
 }
+
+pub struct RemoveMember {
+    pub community: CommunityId,
+    pub member: UserId,
+    pub moderator: UserId,
+}
+
+pub struct BanUser {
+    pub community: CommunityId,
+    pub user: UserId,
+    pub reason: ModerationReason,
+    pub expires: Option<DateTime<Utc>>,
+    pub moderator: UserId,
+}
+
+pub struct UnbanUser {
+    pub community: CommunityId,
+    pub user: UserId,
+    pub moderator: UserId,
+}
+
+pub struct MuteMember {
+    pub community: CommunityId,
+    pub member: UserId,
+    pub until: DateTime<Utc>,
+    pub moderator: UserId,
+}
+
+pub struct SetReactions {
+    pub community: CommunityId,
+    pub enabled: Vec<ReactionKey>,
+    pub custom: Vec<ReactionDefinition>,
+    pub editor: UserId,
+}
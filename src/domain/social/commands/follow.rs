@@ -0,0 +1,11 @@
+use crate::domain::account::aggregates::UserId;
+
+pub struct FollowUser {
+    pub follower: UserId,
+    pub followee: UserId,
+}
+
+pub struct UnfollowUser {
+    pub follower: UserId,
+    pub followee: UserId,
+}
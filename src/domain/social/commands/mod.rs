@@ -1,5 +1,12 @@
+pub mod block;
+pub mod bookmark;
+pub mod follow;
 pub mod community;
+pub mod poll;
 pub mod post;
 pub mod comment;
 pub mod post_reaction;
+pub mod comment_reaction;
+pub mod report;
+pub mod word_list;
 
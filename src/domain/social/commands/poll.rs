@@ -0,0 +1,8 @@
+use crate::domain::social::aggregates::PostId;
+use crate::domain::account::aggregates::UserId;
+
+pub struct VoteInPoll {
+    pub post: PostId,
+    pub choices: Vec<u32>,
+    pub voter: UserId,
+}
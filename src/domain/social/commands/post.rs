@@ -1,4 +1,5 @@
-use crate::domain::social::aggregates::{CommunityId, PostAttachments, PostId, PostText};
+use chrono::{DateTime, Utc};
+use crate::domain::social::aggregates::{CommunityId, ModerationReason, PostAttachments, PostId, PostText};
 use crate::domain::account::aggregates::UserId;
 
 pub struct PublishPost {
@@ -6,6 +7,8 @@ pub struct PublishPost {
     pub text: PostText,
     pub attachments: PostAttachments,
     pub author: UserId,
+    pub announcement: bool,
+    pub scheduled: Option<DateTime<Utc>>, // publishes immediately when absent
 }
 
 pub struct PublishPostResult {
@@ -13,5 +16,48 @@ pub struct PublishPostResult {
 }
 
 pub struct RemovePost {
-    pub post: PostId
-}
\ No newline at end of file
+    pub post: PostId,
+    pub reason: Option<ModerationReason>,
+    pub remover: UserId,
+}
+
+pub struct EditPost {
+    pub post: PostId,
+    pub text: PostText,
+    pub editor: UserId,
+}
+
+pub struct PinPost {
+    pub post: PostId,
+    pub editor: UserId,
+}
+
+pub struct UnpinPost {
+    pub post: PostId,
+    pub editor: UserId,
+}
+
+pub struct SaveDraft {
+    pub community: CommunityId,
+    pub text: PostText,
+    pub attachments: PostAttachments,
+    pub author: UserId,
+}
+
+pub struct SaveDraftResult {
+    pub id: PostId
+}
+
+pub struct EditDraft {
+    pub post: PostId,
+    pub text: PostText,
+    pub attachments: PostAttachments,
+    pub author: UserId,
+}
+
+pub struct PublishDraft {
+    pub post: PostId,
+    pub announcement: bool,
+    pub scheduled: Option<DateTime<Utc>>, // publishes immediately when absent
+    pub author: UserId,
+}
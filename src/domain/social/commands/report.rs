@@ -0,0 +1,39 @@
+use chrono::{DateTime, Utc};
+use crate::domain::social::aggregates::{CommentId, ModerationReason, PostId, ReportCategory, ReportId, ReportText};
+use crate::domain::account::aggregates::UserId;
+
+pub struct ReportPost {
+    pub post: PostId,
+    pub reporter: UserId,
+    pub category: ReportCategory,
+    pub text: ReportText,
+}
+
+pub struct ReportComment {
+    pub comment: CommentId,
+    pub reporter: UserId,
+    pub category: ReportCategory,
+    pub text: ReportText,
+}
+
+pub struct ReportResult {
+    pub id: ReportId
+}
+
+pub struct DismissReport {
+    pub report: ReportId,
+    pub moderator: UserId,
+}
+
+pub struct RemoveReportedContent {
+    pub report: ReportId,
+    pub reason: Option<ModerationReason>,
+    pub moderator: UserId,
+}
+
+pub struct BanReportedAuthor {
+    pub report: ReportId,
+    pub reason: ModerationReason,
+    pub expires: Option<DateTime<Utc>>,
+    pub moderator: UserId,
+}
@@ -0,0 +1,10 @@
+use crate::domain::social::aggregates::{CommunityId, Language, WordListAction};
+use crate::domain::account::aggregates::UserId;
+
+pub struct SetWordList {
+    pub community: CommunityId,
+    pub language: Language,
+    pub action: WordListAction,
+    pub words: Vec<String>,
+    pub editor: UserId,
+}
@@ -2,7 +2,7 @@ use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use crate::common::Event;
 use crate::domain::media::aggregates::ImageId;
-use crate::domain::social::aggregates::{CommentId, CommentText, CommunityContext, CommunityId, CommunityName, PostAttachments, PostId, PostReaction, PostText};
+use crate::domain::social::aggregates::{BookmarkTarget, CommentId, CommentParent, CommentReaction, CommentText, CommunityContext, CommunityId, CommunityName, Language, ModerationReason, PostAttachments, PostId, PostReaction, PostText, ReactionSet, ReportCategory, ReportId, ReportTarget, ReportText, Tombstone, WordListAction};
 use crate::domain::account::aggregates::UserId;
 
 #[derive(Serialize, Deserialize)]
@@ -79,6 +79,111 @@ impl Event for LeftV1 {
     }
 }
 
+#[derive(Serialize, Deserialize)]
+pub struct MembershipOfferedV1 {
+    pub community: CommunityId,
+    pub person: UserId,
+}
+
+impl Event for MembershipOfferedV1 {
+    fn kind(&self) -> &'static str {
+        "MembershipOfferedV1"
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct MembershipOfferWithdrawnV1 {
+    pub community: CommunityId,
+    pub person: UserId,
+}
+
+impl Event for MembershipOfferWithdrawnV1 {
+    fn kind(&self) -> &'static str {
+        "MembershipOfferWithdrawnV1"
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct MembershipOfferDeclinedV1 {
+    pub community: CommunityId,
+    pub person: UserId,
+}
+
+impl Event for MembershipOfferDeclinedV1 {
+    fn kind(&self) -> &'static str {
+        "MembershipOfferDeclinedV1"
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct CommunityReactionsSetV1 {
+    pub community: CommunityId,
+    pub reactions: ReactionSet,
+    pub editor: UserId,
+}
+
+impl Event for CommunityReactionsSetV1 {
+    fn kind(&self) -> &'static str {
+        "CommunityReactionsSetV1"
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct MemberRemovedV1 {
+    pub community:  CommunityId,
+    pub member: UserId,
+    pub moderator: UserId,
+}
+
+impl Event for MemberRemovedV1 {
+    fn kind(&self) -> &'static str {
+        "MemberRemovedV1"
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct UserBannedV1 {
+    pub community:  CommunityId,
+    pub user: UserId,
+    pub reason: ModerationReason,
+    pub banned: DateTime<Utc>,
+    pub expires: Option<DateTime<Utc>>,
+    pub moderator: UserId,
+}
+
+impl Event for UserBannedV1 {
+    fn kind(&self) -> &'static str {
+        "UserBannedV1"
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct UserUnbannedV1 {
+    pub community:  CommunityId,
+    pub user: UserId,
+    pub moderator: UserId,
+}
+
+impl Event for UserUnbannedV1 {
+    fn kind(&self) -> &'static str {
+        "UserUnbannedV1"
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct MemberMutedV1 {
+    pub community:  CommunityId,
+    pub member: UserId,
+    pub until: DateTime<Utc>,
+    pub moderator: UserId,
+}
+
+impl Event for MemberMutedV1 {
+    fn kind(&self) -> &'static str {
+        "MemberMutedV1"
+    }
+}
+
 #[derive(Serialize, Deserialize)]
 pub struct PostPublishedV1 {
     pub id: PostId,
@@ -95,6 +200,34 @@ impl Event for PostPublishedV1 {
     }
 }
 
+// note: scheduled posts are announced by PostPublishedV1 once they actually go live
+#[derive(Serialize, Deserialize)]
+pub struct PostScheduledV1 {
+    pub id: PostId,
+    pub community: CommunityId,
+    pub author: UserId,
+    pub scheduled: DateTime<Utc>,
+}
+
+impl Event for PostScheduledV1 {
+    fn kind(&self) -> &'static str {
+        "PostScheduledV1"
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct PostEditedV1 {
+    pub id: PostId,
+    pub text: PostText,
+    pub edited: DateTime<Utc>,
+}
+
+impl Event for PostEditedV1 {
+    fn kind(&self) -> &'static str {
+        "PostEditedV1"
+    }
+}
+
 #[derive(Serialize, Deserialize)]
 pub struct PostRemovedV1 {
     pub id: PostId,
@@ -106,6 +239,98 @@ impl Event for PostRemovedV1 {
     }
 }
 
+#[derive(Serialize, Deserialize)]
+pub struct PostRemovedV2 {
+    pub id: PostId,
+    pub tombstone: Tombstone,
+}
+
+impl Event for PostRemovedV2 {
+    fn kind(&self) -> &'static str {
+        "PostRemovedV2"
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct PostPinnedV1 {
+    pub id: PostId,
+    pub community: CommunityId,
+    pub editor: UserId,
+    pub pinned: DateTime<Utc>,
+}
+
+impl Event for PostPinnedV1 {
+    fn kind(&self) -> &'static str {
+        "PostPinnedV1"
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct PostUnpinnedV1 {
+    pub id: PostId,
+    pub community: CommunityId,
+    pub editor: UserId,
+}
+
+impl Event for PostUnpinnedV1 {
+    fn kind(&self) -> &'static str {
+        "PostUnpinnedV1"
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct PollVotedV1 {
+    pub post: PostId,
+    pub voter: Option<UserId>, // absent for anonymous polls
+    pub choices: Vec<u32>,
+    pub voted: DateTime<Utc>,
+}
+
+impl Event for PollVotedV1 {
+    fn kind(&self) -> &'static str {
+        "PollVotedV1"
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct PollClosedV1 {
+    pub post: PostId,
+}
+
+impl Event for PollClosedV1 {
+    fn kind(&self) -> &'static str {
+        "PollClosedV1"
+    }
+}
+
+// note: comment is absent when mentioned in the post itself
+#[derive(Serialize, Deserialize)]
+pub struct UserMentionedV1 {
+    pub user: UserId,
+    pub author: UserId,
+    pub community: CommunityId,
+    pub post: PostId,
+    pub comment: Option<CommentId>,
+    pub mentioned: DateTime<Utc>,
+}
+
+impl Event for UserMentionedV1 {
+    fn kind(&self) -> &'static str {
+        "UserMentionedV1"
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct PostPurgedV1 {
+    pub id: PostId,
+}
+
+impl Event for PostPurgedV1 {
+    fn kind(&self) -> &'static str {
+        "PostPurgedV1"
+    }
+}
+
 #[derive(Serialize, Deserialize)]
 pub struct CommentPublishedV1 {
     pub id: CommentId,
@@ -121,6 +346,35 @@ impl Event for CommentPublishedV1 {
     }
 }
 
+#[derive(Serialize, Deserialize)]
+pub struct CommentPublishedV2 {
+    pub id: CommentId,
+    pub reply_to: PostId,
+    pub parent: CommentParent,
+    pub text: CommentText,
+    pub author: UserId,
+    pub published: DateTime<Utc>,
+}
+
+impl Event for CommentPublishedV2 {
+    fn kind(&self) -> &'static str {
+        "CommentPublishedV2"
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct CommentEditedV1 {
+    pub id: CommentId,
+    pub text: CommentText,
+    pub edited: DateTime<Utc>,
+}
+
+impl Event for CommentEditedV1 {
+    fn kind(&self) -> &'static str {
+        "CommentEditedV1"
+    }
+}
+
 #[derive(Serialize, Deserialize)]
 pub struct CommentRemovedV1 {
     pub id: CommentId,
@@ -132,9 +386,54 @@ impl Event for CommentRemovedV1 {
     }
 }
 
+#[derive(Serialize, Deserialize)]
+pub struct CommentRemovedV2 {
+    pub id: CommentId,
+    pub tombstone: Tombstone,
+}
+
+impl Event for CommentRemovedV2 {
+    fn kind(&self) -> &'static str {
+        "CommentRemovedV2"
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct CommentPurgedV1 {
+    pub id: CommentId,
+}
+
+impl Event for CommentPurgedV1 {
+    fn kind(&self) -> &'static str {
+        "CommentPurgedV1"
+    }
+}
+
+// note: v1 is no longer published, but kept to read the reactions published before v2
+#[derive(Serialize, Deserialize)]
+pub enum PostReactionV1 {
+    Love(UserId, PostId),
+    Funny(UserId, PostId),
+    Celebrate(UserId, PostId),
+    Support(UserId, PostId),
+    Insightful(UserId, PostId),
+}
+
+impl PostReactionV1 {
+    pub fn values(&self) -> (&UserId, &PostId) {
+        match self {
+            PostReactionV1::Love(user, post) => (user, post),
+            PostReactionV1::Funny(user, post) => (user, post),
+            PostReactionV1::Celebrate(user, post) => (user, post),
+            PostReactionV1::Support(user, post) => (user, post),
+            PostReactionV1::Insightful(user, post) => (user, post),
+        }
+    }
+}
+
 #[derive(Serialize, Deserialize)]
 pub struct ReactedToPostV1 {
-    pub reaction: PostReaction,
+    pub reaction: PostReactionV1,
 }
 
 impl Event for ReactedToPostV1 {
@@ -143,13 +442,236 @@ impl Event for ReactedToPostV1 {
     }
 }
 
+// note: v2 identifies reactions by key, rather than by a fixed emotion
+#[derive(Serialize, Deserialize)]
+pub struct ReactedToPostV2 {
+    pub reaction: PostReaction,
+}
+
+impl Event for ReactedToPostV2 {
+    fn kind(&self) -> &'static str {
+        "ReactedToPostV2"
+    }
+}
+
 #[derive(Serialize, Deserialize)]
-pub struct PostReactionRetractedV1 {
+pub struct PostReactionRetractedV2 {
     pub reaction: PostReaction,
 }
 
-impl Event for PostReactionRetractedV1 {
+impl Event for PostReactionRetractedV2 {
     fn kind(&self) -> &'static str {
-        "PostReactionRetractedV1"
+        "PostReactionRetractedV2"
     }
-}
\ No newline at end of file
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct ReactedToCommentV2 {
+    pub reaction: CommentReaction,
+}
+
+impl Event for ReactedToCommentV2 {
+    fn kind(&self) -> &'static str {
+        "ReactedToCommentV2"
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct CommentReactionRetractedV2 {
+    pub reaction: CommentReaction,
+}
+
+impl Event for CommentReactionRetractedV2 {
+    fn kind(&self) -> &'static str {
+        "CommentReactionRetractedV2"
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct PostReportedV1 {
+    pub report: ReportId,
+    pub post: PostId,
+    pub community: CommunityId,
+    pub reporter: UserId,
+    pub category: ReportCategory,
+    pub text: ReportText,
+    pub reported: DateTime<Utc>,
+}
+
+impl Event for PostReportedV1 {
+    fn kind(&self) -> &'static str {
+        "PostReportedV1"
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct CommentReportedV1 {
+    pub report: ReportId,
+    pub comment: CommentId,
+    pub community: CommunityId,
+    pub reporter: UserId,
+    pub category: ReportCategory,
+    pub text: ReportText,
+    pub reported: DateTime<Utc>,
+}
+
+impl Event for CommentReportedV1 {
+    fn kind(&self) -> &'static str {
+        "CommentReportedV1"
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct PostHiddenV1 {
+    pub id: PostId,
+    pub reporters: u64,
+}
+
+impl Event for PostHiddenV1 {
+    fn kind(&self) -> &'static str {
+        "PostHiddenV1"
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct ReportDismissedV1 {
+    pub reports: Vec<ReportId>,
+    pub target: ReportTarget,
+    pub moderator: UserId,
+}
+
+impl Event for ReportDismissedV1 {
+    fn kind(&self) -> &'static str {
+        "ReportDismissedV1"
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct ReportedContentRemovedV1 {
+    pub reports: Vec<ReportId>,
+    pub target: ReportTarget,
+    pub moderator: UserId,
+}
+
+impl Event for ReportedContentRemovedV1 {
+    fn kind(&self) -> &'static str {
+        "ReportedContentRemovedV1"
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct ReportedAuthorBannedV1 {
+    pub reports: Vec<ReportId>,
+    pub target: ReportTarget,
+    pub author: UserId,
+    pub moderator: UserId,
+}
+
+impl Event for ReportedAuthorBannedV1 {
+    fn kind(&self) -> &'static str {
+        "ReportedAuthorBannedV1"
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct ContentFlaggedV1 {
+    pub report: ReportId,
+    pub target: ReportTarget,
+    pub community: CommunityId,
+    pub reason: String,
+}
+
+impl Event for ContentFlaggedV1 {
+    fn kind(&self) -> &'static str {
+        "ContentFlaggedV1"
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct WordListSetV1 {
+    pub community: CommunityId,
+    pub language: Language,
+    pub action: WordListAction,
+    pub words: Vec<String>,
+}
+
+impl Event for WordListSetV1 {
+    fn kind(&self) -> &'static str {
+        "WordListSetV1"
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct BookmarkAddedV1 {
+    pub owner: UserId,
+    pub target: BookmarkTarget,
+    pub bookmarked: DateTime<Utc>,
+}
+
+impl Event for BookmarkAddedV1 {
+    fn kind(&self) -> &'static str {
+        "BookmarkAddedV1"
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct BookmarkRemovedV1 {
+    pub owner: UserId,
+    pub target: BookmarkTarget,
+}
+
+impl Event for BookmarkRemovedV1 {
+    fn kind(&self) -> &'static str {
+        "BookmarkRemovedV1"
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct UserFollowedV1 {
+    pub follower: UserId,
+    pub followee: UserId,
+    pub followed: DateTime<Utc>,
+}
+
+impl Event for UserFollowedV1 {
+    fn kind(&self) -> &'static str {
+        "UserFollowedV1"
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct UserUnfollowedV1 {
+    pub follower: UserId,
+    pub followee: UserId,
+}
+
+impl Event for UserUnfollowedV1 {
+    fn kind(&self) -> &'static str {
+        "UserUnfollowedV1"
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct UserBlockedV1 {
+    pub blocker: UserId,
+    pub user: UserId,
+    pub blocked: DateTime<Utc>,
+}
+
+impl Event for UserBlockedV1 {
+    fn kind(&self) -> &'static str {
+        "UserBlockedV1"
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct UserUnblockedV1 {
+    pub blocker: UserId,
+    pub user: UserId,
+}
+
+impl Event for UserUnblockedV1 {
+    fn kind(&self) -> &'static str {
+        "UserUnblockedV1"
+    }
+}
@@ -0,0 +1,49 @@
+use std::fmt::{Display, Formatter};
+
+use crate::common::RepositoryResult;
+use crate::domain::social::aggregates::{CommentId, CommunityId, Language, PostId};
+use crate::domain::account::aggregates::UserId;
+
+// note: identifies the post or comment being checked, which may not be persisted yet
+pub enum FilterableContentKind {
+    Post(PostId),
+    Comment(CommentId),
+}
+
+pub struct FilterableContent {
+    pub community: CommunityId,
+    pub author: UserId,
+    pub kind: FilterableContentKind,
+    pub text: String,
+    pub language: Option<Language>, // unknown when the author has no locale set
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum ContentFilterReason {
+    BlockedWord(String),
+    RepeatedContent,
+    ExcessiveLinks(usize),
+}
+
+impl Display for ContentFilterReason {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ContentFilterReason::BlockedWord(word) => write!(f, "blocked word \"{}\"", word),
+            ContentFilterReason::RepeatedContent => write!(f, "repeated content"),
+            ContentFilterReason::ExcessiveLinks(count) => write!(f, "excessive links ({})", count),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum ContentFilterOutcome {
+    Allow,
+    Flag(ContentFilterReason),
+    Reject(ContentFilterReason),
+}
+
+// note: port, called by use-cases before user generated text is persisted
+#[tonic::async_trait]
+pub trait ContentFilter {
+    async fn check(&self, content: &FilterableContent) -> RepositoryResult<ContentFilterOutcome>;
+}
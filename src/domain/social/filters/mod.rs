@@ -0,0 +1,5 @@
+pub mod content_filter;
+pub mod rules_content_filter;
+
+pub use content_filter::{ContentFilter, ContentFilterOutcome, ContentFilterReason, FilterableContent, FilterableContentKind};
+pub use rules_content_filter::{RulesContentFilter, RulesEngine, RulesSettings};
@@ -0,0 +1,222 @@
+use chrono::{Duration, Utc};
+
+use crate::common::RepositoryResult;
+use crate::domain::social::aggregates::{Language, WordList, WordListAction};
+use crate::domain::social::filters::{ContentFilter, ContentFilterOutcome, ContentFilterReason, FilterableContent};
+use crate::domain::social::repositories::{ContentHistoryRepository, WordListRepository};
+
+pub struct RulesSettings {
+    pub max_links: usize,
+    pub max_repeats: usize,
+    pub repeat_window: Duration,
+}
+
+// note: pure (offline) rules, all input is supplied by the caller
+pub struct RulesEngine {
+    settings: RulesSettings,
+}
+
+impl RulesEngine {
+    pub fn build(settings: RulesSettings) -> RulesEngine {
+        RulesEngine {
+            settings
+        }
+    }
+
+    pub fn evaluate(&self, text: &str, language: Option<&Language>, word_lists: &[WordList], recent_texts: &[String]) -> ContentFilterOutcome {
+        let words = tokenize(text);
+        let mut flagged = None;
+
+        // rule: only the word lists of the content's language apply, all of them when it's unknown
+        let word_lists = word_lists.iter()
+            .filter(|list| match language {
+                Some(language) => &list.language == language,
+                None => true,
+            });
+
+        // rule: words on a reject list take precedence over words on a flag list
+        for list in word_lists {
+            if let Some(entry) = list.iter().find(|entry| contains_phrase(&words, &tokenize(entry))) {
+                let reason = ContentFilterReason::BlockedWord(entry.clone());
+                match list.action {
+                    WordListAction::Reject => return ContentFilterOutcome::Reject(reason),
+                    WordListAction::Flag => { flagged.get_or_insert(reason); },
+                }
+            }
+        }
+
+        // rule: posting the same text over and over again is spam
+        let normalized = words.join(" ");
+        let repeats = recent_texts.iter()
+            .filter(|recent| tokenize(recent).join(" ") == normalized)
+            .count();
+        if repeats >= self.settings.max_repeats {
+            return ContentFilterOutcome::Reject(ContentFilterReason::RepeatedContent);
+        }
+
+        // rule: lots of links is suspicious, but not necessarily spam
+        let links = count_links(text);
+        if links > self.settings.max_links {
+            flagged.get_or_insert(ContentFilterReason::ExcessiveLinks(links));
+        }
+
+        match flagged {
+            Some(reason) => ContentFilterOutcome::Flag(reason),
+            None => ContentFilterOutcome::Allow,
+        }
+    }
+
+    pub fn repeat_window(&self) -> Duration {
+        self.settings.repeat_window
+    }
+}
+
+fn tokenize(text: &str) -> Vec<String> {
+    text.split(|c: char| !c.is_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .map(|word| word.to_lowercase())
+        .collect()
+}
+
+// note: a phrase is a run of whole words, e.g. a word list entry with spaces or hyphens
+fn contains_phrase(words: &[String], phrase: &[String]) -> bool {
+    !phrase.is_empty() && words.windows(phrase.len()).any(|window| window == phrase)
+}
+
+fn count_links(text: &str) -> usize {
+    text.split_whitespace()
+        .map(|token| token.to_lowercase())
+        .filter(|token| token.starts_with("http://") || token.starts_with("https://") || token.starts_with("www."))
+        .count()
+}
+
+pub struct RulesContentFilter {
+    word_list_repository: Box<dyn WordListRepository + Send + Sync>,
+    content_history_repository: Box<dyn ContentHistoryRepository + Send + Sync>,
+    engine: RulesEngine,
+}
+
+impl RulesContentFilter {
+    pub fn build(
+        word_list_repository: Box<dyn WordListRepository + Send + Sync>,
+        content_history_repository: Box<dyn ContentHistoryRepository + Send + Sync>,
+        engine: RulesEngine) -> RulesContentFilter {
+        RulesContentFilter {
+            word_list_repository,
+            content_history_repository,
+            engine,
+        }
+    }
+}
+
+#[tonic::async_trait]
+impl ContentFilter for RulesContentFilter {
+    async fn check(&self, content: &FilterableContent) -> RepositoryResult<ContentFilterOutcome> {
+        let word_lists = self.word_list_repository.list(&content.community).await?;
+
+        let since = Utc::now() - self.engine.repeat_window();
+        let recent_texts = self.content_history_repository.recent_texts(&content.author, &since, &content.kind).await?;
+
+        Ok(self.engine.evaluate(&content.text, content.language.as_ref(), &word_lists, &recent_texts))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::social::aggregates::CommunityId;
+
+    fn engine() -> RulesEngine {
+        RulesEngine::build(RulesSettings {
+            max_links: 2,
+            max_repeats: 2,
+            repeat_window: Duration::hours(1),
+        })
+    }
+
+    fn word_list(language: &str, action: WordListAction, words: &[&str]) -> WordList {
+        let community = CommunityId::random();
+        let words = words.iter().map(|word| word.to_string()).collect();
+        WordList::new(community, Language::parse(language).unwrap(), action, words)
+    }
+
+    #[test]
+    fn allows_clean_text() {
+        let word_lists = [word_list("en", WordListAction::Reject, &["spam"])];
+        assert_eq!(engine().evaluate("What a game!", None, &word_lists, &[]), ContentFilterOutcome::Allow);
+    }
+
+    #[test]
+    fn rejects_blocked_words_before_flagging() {
+        let word_lists = [
+            word_list("en", WordListAction::Flag, &["ref"]),
+            word_list("en", WordListAction::Reject, &["Scam"]),
+        ];
+
+        let outcome = engine().evaluate("The REF is a scam.", None, &word_lists, &[]);
+        assert_eq!(outcome, ContentFilterOutcome::Reject(ContentFilterReason::BlockedWord(String::from("scam"))));
+
+        let outcome = engine().evaluate("The ref again", None, &word_lists, &[]);
+        assert_eq!(outcome, ContentFilterOutcome::Flag(ContentFilterReason::BlockedWord(String::from("ref"))));
+    }
+
+    #[test]
+    fn matches_whole_words_only() {
+        let word_lists = [word_list("en", WordListAction::Reject, &["ass"])];
+        assert_eq!(engine().evaluate("Great assist", None, &word_lists, &[]), ContentFilterOutcome::Allow);
+    }
+
+    #[test]
+    fn matches_phrases_of_whole_words() {
+        let word_lists = [word_list("en", WordListAction::Reject, &["fake news", "cheat-code"])];
+
+        assert_eq!(engine().evaluate("News about a fake", None, &word_lists, &[]), ContentFilterOutcome::Allow);
+        assert_eq!(
+            engine().evaluate("That's FAKE  news!", None, &word_lists, &[]),
+            ContentFilterOutcome::Reject(ContentFilterReason::BlockedWord(String::from("fake news"))));
+        assert_eq!(
+            engine().evaluate("Found a cheat code", None, &word_lists, &[]),
+            ContentFilterOutcome::Reject(ContentFilterReason::BlockedWord(String::from("cheat-code"))));
+    }
+
+    #[test]
+    fn applies_only_word_lists_of_the_language() {
+        let word_lists = [
+            word_list("en", WordListAction::Reject, &["gift"]),
+            word_list("de", WordListAction::Reject, &["mist"]),
+        ];
+        let english = Language::parse("en").unwrap();
+        let german = Language::parse("de").unwrap();
+
+        assert_eq!(engine().evaluate("Ein Gift", Some(&german), &word_lists, &[]), ContentFilterOutcome::Allow);
+        assert_eq!(engine().evaluate("Morning mist", Some(&english), &word_lists, &[]), ContentFilterOutcome::Allow);
+        assert_eq!(
+            engine().evaluate("So ein Mist", Some(&german), &word_lists, &[]),
+            ContentFilterOutcome::Reject(ContentFilterReason::BlockedWord(String::from("mist"))));
+        assert_eq!(
+            engine().evaluate("So ein Mist", None, &word_lists, &[]),
+            ContentFilterOutcome::Reject(ContentFilterReason::BlockedWord(String::from("mist"))));
+    }
+
+    #[test]
+    fn rejects_repeated_content() {
+        let once = [String::from("Buy tickets here!")];
+        assert_eq!(engine().evaluate("buy tickets here", None, &[], &once), ContentFilterOutcome::Allow);
+
+        let twice = [String::from("Buy tickets here!"), String::from("BUY  tickets, here")];
+        assert_eq!(
+            engine().evaluate("buy tickets here", None, &[], &twice),
+            ContentFilterOutcome::Reject(ContentFilterReason::RepeatedContent));
+    }
+
+    #[test]
+    fn flags_excessive_links() {
+        let two = "https://a.example http://b.example";
+        assert_eq!(engine().evaluate(two, None, &[], &[]), ContentFilterOutcome::Allow);
+
+        let three = "https://a.example http://b.example www.c.example";
+        assert_eq!(
+            engine().evaluate(three, None, &[], &[]),
+            ContentFilterOutcome::Flag(ContentFilterReason::ExcessiveLinks(3)));
+    }
+}
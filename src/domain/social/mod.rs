@@ -3,4 +3,5 @@ pub mod policies;
 pub mod repositories;
 pub mod commands;
 pub mod events;
+pub mod filters;
 pub mod usecases;
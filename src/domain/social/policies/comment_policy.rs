@@ -6,6 +6,7 @@ use crate::domain::account::aggregates::UserId;
 #[derive(Debug)]
 pub enum CommentPolicyViolation {
     InsufficientPermissions,
+    Muted,
 }
 
 type CommentPolicyResult = Result<(), CommentPolicyViolation>;
@@ -14,6 +15,8 @@ pub struct CommentPolicyExecutionContext {
     pub user: UserId,
     pub community: CommunityId,
     pub post: PostId,
+    pub editor: bool,
+    pub muted: bool,
 }
 
 pub trait CommentPolicy {
@@ -25,4 +28,53 @@ pub trait CommentPolicy {
         context: &CommentPolicyExecutionContext,
         comment: &Comment,
     ) -> CommentPolicyResult;
+
+    fn allow_edit(
+        context: &CommentPolicyExecutionContext,
+        comment: &Comment,
+    ) -> CommentPolicyResult;
+
+    fn allow_view_revisions(
+        context: &CommentPolicyExecutionContext,
+        comment: &Comment,
+    ) -> CommentPolicyResult;
+}
+
+pub struct DefaultCommentPolicy;
+
+impl CommentPolicy for DefaultCommentPolicy {
+    fn allow_publish(context: &CommentPolicyExecutionContext) -> CommentPolicyResult {
+        // rule: muted members can't comment until their mute expires
+        if context.muted {
+            return Err(CommentPolicyViolation::Muted);
+        }
+        Ok(())
+    }
+
+    fn allow_remove(context: &CommentPolicyExecutionContext, comment: &Comment) -> CommentPolicyResult {
+        // rule: authors can remove their own comments, editors can remove any comment in their community
+        if comment.author != context.user && !context.editor {
+            return Err(CommentPolicyViolation::InsufficientPermissions);
+        }
+        Ok(())
+    }
+
+    fn allow_edit(context: &CommentPolicyExecutionContext, comment: &Comment) -> CommentPolicyResult {
+        // rule: only the author can edit a comment, unless muted
+        if comment.author != context.user {
+            return Err(CommentPolicyViolation::InsufficientPermissions);
+        }
+        if context.muted {
+            return Err(CommentPolicyViolation::Muted);
+        }
+        Ok(())
+    }
+
+    fn allow_view_revisions(context: &CommentPolicyExecutionContext, _comment: &Comment) -> CommentPolicyResult {
+        // rule: earlier revisions are visible to editors only
+        if !context.editor {
+            return Err(CommentPolicyViolation::InsufficientPermissions);
+        }
+        Ok(())
+    }
 }
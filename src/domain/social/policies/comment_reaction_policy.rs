@@ -0,0 +1,23 @@
+use crate::domain::social::aggregates::CommunityId;
+use crate::domain::social::aggregates::{CommentReaction};
+use crate::domain::account::aggregates::UserId;
+
+#[derive(Debug)]
+pub enum CommentReactionPolicyViolation {
+    InsufficientPermissions,
+    OperationBlacklisted,
+}
+
+type CommentReactionPolicyResult = Result<(), CommentReactionPolicyViolation>;
+
+pub struct CommentReactionPolicyExecutionContext {
+    pub user: UserId,
+    pub community: CommunityId,
+}
+
+pub trait CommentReactionPolicy {
+    fn allow_react(
+        context: &CommentReactionPolicyExecutionContext,
+        reaction: CommentReaction,
+    ) -> CommentReactionPolicyResult;
+}
@@ -23,4 +23,22 @@ pub trait CommunityPolicy {
         context: &CommunityPolicyExecutionContext,
         moderator: &UserId,
     ) -> CommunityPolicyResult;
+    fn allow_remove_member(
+        context: &CommunityPolicyExecutionContext,
+        member: &UserId,
+    ) -> CommunityPolicyResult;
+    fn allow_ban(
+        context: &CommunityPolicyExecutionContext,
+        user: &UserId,
+    ) -> CommunityPolicyResult;
+    fn allow_unban(
+        context: &CommunityPolicyExecutionContext,
+        user: &UserId,
+    ) -> CommunityPolicyResult;
+    fn allow_mute(
+        context: &CommunityPolicyExecutionContext,
+        member: &UserId,
+    ) -> CommunityPolicyResult;
+    fn allow_set_word_list(context: &CommunityPolicyExecutionContext) -> CommunityPolicyResult;
+    fn allow_set_reactions(context: &CommunityPolicyExecutionContext) -> CommunityPolicyResult;
 }
@@ -1,15 +1,19 @@
 pub mod comment_policy;
+pub mod comment_reaction_policy;
 pub mod community_policy;
 pub mod feed_policy;
 pub mod post_policy;
 pub mod post_reaction_policy;
 
-pub use comment_policy::{CommentPolicy, CommentPolicyExecutionContext, CommentPolicyViolation};
+pub use comment_policy::{CommentPolicy, CommentPolicyExecutionContext, CommentPolicyViolation, DefaultCommentPolicy};
+pub use comment_reaction_policy::{
+    CommentReactionPolicy, CommentReactionPolicyExecutionContext, CommentReactionPolicyViolation,
+};
 pub use community_policy::{
     CommunityPolicy, CommunityPolicyExecutionContext, CommunityPolicyViolation,
 };
 pub use feed_policy::{FeedPolicy, FeedPolicyExecutionContext, FeedPolicyViolation};
-pub use post_policy::{PostPolicy, PostPolicyExecutionContext, PostPolicyViolation};
+pub use post_policy::{DefaultPostPolicy, PostPolicy, PostPolicyExecutionContext, PostPolicyViolation};
 pub use post_reaction_policy::{
     PostReactionPolicy, PostReactionPolicyExecutionContext, PostReactionPolicyViolation,
 };
@@ -5,6 +5,7 @@ use crate::domain::account::aggregates::UserId;
 #[derive(Debug)]
 pub enum PostPolicyViolation {
     InsufficientPermissions,
+    Muted,
 }
 
 type PostPolicyResult = Result<(), PostPolicyViolation>;
@@ -12,9 +13,117 @@ type PostPolicyResult = Result<(), PostPolicyViolation>;
 pub struct PostPolicyExecutionContext {
     pub user: UserId,
     pub community: CommunityId,
+    pub editor: bool,
+    pub muted: bool,
 }
 
 pub trait PostPolicy {
     fn allow_publish(context: &PostPolicyExecutionContext) -> PostPolicyResult;
     fn allow_remove(context: &PostPolicyExecutionContext, post: &Post) -> PostPolicyResult;
+    fn allow_edit(context: &PostPolicyExecutionContext, post: &Post) -> PostPolicyResult;
+    fn allow_view_revisions(context: &PostPolicyExecutionContext, post: &Post) -> PostPolicyResult;
+    fn allow_announce(context: &PostPolicyExecutionContext) -> PostPolicyResult;
+    fn allow_pin(context: &PostPolicyExecutionContext, post: &Post) -> PostPolicyResult;
+    fn allow_unpin(context: &PostPolicyExecutionContext, post: &Post) -> PostPolicyResult;
+}
+
+pub struct DefaultPostPolicy;
+
+impl PostPolicy for DefaultPostPolicy {
+    fn allow_publish(context: &PostPolicyExecutionContext) -> PostPolicyResult {
+        // rule: muted members can't publish until their mute expires
+        if context.muted {
+            return Err(PostPolicyViolation::Muted);
+        }
+        Ok(())
+    }
+
+    fn allow_remove(context: &PostPolicyExecutionContext, post: &Post) -> PostPolicyResult {
+        // rule: authors can remove their own posts, editors can remove any post in their community
+        if post.author != context.user && !context.editor {
+            return Err(PostPolicyViolation::InsufficientPermissions);
+        }
+        Ok(())
+    }
+
+    fn allow_edit(context: &PostPolicyExecutionContext, post: &Post) -> PostPolicyResult {
+        // rule: only the author can edit a post, unless muted
+        if post.author != context.user {
+            return Err(PostPolicyViolation::InsufficientPermissions);
+        }
+        if context.muted {
+            return Err(PostPolicyViolation::Muted);
+        }
+        Ok(())
+    }
+
+    fn allow_view_revisions(context: &PostPolicyExecutionContext, _post: &Post) -> PostPolicyResult {
+        // rule: earlier revisions are visible to editors only
+        editor_only(context)
+    }
+
+    fn allow_announce(context: &PostPolicyExecutionContext) -> PostPolicyResult {
+        // rule: only editors can publish announcements
+        editor_only(context)
+    }
+
+    fn allow_pin(context: &PostPolicyExecutionContext, _post: &Post) -> PostPolicyResult {
+        // rule: only editors can pin posts
+        editor_only(context)
+    }
+
+    fn allow_unpin(context: &PostPolicyExecutionContext, _post: &Post) -> PostPolicyResult {
+        // rule: only editors can unpin posts
+        editor_only(context)
+    }
+}
+
+fn editor_only(context: &PostPolicyExecutionContext) -> PostPolicyResult {
+    if !context.editor {
+        return Err(PostPolicyViolation::InsufficientPermissions);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Utc;
+    use crate::domain::social::aggregates::{PostAttachments, PostId, PostText};
+    use super::*;
+
+    fn user(name: &str) -> UserId {
+        UserId::parse(format!("{:0<20}", name).as_str()).unwrap()
+    }
+
+    fn context(name: &str, editor: bool, muted: bool) -> PostPolicyExecutionContext {
+        PostPolicyExecutionContext { user: user(name), community: CommunityId::random(), editor, muted }
+    }
+
+    fn post(author: &str) -> Post {
+        Post::new(PostId::random(), CommunityId::random(), PostText::parse("derby day").unwrap(), PostAttachments::from_vec(Vec::new()), user(author), Utc::now())
+    }
+
+    #[test]
+    fn muted_members_cannot_publish() {
+        assert!(DefaultPostPolicy::allow_publish(&context("alice", false, false)).is_ok());
+        assert!(matches!(DefaultPostPolicy::allow_publish(&context("alice", false, true)), Err(PostPolicyViolation::Muted)));
+    }
+
+    #[test]
+    fn muted_authors_cannot_edit() {
+        let post = post("alice");
+
+        assert!(DefaultPostPolicy::allow_edit(&context("alice", false, false), &post).is_ok());
+        assert!(matches!(DefaultPostPolicy::allow_edit(&context("alice", false, true), &post), Err(PostPolicyViolation::Muted)));
+        assert!(matches!(DefaultPostPolicy::allow_edit(&context("bob", true, false), &post), Err(PostPolicyViolation::InsufficientPermissions)));
+    }
+
+    #[test]
+    fn editors_remove_any_post() {
+        let post = post("alice");
+
+        assert!(DefaultPostPolicy::allow_remove(&context("alice", false, true), &post).is_ok());
+        assert!(DefaultPostPolicy::allow_remove(&context("bob", true, false), &post).is_ok());
+        assert!(matches!(DefaultPostPolicy::allow_remove(&context("bob", false, false), &post), Err(PostPolicyViolation::InsufficientPermissions)));
+    }
 }
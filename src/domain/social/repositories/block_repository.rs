@@ -0,0 +1,11 @@
+use crate::common::RepositoryResult;
+use crate::domain::account::aggregates::UserId;
+use crate::domain::social::aggregates::Block;
+
+#[tonic::async_trait]
+pub trait BlockRepository {
+    async fn list(&self, blocker: &UserId, after: &Option<UserId>) -> RepositoryResult<Vec<Block>>;
+    async fn is_blocked(&self, blocker: &UserId, user: &UserId) -> RepositoryResult<bool>;
+    async fn set(&self, block: &Block) -> RepositoryResult<bool>;
+    async fn unset(&self, blocker: &UserId, user: &UserId) -> RepositoryResult<bool>;
+}
@@ -0,0 +1,9 @@
+use crate::common::RepositoryResult;
+use crate::domain::account::aggregates::UserId;
+use crate::domain::social::aggregates::{Bookmark, BookmarkTarget};
+
+#[tonic::async_trait]
+pub trait BookmarkRepository {
+    async fn set(&self, bookmark: &Bookmark) -> RepositoryResult<bool>;
+    async fn unset(&self, owner: &UserId, target: &BookmarkTarget) -> RepositoryResult<bool>;
+}
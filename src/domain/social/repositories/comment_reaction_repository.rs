@@ -0,0 +1,8 @@
+use crate::common::RepositoryResult;
+use crate::domain::social::aggregates::CommentReaction;
+
+#[tonic::async_trait]
+pub trait CommentReactionRepository {
+    async fn set(&self, reaction: &CommentReaction) -> RepositoryResult<bool>;
+    async fn unset(&self, reaction: &CommentReaction) -> RepositoryResult<bool>;
+}
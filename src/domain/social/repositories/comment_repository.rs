@@ -1,10 +1,16 @@
+use chrono::{DateTime, Utc};
 use crate::common::RepositoryResult;
-use crate::domain::social::aggregates::{Comment, CommentId, PostId};
+use crate::domain::account::aggregates::UserId;
+use crate::domain::social::aggregates::{Comment, CommentId, CommentListing, PostId};
 
 #[tonic::async_trait]
 pub trait CommentRepository {
-    async fn list(&self, post: &PostId, after: &Option<CommentId>) -> RepositoryResult<Vec<Comment>>;
+    // lists top-level comments, each followed by up to `replies` of its direct replies
+    async fn list(&self, post: &PostId, viewer: &UserId, after: &Option<CommentId>, include_removed: bool, replies: u32) -> RepositoryResult<Vec<CommentListing>>;
+    // lists all replies below a comment, depth first
+    async fn list_thread(&self, comment: &CommentId, viewer: &UserId, after: &Option<CommentId>, include_removed: bool) -> RepositoryResult<Vec<CommentListing>>;
     async fn get(&self, id: &CommentId) -> RepositoryResult<Option<Comment>>;
     async fn set(&self, comment: &Comment) -> RepositoryResult<()>;
-    async fn remove(&self, id: &CommentId) -> RepositoryResult<()>;
+    // permanently deletes comments removed before the given moment, including their replies
+    async fn purge(&self, removed_before: &DateTime<Utc>) -> RepositoryResult<Vec<CommentId>>;
 }
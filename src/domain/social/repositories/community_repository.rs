@@ -1,9 +1,11 @@
 use crate::common::RepositoryResult;
+use crate::domain::account::aggregates::UserId;
 use crate::domain::social::aggregates::{Community, CommunityContext, CommunityId};
 
 #[tonic::async_trait]
 pub trait CommunityRepository {
     async fn list(&self, context: &Option<CommunityContext>, after: &Option<CommunityId>) -> RepositoryResult<Vec<Community>>;
+    async fn list_offered(&self, person: &UserId, after: &Option<CommunityId>) -> RepositoryResult<Vec<Community>>;
     async fn get(&self, id: &CommunityId) -> RepositoryResult<Option<Community>>;
     async fn set(&self, community: &Community) -> RepositoryResult<()>;
 }
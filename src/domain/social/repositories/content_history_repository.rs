@@ -0,0 +1,10 @@
+use chrono::{DateTime, Utc};
+use crate::common::RepositoryResult;
+use crate::domain::account::aggregates::UserId;
+use crate::domain::social::filters::FilterableContentKind;
+
+#[tonic::async_trait]
+pub trait ContentHistoryRepository {
+    // texts of live posts + comments published by author since given time, except the content being checked
+    async fn recent_texts(&self, author: &UserId, since: &DateTime<Utc>, except: &FilterableContentKind) -> RepositoryResult<Vec<String>>;
+}
@@ -1,7 +1,8 @@
 use crate::common::RepositoryResult;
+use crate::domain::account::aggregates::UserId;
 use crate::domain::social::aggregates::{Feed, FeedFragment, PostId};
 
 #[tonic::async_trait]
 pub trait FeedRepository {
-    async fn list(&self, feed: &Feed, after: &Option<PostId>) -> RepositoryResult<FeedFragment>;
+    async fn list(&self, feed: &Feed, viewer: &UserId, after: &Option<PostId>, include_removed: bool) -> RepositoryResult<FeedFragment>;
 }
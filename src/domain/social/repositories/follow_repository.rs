@@ -0,0 +1,11 @@
+use crate::common::RepositoryResult;
+use crate::domain::account::aggregates::UserId;
+use crate::domain::social::aggregates::Follow;
+
+#[tonic::async_trait]
+pub trait FollowRepository {
+    async fn list_followers(&self, followee: &UserId, after: &Option<UserId>) -> RepositoryResult<Vec<Follow>>;
+    async fn list_following(&self, follower: &UserId, after: &Option<UserId>) -> RepositoryResult<Vec<Follow>>;
+    async fn set(&self, follow: &Follow) -> RepositoryResult<bool>;
+    async fn unset(&self, follower: &UserId, followee: &UserId) -> RepositoryResult<bool>;
+}
@@ -1,11 +1,31 @@
+pub mod block_repository;
+pub mod bookmark_repository;
+pub mod comment_reaction_repository;
 pub mod comment_repository;
+pub mod content_history_repository;
 pub mod feed_repository;
+pub mod follow_repository;
+pub mod poll_vote_repository;
 pub mod post_reaction_repository;
 pub mod post_repository;
+pub mod profile_repository;
 pub mod community_repository;
+pub mod report_repository;
+pub mod search_repository;
+pub mod word_list_repository;
 
+pub use block_repository::BlockRepository;
+pub use bookmark_repository::BookmarkRepository;
+pub use comment_reaction_repository::CommentReactionRepository;
 pub use comment_repository::CommentRepository;
+pub use content_history_repository::ContentHistoryRepository;
 pub use feed_repository::FeedRepository;
+pub use follow_repository::FollowRepository;
+pub use poll_vote_repository::PollVoteRepository;
 pub use post_reaction_repository::PostReactionRepository;
 pub use post_repository::PostRepository;
-pub use community_repository::CommunityRepository;
\ No newline at end of file
+pub use profile_repository::ProfileRepository;
+pub use community_repository::CommunityRepository;
+pub use report_repository::ReportRepository;
+pub use search_repository::SearchRepository;
+pub use word_list_repository::WordListRepository;
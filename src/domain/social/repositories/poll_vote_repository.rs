@@ -0,0 +1,14 @@
+use chrono::{DateTime, Utc};
+use crate::common::RepositoryResult;
+use crate::domain::account::aggregates::UserId;
+use crate::domain::social::aggregates::{PollVote, PostId};
+
+#[tonic::async_trait]
+pub trait PollVoteRepository {
+    // lists votes on the poll of a post, paginated by voter
+    async fn list(&self, post: &PostId, choice: &Option<u32>, after: &Option<UserId>) -> RepositoryResult<Vec<PollVote>>;
+    // returns false when the voter already voted
+    async fn set(&self, vote: &PollVote) -> RepositoryResult<bool>;
+    // marks polls closing before the given moment as closed, returns the posts of newly closed polls
+    async fn close(&self, closes_before: &DateTime<Utc>) -> RepositoryResult<Vec<PostId>>;
+}
@@ -1,8 +1,11 @@
 use crate::common::RepositoryResult;
-use crate::domain::social::aggregates::PostReaction;
+use crate::domain::account::aggregates::UserId;
+use crate::domain::social::aggregates::{PostId, PostReaction, PostReactionRecord, ReactionKey};
 
 #[tonic::async_trait]
 pub trait PostReactionRepository {
-    async fn set(&self, reaction: &PostReaction) -> RepositoryResult<bool>;
+    // lists reactions to a post, most recent first, of any of the given reactions (empty means all); reactions are paginated by author
+    async fn list(&self, post: &PostId, reactions: &[ReactionKey], after: &Option<UserId>) -> RepositoryResult<Vec<PostReactionRecord>>;
+    async fn set(&self, record: &PostReactionRecord) -> RepositoryResult<bool>;
     async fn unset(&self, reaction: &PostReaction) -> RepositoryResult<bool>;
 }
@@ -1,9 +1,18 @@
+use chrono::{DateTime, Utc};
 use crate::common::RepositoryResult;
-use crate::domain::social::aggregates::{Post, PostId};
+use crate::domain::account::aggregates::UserId;
+use crate::domain::social::aggregates::{CommunityId, Post, PostId};
 
 #[tonic::async_trait]
 pub trait PostRepository {
     async fn get(&self, id: &PostId) -> RepositoryResult<Option<Post>>;
     async fn set(&self, post: &Post) -> RepositoryResult<()>;
-    async fn remove(&self, id: &PostId) -> RepositoryResult<()>;
+    // lists pinned posts of a community, excluding removed posts
+    async fn list_pinned(&self, community: &CommunityId) -> RepositoryResult<Vec<Post>>;
+    // lists drafts & scheduled posts of an author, excluding removed posts
+    async fn list_drafts(&self, author: &UserId, after: &Option<PostId>) -> RepositoryResult<Vec<Post>>;
+    // lists scheduled posts due at the given moment
+    async fn list_due(&self, at: &DateTime<Utc>) -> RepositoryResult<Vec<Post>>;
+    // permanently deletes posts removed before the given moment, including their comments & reactions
+    async fn purge(&self, removed_before: &DateTime<Utc>) -> RepositoryResult<Vec<PostId>>;
 }
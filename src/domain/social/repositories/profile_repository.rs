@@ -0,0 +1,9 @@
+use crate::common::RepositoryResult;
+use crate::domain::account::aggregates::UserId;
+use crate::domain::social::aggregates::Language;
+
+#[tonic::async_trait]
+pub trait ProfileRepository {
+    // note: the language of the user's profile locale, if set and a ISO 639-1 code
+    async fn get_language(&self, user: &UserId) -> RepositoryResult<Option<Language>>;
+}
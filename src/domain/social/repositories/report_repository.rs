@@ -0,0 +1,13 @@
+use crate::common::RepositoryResult;
+use crate::domain::social::aggregates::{Report, ReportId, ReportTarget};
+use crate::domain::account::aggregates::UserId;
+
+#[tonic::async_trait]
+pub trait ReportRepository {
+    // open reports in communities edited by the given editor, oldest first
+    async fn list(&self, editor: &UserId, after: &Option<ReportId>) -> RepositoryResult<Vec<Report>>;
+    async fn list_open(&self, target: &ReportTarget) -> RepositoryResult<Vec<Report>>;
+    async fn count_reporters(&self, target: &ReportTarget) -> RepositoryResult<u64>;
+    async fn get(&self, id: &ReportId) -> RepositoryResult<Option<Report>>;
+    async fn set(&self, report: &Report) -> RepositoryResult<()>;
+}
@@ -0,0 +1,9 @@
+use crate::common::RepositoryResult;
+use crate::domain::account::aggregates::UserId;
+use crate::domain::social::aggregates::{SearchHit, SearchKind, SearchQuery, SearchTarget};
+
+// note: hits are ordered by rank (best first); only content visible to the viewer is searched
+#[tonic::async_trait]
+pub trait SearchRepository {
+    async fn search(&self, query: &SearchQuery, kinds: &[SearchKind], viewer: &UserId, after: &Option<SearchTarget>) -> RepositoryResult<Vec<SearchHit>>;
+}
@@ -0,0 +1,8 @@
+use crate::common::RepositoryResult;
+use crate::domain::social::aggregates::{CommunityId, WordList};
+
+#[tonic::async_trait]
+pub trait WordListRepository {
+    async fn list(&self, community: &CommunityId) -> RepositoryResult<Vec<WordList>>;
+    async fn set(&self, list: &WordList) -> RepositoryResult<()>;
+}
@@ -1,10 +1,28 @@
 use std::fmt::Formatter;
+use crate::domain::social::filters::ContentFilterReason;
+use crate::domain::social::policies::{CommentPolicyViolation, PostPolicyViolation};
 
 #[derive(Debug)]
 pub enum DomainError {
     UnknownCommunity,
     UnknownPost,
     UnknownComment,
+    InsufficientPermissions,
+    BannedFromCommunity,
+    MutedInCommunity,
+    UnknownReport,
+    ContentRejected(ContentFilterReason),
+    CommentDepthExceeded,
+    UnknownReaction,
+    InvalidReactionSet,
+    PinLimitReached,
+    InvalidSchedule,
+    UnknownPoll,
+    PollClosed,
+    InvalidVote,
+    AlreadyVoted,
+    SelfFollow,
+    SelfBlock,
 }
 
 impl std::fmt::Display for DomainError {
@@ -13,8 +31,42 @@ impl std::fmt::Display for DomainError {
             DomainError::UnknownCommunity => write!(f,"unknown community"),
             DomainError::UnknownPost => write!(f,"unknown post"),
             DomainError::UnknownComment => write!(f,"unknown comment"),
+            DomainError::InsufficientPermissions => write!(f,"insufficient permissions"),
+            DomainError::BannedFromCommunity => write!(f,"banned from community"),
+            DomainError::MutedInCommunity => write!(f,"muted in community"),
+            DomainError::UnknownReport => write!(f,"unknown report"),
+            DomainError::ContentRejected(reason) => write!(f,"content rejected: {}", reason),
+            DomainError::CommentDepthExceeded => write!(f,"comment depth exceeded"),
+            DomainError::UnknownReaction => write!(f,"unknown reaction"),
+            DomainError::InvalidReactionSet => write!(f,"invalid reaction set"),
+            DomainError::PinLimitReached => write!(f,"pin limit reached"),
+            DomainError::InvalidSchedule => write!(f,"invalid schedule"),
+            DomainError::UnknownPoll => write!(f,"unknown poll"),
+            DomainError::PollClosed => write!(f,"poll closed"),
+            DomainError::InvalidVote => write!(f,"invalid vote"),
+            DomainError::AlreadyVoted => write!(f,"already voted"),
+            DomainError::SelfFollow => write!(f,"can't follow yourself"),
+            DomainError::SelfBlock => write!(f,"can't block yourself"),
         }
     }
 }
 
 impl std::error::Error for DomainError {}
+
+impl From<PostPolicyViolation> for DomainError {
+    fn from(violation: PostPolicyViolation) -> Self {
+        match violation {
+            PostPolicyViolation::InsufficientPermissions => DomainError::InsufficientPermissions,
+            PostPolicyViolation::Muted => DomainError::MutedInCommunity,
+        }
+    }
+}
+
+impl From<CommentPolicyViolation> for DomainError {
+    fn from(violation: CommentPolicyViolation) -> Self {
+        match violation {
+            CommentPolicyViolation::InsufficientPermissions => DomainError::InsufficientPermissions,
+            CommentPolicyViolation::Muted => DomainError::MutedInCommunity,
+        }
+    }
+}
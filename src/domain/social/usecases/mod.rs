@@ -1,4 +1,10 @@
 pub mod usecase;
 pub mod error;
+pub mod publication;
+pub mod retention;
+pub mod settings;
 
-pub use error::DomainError;
\ No newline at end of file
+pub use error::DomainError;
+pub use publication::PublicationUsecase;
+pub use retention::RetentionUsecase;
+pub use settings::SocialSettings;
@@ -0,0 +1,81 @@
+use std::sync::Arc;
+use chrono::Utc;
+use crate::common::EventPublisher;
+use crate::domain::social::events::{PollClosedV1, PostPublishedV1, UserMentionedV1};
+use crate::domain::social::repositories::{BlockRepository, PollVoteRepository, PostRepository};
+use crate::domain::social::usecases::usecase::Result;
+
+// note: runs periodically, outside of any request
+pub struct PublicationUsecase {
+    post_repository: Box<dyn PostRepository + Send + Sync>,
+    poll_vote_repository: Box<dyn PollVoteRepository + Send + Sync>,
+    block_repository: Box<dyn BlockRepository + Send + Sync>,
+    event_publisher: Arc<EventPublisher>,
+}
+
+impl PublicationUsecase {
+    pub fn build(
+        post_repository: Box<dyn PostRepository + Send + Sync>,
+        poll_vote_repository: Box<dyn PollVoteRepository + Send + Sync>,
+        block_repository: Box<dyn BlockRepository + Send + Sync>,
+        event_publisher: Arc<EventPublisher>,
+    ) -> PublicationUsecase {
+        PublicationUsecase {
+            post_repository,
+            poll_vote_repository,
+            block_repository,
+            event_publisher,
+        }
+    }
+
+    // rule: scheduled posts go live once due, published reflects the actual moment
+    pub async fn publish_due(&self) -> Result<()> {
+        let now = Utc::now();
+
+        for mut post in self.post_repository.list_due(&now).await? {
+            if !post.publish(now) {
+                continue;
+            }
+            self.post_repository.set(&post).await?;
+
+            for user in post.mentions() {
+                // rule: blocked users don't reach the blocker by mentioning them
+                if self.block_repository.is_blocked(&user, &post.author).await? {
+                    continue;
+                }
+
+                let event = UserMentionedV1 {
+                    user,
+                    author: post.author.clone(),
+                    community: post.community.clone(),
+                    post: post.id.clone(),
+                    comment: None,
+                    mentioned: post.published,
+                };
+                self.event_publisher.publish(&event).await?;
+            }
+
+            let event = PostPublishedV1 {
+                id: post.id,
+                community: post.community,
+                text: post.text,
+                attachments: post.attachments,
+                author: post.author,
+                published: post.published,
+            };
+            self.event_publisher.publish(&event).await?;
+        }
+
+        Ok(())
+    }
+
+    // rule: polls close at their closing time, which is announced once
+    pub async fn close_due_polls(&self) -> Result<()> {
+        for post in self.poll_vote_repository.close(&Utc::now()).await? {
+            let event = PollClosedV1 { post };
+            self.event_publisher.publish(&event).await?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,48 @@
+use std::sync::Arc;
+use chrono::{Duration, Utc};
+use crate::common::EventPublisher;
+use crate::domain::social::events::{CommentPurgedV1, PostPurgedV1};
+use crate::domain::social::repositories::{CommentRepository, PostRepository};
+use crate::domain::social::usecases::usecase::Result;
+
+// note: runs periodically, outside of any request
+pub struct RetentionUsecase {
+    post_repository: Box<dyn PostRepository + Send + Sync>,
+    comment_repository: Box<dyn CommentRepository + Send + Sync>,
+    event_publisher: Arc<EventPublisher>,
+    retention: Duration,
+}
+
+impl RetentionUsecase {
+    pub fn build(
+        post_repository: Box<dyn PostRepository + Send + Sync>,
+        comment_repository: Box<dyn CommentRepository + Send + Sync>,
+        event_publisher: Arc<EventPublisher>,
+        retention: Duration,
+    ) -> RetentionUsecase {
+        RetentionUsecase {
+            post_repository,
+            comment_repository,
+            event_publisher,
+            retention,
+        }
+    }
+
+    // rule: removed content is purged permanently once the retention period has passed
+    pub async fn purge_removed(&self) -> Result<()> {
+        let removed_before = Utc::now() - self.retention;
+
+        // note: purging a post also purges its comments & reactions
+        for id in self.post_repository.purge(&removed_before).await? {
+            let event = PostPurgedV1 { id };
+            self.event_publisher.publish(&event).await?;
+        }
+
+        for id in self.comment_repository.purge(&removed_before).await? {
+            let event = CommentPurgedV1 { id };
+            self.event_publisher.publish(&event).await?;
+        }
+
+        Ok(())
+    }
+}
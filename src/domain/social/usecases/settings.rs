@@ -0,0 +1,12 @@
+use chrono::Duration;
+
+pub struct SocialSettings {
+    // number of distinct reporters after which a post is hidden pending review
+    pub report_threshold: u64,
+    // maximum nesting of comment replies, top-level comments are at depth 0
+    pub max_comment_depth: u32,
+    // maximum number of pinned posts per community
+    pub max_pinned_posts: usize,
+    // period during which an announcement tops the timelines of community members
+    pub announcement_duration: Duration,
+}
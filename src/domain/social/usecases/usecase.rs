@@ -1,15 +1,26 @@
 use std::error::Error;
 use std::sync::Arc;
-use chrono::{Utc};
+use chrono::{DateTime, Utc};
 use crate::common::EventPublisher;
-use crate::domain::social::aggregates::{Comment, CommentId, Community, CommunityContext, CommunityId, Feed, FeedFragment, Post, PostId};
-use crate::domain::social::commands::comment::{PublishComment, PublishCommentResult, RemoveComment};
-use crate::domain::social::commands::community::{DemoteEditor, Join, Leave, New, NewResult, PromoteMemberToEditor, SetLogo};
-use crate::domain::social::commands::post::{PublishPost, PublishPostResult, RemovePost};
+use crate::domain::account::aggregates::UserId;
+use crate::domain::social::aggregates::{catalog, Ban, Block, Bookmark, BookmarkTarget, Comment, CommentId, CommentListing, CommentParent, CommentRevision, Community, CommunityContext, CommunityId, Feed, FeedFragment, Follow, Hashtag, Mute, PollVisibility, PollVote, Post, PostId, PostReactionRecord, PostRevision, ReactionDefinition, ReactionKey, ReactionSet, RemoverRole, Report, ReportCategory, ReportId, ReportTarget, ReportText, SearchHit, SearchKind, SearchQuery, SearchTarget, Tombstone, WordList};
+use crate::domain::social::commands::block::{BlockUser, UnblockUser};
+use crate::domain::social::commands::bookmark::{AddBookmark, RemoveBookmark};
+use crate::domain::social::commands::follow::{FollowUser, UnfollowUser};
+use crate::domain::social::commands::comment::{EditComment, PublishComment, PublishCommentResult, RemoveComment};
+use crate::domain::social::commands::community::{BanUser, DeclineOffer, DemoteEditor, Join, Leave, MuteMember, New, NewResult, PromoteMemberToEditor, RemoveMember, SetLogo, SetReactions, UnbanUser};
+use crate::domain::social::commands::poll::VoteInPoll;
+use crate::domain::social::commands::post::{EditDraft, EditPost, PinPost, PublishDraft, PublishPost, PublishPostResult, RemovePost, SaveDraft, SaveDraftResult, UnpinPost};
+use crate::domain::social::commands::comment_reaction::{ReactToComment, RetractCommentReaction};
 use crate::domain::social::commands::post_reaction::{ReactToPost, RetractPostReaction};
-use crate::domain::social::events::{CommentPublishedV1, CommentRemovedV1, CommunityAddedV1, CommunityLogoSetV1, EditorDemotedV1, JoinedV1, LeftV1, MemberPromotedToEditorV1, PostPublishedV1, PostReactionRetractedV1, PostRemovedV1, ReactedToPostV1};
-use crate::domain::social::repositories::{CommentRepository, CommunityRepository, FeedRepository, PostReactionRepository, PostRepository};
+use crate::domain::social::commands::report::{BanReportedAuthor, DismissReport, RemoveReportedContent, ReportComment, ReportPost, ReportResult};
+use crate::domain::social::commands::word_list::SetWordList;
+use crate::domain::social::events::{BookmarkAddedV1, BookmarkRemovedV1, CommentEditedV1, CommentPublishedV2, CommentReactionRetractedV2, CommentRemovedV2, CommunityAddedV1, CommunityLogoSetV1, CommunityReactionsSetV1, ContentFlaggedV1, EditorDemotedV1, JoinedV1, LeftV1, MemberMutedV1, MembershipOfferDeclinedV1, MemberPromotedToEditorV1, MemberRemovedV1, CommentReportedV1, PostEditedV1, PostHiddenV1, PollVotedV1, PostPinnedV1, PostPublishedV1, PostReactionRetractedV2, PostRemovedV2, PostReportedV1, PostScheduledV1, PostUnpinnedV1, ReactedToCommentV2, ReactedToPostV2, ReportDismissedV1, ReportedAuthorBannedV1, ReportedContentRemovedV1, UserBannedV1, UserBlockedV1, UserFollowedV1, UserMentionedV1, UserUnbannedV1, UserUnblockedV1, UserUnfollowedV1, WordListSetV1};
+use crate::domain::social::filters::{ContentFilter, ContentFilterOutcome, ContentFilterReason, FilterableContent, FilterableContentKind};
+use crate::domain::social::policies::{CommentPolicy, CommentPolicyExecutionContext, DefaultCommentPolicy, DefaultPostPolicy, PostPolicy, PostPolicyExecutionContext};
+use crate::domain::social::repositories::{BlockRepository, BookmarkRepository, CommentReactionRepository, CommentRepository, CommunityRepository, FeedRepository, FollowRepository, PollVoteRepository, PostReactionRepository, PostRepository, ProfileRepository, ReportRepository, SearchRepository, WordListRepository};
 use crate::domain::social::usecases::error::DomainError;
+use crate::domain::social::usecases::SocialSettings;
 
 pub type Result<T> = std::result::Result<T, Box<dyn Error + Send + Sync>>;
 
@@ -17,31 +28,66 @@ pub struct SocialUsecase {
     community_repository: Box<dyn CommunityRepository + Send + Sync>,
     post_repository: Box<dyn PostRepository + Send + Sync>,
     post_reaction_repository: Box<dyn PostReactionRepository + Send + Sync>,
+    poll_vote_repository: Box<dyn PollVoteRepository + Send + Sync>,
     comment_repository: Box<dyn CommentRepository + Send + Sync>,
+    comment_reaction_repository: Box<dyn CommentReactionRepository + Send + Sync>,
+    bookmark_repository: Box<dyn BookmarkRepository + Send + Sync>,
+    follow_repository: Box<dyn FollowRepository + Send + Sync>,
+    block_repository: Box<dyn BlockRepository + Send + Sync>,
+    profile_repository: Box<dyn ProfileRepository + Send + Sync>,
     feed_repository: Box<dyn FeedRepository + Send + Sync>,
+    search_repository: Box<dyn SearchRepository + Send + Sync>,
+    report_repository: Box<dyn ReportRepository + Send + Sync>,
+    word_list_repository: Box<dyn WordListRepository + Send + Sync>,
+    content_filter: Box<dyn ContentFilter + Send + Sync>,
     event_publisher: Arc<EventPublisher>,
+    settings: SocialSettings,
 }
 
 impl SocialUsecase {
+    #[allow(clippy::too_many_arguments)]
     pub fn build(
         community_repository: Box<dyn CommunityRepository + Send + Sync>,
         post_repository: Box<dyn PostRepository + Send + Sync>,
         post_reaction_repository: Box<dyn PostReactionRepository + Send + Sync>,
+        poll_vote_repository: Box<dyn PollVoteRepository + Send + Sync>,
         comment_repository: Box<dyn CommentRepository + Send + Sync>,
+        comment_reaction_repository: Box<dyn CommentReactionRepository + Send + Sync>,
+        bookmark_repository: Box<dyn BookmarkRepository + Send + Sync>,
+        follow_repository: Box<dyn FollowRepository + Send + Sync>,
+        block_repository: Box<dyn BlockRepository + Send + Sync>,
+        profile_repository: Box<dyn ProfileRepository + Send + Sync>,
         feed_repository: Box<dyn FeedRepository + Send + Sync>,
-        event_publisher: Arc<EventPublisher>) -> SocialUsecase {
+        search_repository: Box<dyn SearchRepository + Send + Sync>,
+        report_repository: Box<dyn ReportRepository + Send + Sync>,
+        word_list_repository: Box<dyn WordListRepository + Send + Sync>,
+        content_filter: Box<dyn ContentFilter + Send + Sync>,
+        event_publisher: Arc<EventPublisher>,
+        settings: SocialSettings) -> SocialUsecase {
         SocialUsecase {
             community_repository,
             post_repository,
             post_reaction_repository,
+            poll_vote_repository,
             comment_repository,
+            comment_reaction_repository,
+            bookmark_repository,
+            follow_repository,
+            block_repository,
+            profile_repository,
             feed_repository,
+            search_repository,
+            report_repository,
+            word_list_repository,
+            content_filter,
             event_publisher,
+            settings,
         }
     }
 
     // commands
     // - community
+    #[allow(clippy::new_ret_no_self)]
     pub async fn new(&self, command: New) -> Result<NewResult> {
         let id = CommunityId::random();
         let name = command.name;
@@ -113,13 +159,44 @@ impl SocialUsecase {
         Ok(())
     }
 
+    pub async fn set_reactions(&self, command: SetReactions) -> Result<()> {
+        let mut community = self.community_repository
+            .get(&command.community)
+            .await?
+            .ok_or(DomainError::UnknownCommunity)?;
+
+        if !community.is_editor(&command.editor) {
+            return Err(DomainError::InsufficientPermissions.into());
+        }
+
+        let reactions = ReactionSet::new(command.enabled, command.custom)
+            .ok_or(DomainError::InvalidReactionSet)?;
+
+        community.set_reactions(reactions);
+        self.community_repository.set(&community).await?;
+
+        let event = CommunityReactionsSetV1 {
+            community: community.id,
+            reactions: community.reactions,
+            editor: command.editor,
+        };
+        self.event_publisher.publish(&event).await?;
+
+        Ok(())
+    }
+
     pub async fn join(&self, command: Join) -> Result<()> {
         let mut community = self.community_repository
             .get(&command.community)
             .await?
             .ok_or(DomainError::UnknownCommunity)?;
 
-        let joined = community.join(&command.person);
+        let now = Utc::now();
+        if community.is_banned(&command.person, &now) {
+            return Err(DomainError::BannedFromCommunity.into());
+        }
+
+        let joined = community.join(&command.person, &now);
         if joined {
             self.community_repository.set(&community).await?;
 
@@ -130,6 +207,23 @@ impl SocialUsecase {
         Ok(())
     }
 
+    pub async fn decline_offer(&self, command: DeclineOffer) -> Result<()> {
+        let mut community = self.community_repository
+            .get(&command.community)
+            .await?
+            .ok_or(DomainError::UnknownCommunity)?;
+
+        let declined = community.withdraw_offer(&command.person);
+        if declined {
+            self.community_repository.set(&community).await?;
+
+            let event = MembershipOfferDeclinedV1 { community: command.community, person: command.person };
+            self.event_publisher.publish(&event).await?;
+        }
+
+        Ok(())
+    }
+
     pub async fn leave(&self, command: Leave) -> Result<()> {
         let mut community = self.community_repository
             .get(&command.community)
@@ -147,44 +241,358 @@ impl SocialUsecase {
         Ok(())
     }
 
+    pub async fn remove_member(&self, command: RemoveMember) -> Result<()> {
+        let mut community = self.community_repository
+            .get(&command.community)
+            .await?
+            .ok_or(DomainError::UnknownCommunity)?;
+
+        if !community.is_editor(&command.moderator) {
+            return Err(DomainError::InsufficientPermissions.into());
+        }
+
+        // rule: editors must be demoted before they can be removed
+        if community.is_editor(&command.member) {
+            return Err(DomainError::InsufficientPermissions.into());
+        }
+
+        let removed = community.remove_member(&command.member);
+        if removed {
+            self.community_repository.set(&community).await?;
+
+            let event = MemberRemovedV1 { community: command.community, member: command.member, moderator: command.moderator };
+            self.event_publisher.publish(&event).await?;
+        }
+
+        Ok(())
+    }
+
+    pub async fn ban_user(&self, command: BanUser) -> Result<()> {
+        let mut community = self.community_repository
+            .get(&command.community)
+            .await?
+            .ok_or(DomainError::UnknownCommunity)?;
+
+        if !community.is_editor(&command.moderator) {
+            return Err(DomainError::InsufficientPermissions.into());
+        }
+
+        // rule: editors must be demoted before they can be banned
+        if community.is_editor(&command.user) {
+            return Err(DomainError::InsufficientPermissions.into());
+        }
+
+        let ban = Ban {
+            user: command.user,
+            reason: command.reason,
+            banned_by: command.moderator,
+            banned: Utc::now(),
+            expires: command.expires,
+        };
+
+        let banned = community.ban(ban.clone());
+        if banned {
+            self.community_repository.set(&community).await?;
+
+            let event = UserBannedV1 {
+                community: command.community,
+                user: ban.user,
+                reason: ban.reason,
+                banned: ban.banned,
+                expires: ban.expires,
+                moderator: ban.banned_by,
+            };
+            self.event_publisher.publish(&event).await?;
+        }
+
+        Ok(())
+    }
+
+    pub async fn unban_user(&self, command: UnbanUser) -> Result<()> {
+        let mut community = self.community_repository
+            .get(&command.community)
+            .await?
+            .ok_or(DomainError::UnknownCommunity)?;
+
+        if !community.is_editor(&command.moderator) {
+            return Err(DomainError::InsufficientPermissions.into());
+        }
+
+        let unbanned = community.unban(&command.user);
+        if unbanned {
+            self.community_repository.set(&community).await?;
+
+            let event = UserUnbannedV1 { community: command.community, user: command.user, moderator: command.moderator };
+            self.event_publisher.publish(&event).await?;
+        }
+
+        Ok(())
+    }
+
+    pub async fn mute_member(&self, command: MuteMember) -> Result<()> {
+        let mut community = self.community_repository
+            .get(&command.community)
+            .await?
+            .ok_or(DomainError::UnknownCommunity)?;
+
+        if !community.is_editor(&command.moderator) {
+            return Err(DomainError::InsufficientPermissions.into());
+        }
+
+        // rule: editors must be demoted before they can be muted
+        if community.is_editor(&command.member) {
+            return Err(DomainError::InsufficientPermissions.into());
+        }
+
+        let mute = Mute {
+            member: command.member,
+            muted_by: command.moderator,
+            until: command.until,
+        };
+
+        let muted = community.mute(mute.clone());
+        if muted {
+            self.community_repository.set(&community).await?;
+
+            let event = MemberMutedV1 {
+                community: command.community,
+                member: mute.member,
+                until: mute.until,
+                moderator: mute.muted_by,
+            };
+            self.event_publisher.publish(&event).await?;
+        }
+
+        Ok(())
+    }
+
     // - post
     pub async fn publish_post(&self, command: PublishPost) -> Result<PublishPostResult> {
-        let id = PostId::random();
-        let community = command.community;
-        let text = command.text;
-        let attachments = command.attachments;
-        let author = command.author;
-        let published = Utc::now();
+        let community = self.community_repository
+            .get(&command.community)
+            .await?
+            .ok_or(DomainError::UnknownCommunity)?;
+
+        let post = Post::draft(PostId::random(), command.community, command.text, command.attachments, command.author, Utc::now());
+        let id = post.id.clone();
+        self.release(post, &community, command.announcement, command.scheduled).await?;
+
+        Ok(PublishPostResult {
+            id
+        })
+    }
+
+    pub async fn save_draft(&self, command: SaveDraft) -> Result<SaveDraftResult> {
+        let community = self.community_repository
+            .get(&command.community)
+            .await?
+            .ok_or(DomainError::UnknownCommunity)?;
+
+        let created = Utc::now();
+        let context = post_policy_context(&community, &command.author, &created);
+        DefaultPostPolicy::allow_publish(&context).map_err(DomainError::from)?;
+
+        let post = Post::draft(PostId::random(), command.community, command.text, command.attachments, command.author, created);
+        self.post_repository.set(&post).await?;
+
+        Ok(SaveDraftResult {
+            id: post.id
+        })
+    }
+
+    pub async fn edit_draft(&self, command: EditDraft) -> Result<()> {
+        let mut post = self.post_repository
+            .get(&command.post)
+            .await?
+            .filter(|post| !post.is_published() && !post.is_removed())
+            .ok_or(DomainError::UnknownPost)?;
+
+        // rule: only the author can edit a draft
+        if post.author != command.author {
+            return Err(DomainError::InsufficientPermissions.into());
+        }
 
-        let post = Post::new(id.clone(), community, text, attachments, author, published);
+        post.edit_draft(command.text, command.attachments);
         self.post_repository.set(&post).await?;
 
-        let event = PostPublishedV1 {
+        Ok(())
+    }
+
+    pub async fn publish_draft(&self, command: PublishDraft) -> Result<()> {
+        let post = self.post_repository
+            .get(&command.post)
+            .await?
+            .filter(|post| !post.is_published() && !post.is_removed())
+            .ok_or(DomainError::UnknownPost)?;
+
+        // rule: only the author can publish a draft
+        if post.author != command.author {
+            return Err(DomainError::InsufficientPermissions.into());
+        }
+
+        let community = self.community_repository
+            .get(&post.community)
+            .await?
+            .ok_or(DomainError::UnknownCommunity)?;
+
+        self.release(post, &community, command.announcement, command.scheduled).await
+    }
+
+    pub async fn edit_post(&self, command: EditPost) -> Result<()> {
+        let mut post = self.post_repository
+            .get(&command.post)
+            .await?
+            .filter(|post| post.is_live())
+            .ok_or(DomainError::UnknownPost)?;
+
+        let community = self.community_repository
+            .get(&post.community)
+            .await?
+            .ok_or(DomainError::UnknownCommunity)?;
+
+        let edited = Utc::now();
+        let context = post_policy_context(&community, &command.editor, &edited);
+        DefaultPostPolicy::allow_edit(&context, &post).map_err(DomainError::from)?;
+
+        let language = self.profile_repository.get_language(&command.editor).await?;
+        let content = FilterableContent {
+            community: community.id.clone(),
+            author: command.editor,
+            kind: FilterableContentKind::Post(post.id.clone()),
+            text: command.text.to_string(),
+            language,
+        };
+        let outcome = self.content_filter.check(&content).await?;
+        if let ContentFilterOutcome::Reject(reason) = outcome {
+            return Err(DomainError::ContentRejected(reason).into());
+        }
+
+        let mentioned = post.mentions();
+        post.edit(command.text, edited);
+        self.post_repository.set(&post).await?;
+
+        if let ContentFilterOutcome::Flag(reason) = outcome {
+            self.flag(ReportTarget::Post(post.id.clone()), community.id.clone(), reason).await?;
+        }
+
+        let mentions = post.mentions().into_iter().filter(|user| !mentioned.contains(user)).collect();
+        self.mention(mentions, &post.author, &community.id, &post.id, None, edited).await?;
+
+        let event = PostEditedV1 {
             id: post.id,
-            community: post.community,
             text: post.text,
-            attachments: post.attachments,
-            author: post.author,
-            published: post.published,
+            edited,
         };
         self.event_publisher.publish(&event).await?;
 
-        Ok(PublishPostResult {
-            id
-        })
+        Ok(())
     }
 
     pub async fn remove_post(&self, command: RemovePost) -> Result<()> {
-        let id = command.post;
-        let _post = self.post_repository
-            .get(&id)
+        let mut post = self.post_repository
+            .get(&command.post)
             .await?
+            .filter(|post| !post.is_removed())
             .ok_or(DomainError::UnknownPost)?;
 
-        self.post_repository.remove(&id).await?;
+        let community = self.community_repository
+            .get(&post.community)
+            .await?
+            .ok_or(DomainError::UnknownCommunity)?;
 
-        let event = PostRemovedV1 {
-            id
+        let removed = Utc::now();
+        let context = post_policy_context(&community, &command.remover, &removed);
+        DefaultPostPolicy::allow_remove(&context, &post).map_err(DomainError::from)?;
+
+        let role = if post.author == command.remover {
+            RemoverRole::Author
+        } else {
+            RemoverRole::Moderator
+        };
+
+        let tombstone = Tombstone {
+            removed_by: command.remover,
+            role,
+            reason: command.reason,
+            removed,
+        };
+        if !post.remove(tombstone.clone()) {
+            return Ok(());
+        }
+        self.post_repository.set(&post).await?;
+
+        let event = PostRemovedV2 {
+            id: post.id,
+            tombstone,
+        };
+        self.event_publisher.publish(&event).await?;
+
+        Ok(())
+    }
+
+    pub async fn pin_post(&self, command: PinPost) -> Result<()> {
+        let mut post = self.post_repository
+            .get(&command.post)
+            .await?
+            .filter(|post| post.is_live())
+            .ok_or(DomainError::UnknownPost)?;
+
+        let community = self.community_repository
+            .get(&post.community)
+            .await?
+            .ok_or(DomainError::UnknownCommunity)?;
+
+        let at = Utc::now();
+        let context = post_policy_context(&community, &command.editor, &at);
+        DefaultPostPolicy::allow_pin(&context, &post).map_err(DomainError::from)?;
+
+        if post.is_pinned() {
+            return Ok(());
+        }
+
+        // rule: number of pinned posts per community is limited
+        let pinned = self.post_repository.list_pinned(&community.id).await?;
+        if pinned.len() >= self.settings.max_pinned_posts {
+            return Err(DomainError::PinLimitReached.into());
+        }
+
+        post.pin(at);
+        self.post_repository.set(&post).await?;
+
+        let event = PostPinnedV1 {
+            id: post.id,
+            community: post.community,
+            editor: command.editor,
+            pinned: at,
+        };
+        self.event_publisher.publish(&event).await?;
+
+        Ok(())
+    }
+
+    pub async fn unpin_post(&self, command: UnpinPost) -> Result<()> {
+        let mut post = self.post_repository
+            .get(&command.post)
+            .await?
+            .ok_or(DomainError::UnknownPost)?;
+
+        let community = self.community_repository
+            .get(&post.community)
+            .await?
+            .ok_or(DomainError::UnknownCommunity)?;
+
+        let context = post_policy_context(&community, &command.editor, &Utc::now());
+        DefaultPostPolicy::allow_unpin(&context, &post).map_err(DomainError::from)?;
+
+        if !post.unpin() {
+            return Ok(());
+        }
+        self.post_repository.set(&post).await?;
+
+        let event = PostUnpinnedV1 {
+            id: post.id,
+            community: post.community,
+            editor: command.editor,
         };
         self.event_publisher.publish(&event).await?;
 
@@ -192,14 +600,30 @@ impl SocialUsecase {
     }
 
     pub async fn react_to_post(&self, command: ReactToPost) -> Result<()> {
-        let (_, _, post) = command.reaction.values();
-        if let None = self.post_repository.get(&post).await? {
-            return Err(DomainError::UnknownPost.into());
+        let post = self.post_repository
+            .get(&command.reaction.post)
+            .await?
+            .filter(|post| post.is_live())
+            .ok_or(DomainError::UnknownPost)?;
+
+        let community = self.community_repository
+            .get(&post.community)
+            .await?
+            .ok_or(DomainError::UnknownCommunity)?;
+
+        // rule: only reactions enabled by the community can be used
+        if !community.reactions.allows(&command.reaction.reaction) {
+            return Err(DomainError::UnknownReaction.into());
         }
 
-        let set = self.post_reaction_repository.set(&command.reaction).await?;
+        let record = PostReactionRecord {
+            reaction: command.reaction,
+            reacted: Utc::now(),
+        };
+
+        let set = self.post_reaction_repository.set(&record).await?;
         if set {
-            let event = ReactedToPostV1 { reaction: command.reaction };
+            let event = ReactedToPostV2 { reaction: record.reaction };
             self.event_publisher.publish(&event).await?;
         }
 
@@ -209,29 +633,122 @@ impl SocialUsecase {
     pub async fn retract_postreaction(&self, command: RetractPostReaction) -> Result<()> {
         let unset = self.post_reaction_repository.unset(&command.reaction).await?;
         if unset {
-            let event = PostReactionRetractedV1 { reaction: command.reaction };
+            let event = PostReactionRetractedV2 { reaction: command.reaction };
             self.event_publisher.publish(&event).await?;
         }
 
         Ok(())
     }
 
+    // - poll
+    pub async fn vote_in_poll(&self, command: VoteInPoll) -> Result<()> {
+        let post = self.post_repository
+            .get(&command.post)
+            .await?
+            .filter(|post| post.is_live())
+            .ok_or(DomainError::UnknownPost)?;
+
+        let poll = post.attachments
+            .poll()
+            .ok_or(DomainError::UnknownPoll)?;
+
+        let voted = Utc::now();
+        if poll.is_closed(&voted) {
+            return Err(DomainError::PollClosed.into());
+        }
+
+        if !poll.accepts(&command.choices) {
+            return Err(DomainError::InvalidVote.into());
+        }
+
+        let vote = PollVote {
+            post: post.id.clone(),
+            voter: command.voter,
+            choices: command.choices,
+            voted,
+        };
+
+        // rule: one vote per user, votes are final
+        if !self.poll_vote_repository.set(&vote).await? {
+            return Err(DomainError::AlreadyVoted.into());
+        }
+
+        let event = PollVotedV1 {
+            post: vote.post,
+            voter: Some(vote.voter).filter(|_| poll.visibility == PollVisibility::Public),
+            choices: vote.choices,
+            voted: vote.voted,
+        };
+        self.event_publisher.publish(&event).await?;
+
+        Ok(())
+    }
+
     // - comment
     pub async fn publish_comment(&self, command: PublishComment) -> Result<PublishCommentResult> {
         let id = CommentId::random();
-        let reply_to = command.reply_to;
         let text = command.text;
         let author = command.author;
         let published = Utc::now();
 
-        if let None = self.post_repository.get(&reply_to).await? {
-            return Err(DomainError::UnknownPost.into());
+        let (reply_to, parent) = match command.parent {
+            CommentParent::Post(post) => (post, None),
+            CommentParent::Comment(comment) => {
+                let parent = self.comment_repository
+                    .get(&comment)
+                    .await?
+                    .filter(|comment| !comment.is_removed())
+                    .ok_or(DomainError::UnknownComment)?;
+
+                // rule: replies can only be nested up to the configured depth
+                if parent.depth >= self.settings.max_comment_depth {
+                    return Err(DomainError::CommentDepthExceeded.into());
+                }
+                (parent.reply_to.clone(), Some(parent))
+            },
+        };
+
+        let post = self.post_repository
+            .get(&reply_to)
+            .await?
+            .filter(|post| post.is_live())
+            .ok_or(DomainError::UnknownPost)?;
+
+        let community = self.community_repository
+            .get(&post.community)
+            .await?
+            .ok_or(DomainError::UnknownCommunity)?;
+
+        let context = comment_policy_context(&community, &post.id, &author, &published);
+        DefaultCommentPolicy::allow_publish(&context).map_err(DomainError::from)?;
+
+        let language = self.profile_repository.get_language(&author).await?;
+        let content = FilterableContent {
+            community: community.id.clone(),
+            author: author.clone(),
+            kind: FilterableContentKind::Comment(id.clone()),
+            text: text.to_string(),
+            language,
+        };
+        let outcome = self.content_filter.check(&content).await?;
+        if let ContentFilterOutcome::Reject(reason) = outcome {
+            return Err(DomainError::ContentRejected(reason).into());
         }
 
-        let comment = Comment::new(id.clone(), reply_to, text, author, published);
+        let comment = match parent {
+            Some(parent) => parent.reply(id.clone(), text, author, published),
+            None => Comment::new(id.clone(), reply_to, text, author, published),
+        };
         self.comment_repository.set(&comment).await?;
 
-        let event = CommentPublishedV1 {
+        if let ContentFilterOutcome::Flag(reason) = outcome {
+            self.flag(ReportTarget::Comment(comment.id.clone()), community.id.clone(), reason).await?;
+        }
+
+        self.mention(comment.mentions(), &comment.author, &community.id, &comment.reply_to, Some(&comment.id), comment.published).await?;
+
+        let event = CommentPublishedV2 {
+            parent: comment.parent(),
             id: comment.id,
             reply_to: comment.reply_to,
             text: comment.text,
@@ -245,23 +762,679 @@ impl SocialUsecase {
         })
     }
 
-    pub async fn remove_comment(&self, command: RemoveComment) -> Result<()> {
-        let id = command.comment;
-        let _comment = self.comment_repository
-            .get(&id)
+    pub async fn edit_comment(&self, command: EditComment) -> Result<()> {
+        let mut comment = self.comment_repository
+            .get(&command.comment)
             .await?
+            .filter(|comment| !comment.is_removed())
             .ok_or(DomainError::UnknownComment)?;
 
-        self.comment_repository.remove(&id).await?;
+        let post = self.post_repository
+            .get(&comment.reply_to)
+            .await?
+            .filter(|post| post.is_live())
+            .ok_or(DomainError::UnknownPost)?;
 
-        let event = CommentRemovedV1 {
-            id
+        let community = self.community_repository
+            .get(&post.community)
+            .await?
+            .ok_or(DomainError::UnknownCommunity)?;
+
+        let edited = Utc::now();
+        let context = comment_policy_context(&community, &post.id, &command.editor, &edited);
+        DefaultCommentPolicy::allow_edit(&context, &comment).map_err(DomainError::from)?;
+
+        let language = self.profile_repository.get_language(&command.editor).await?;
+        let content = FilterableContent {
+            community: community.id.clone(),
+            author: command.editor,
+            kind: FilterableContentKind::Comment(comment.id.clone()),
+            text: command.text.to_string(),
+            language,
+        };
+        let outcome = self.content_filter.check(&content).await?;
+        if let ContentFilterOutcome::Reject(reason) = outcome {
+            return Err(DomainError::ContentRejected(reason).into());
+        }
+
+        let mentioned = comment.mentions();
+        comment.edit(command.text, edited);
+        self.comment_repository.set(&comment).await?;
+
+        if let ContentFilterOutcome::Flag(reason) = outcome {
+            self.flag(ReportTarget::Comment(comment.id.clone()), community.id.clone(), reason).await?;
+        }
+
+        let mentions = comment.mentions().into_iter().filter(|user| !mentioned.contains(user)).collect();
+        self.mention(mentions, &comment.author, &community.id, &comment.reply_to, Some(&comment.id), edited).await?;
+
+        let event = CommentEditedV1 {
+            id: comment.id,
+            text: comment.text,
+            edited,
+        };
+        self.event_publisher.publish(&event).await?;
+
+        Ok(())
+    }
+
+    pub async fn remove_comment(&self, command: RemoveComment) -> Result<()> {
+        let mut comment = self.comment_repository
+            .get(&command.comment)
+            .await?
+            .filter(|comment| !comment.is_removed())
+            .ok_or(DomainError::UnknownComment)?;
+
+        let post = self.post_repository
+            .get(&comment.reply_to)
+            .await?
+            .ok_or(DomainError::UnknownPost)?;
+
+        let community = self.community_repository
+            .get(&post.community)
+            .await?
+            .ok_or(DomainError::UnknownCommunity)?;
+
+        let removed = Utc::now();
+        let context = comment_policy_context(&community, &post.id, &command.remover, &removed);
+        DefaultCommentPolicy::allow_remove(&context, &comment).map_err(DomainError::from)?;
+
+        let role = if comment.author == command.remover {
+            RemoverRole::Author
+        } else {
+            RemoverRole::Moderator
+        };
+
+        let tombstone = Tombstone {
+            removed_by: command.remover,
+            role,
+            reason: command.reason,
+            removed,
+        };
+        if !comment.remove(tombstone.clone()) {
+            return Ok(());
+        }
+        self.comment_repository.set(&comment).await?;
+
+        let event = CommentRemovedV2 {
+            id: comment.id,
+            tombstone,
+        };
+        self.event_publisher.publish(&event).await?;
+
+        Ok(())
+    }
+
+    // - comment reaction
+    pub async fn react_to_comment(&self, command: ReactToComment) -> Result<()> {
+        let comment = self.comment_repository
+            .get(&command.reaction.comment)
+            .await?
+            .filter(|comment| !comment.is_removed())
+            .ok_or(DomainError::UnknownComment)?;
+
+        let post = self.post_repository
+            .get(&comment.reply_to)
+            .await?
+            .ok_or(DomainError::UnknownPost)?;
+
+        let community = self.community_repository
+            .get(&post.community)
+            .await?
+            .ok_or(DomainError::UnknownCommunity)?;
+
+        // rule: only reactions enabled by the community can be used
+        if !community.reactions.allows(&command.reaction.reaction) {
+            return Err(DomainError::UnknownReaction.into());
+        }
+
+        let set = self.comment_reaction_repository.set(&command.reaction).await?;
+        if set {
+            let event = ReactedToCommentV2 { reaction: command.reaction };
+            self.event_publisher.publish(&event).await?;
+        }
+
+        Ok(())
+    }
+
+    pub async fn retract_comment_reaction(&self, command: RetractCommentReaction) -> Result<()> {
+        let unset = self.comment_reaction_repository.unset(&command.reaction).await?;
+        if unset {
+            let event = CommentReactionRetractedV2 { reaction: command.reaction };
+            self.event_publisher.publish(&event).await?;
+        }
+
+        Ok(())
+    }
+
+    // - bookmark
+    pub async fn add_bookmark(&self, command: AddBookmark) -> Result<()> {
+        match &command.target {
+            BookmarkTarget::Post(id) => {
+                let post = self.post_repository.get(id).await?;
+                if post.filter(|post| post.is_live()).is_none() {
+                    return Err(DomainError::UnknownPost.into());
+                }
+            },
+            BookmarkTarget::Comment(id) => {
+                let comment = self.comment_repository.get(id).await?;
+                if comment.filter(|comment| !comment.is_removed()).is_none() {
+                    return Err(DomainError::UnknownComment.into());
+                }
+            },
+        }
+
+        let bookmark = Bookmark {
+            owner: command.owner,
+            target: command.target,
+            bookmarked: Utc::now(),
+        };
+
+        let set = self.bookmark_repository.set(&bookmark).await?;
+        if set {
+            let event = BookmarkAddedV1 {
+                owner: bookmark.owner,
+                target: bookmark.target,
+                bookmarked: bookmark.bookmarked,
+            };
+            self.event_publisher.publish(&event).await?;
+        }
+
+        Ok(())
+    }
+
+    pub async fn remove_bookmark(&self, command: RemoveBookmark) -> Result<()> {
+        let unset = self.bookmark_repository.unset(&command.owner, &command.target).await?;
+        if unset {
+            let event = BookmarkRemovedV1 {
+                owner: command.owner,
+                target: command.target,
+            };
+            self.event_publisher.publish(&event).await?;
+        }
+
+        Ok(())
+    }
+
+    // - follow
+    pub async fn follow_user(&self, command: FollowUser) -> Result<()> {
+        // rule: users don't follow themselves
+        if command.follower == command.followee {
+            return Err(DomainError::SelfFollow.into());
+        }
+
+        // rule: blocked users don't follow the blocker; the follow is ignored to keep the block private
+        if self.block_repository.is_blocked(&command.followee, &command.follower).await? {
+            return Ok(());
+        }
+
+        let follow = Follow {
+            follower: command.follower,
+            followee: command.followee,
+            followed: Utc::now(),
+        };
+
+        let set = self.follow_repository.set(&follow).await?;
+        if set {
+            let event = UserFollowedV1 {
+                follower: follow.follower,
+                followee: follow.followee,
+                followed: follow.followed,
+            };
+            self.event_publisher.publish(&event).await?;
+        }
+
+        Ok(())
+    }
+
+    pub async fn unfollow_user(&self, command: UnfollowUser) -> Result<()> {
+        let unset = self.follow_repository.unset(&command.follower, &command.followee).await?;
+        if unset {
+            let event = UserUnfollowedV1 {
+                follower: command.follower,
+                followee: command.followee,
+            };
+            self.event_publisher.publish(&event).await?;
+        }
+
+        Ok(())
+    }
+
+    // - block
+    pub async fn block_user(&self, command: BlockUser) -> Result<()> {
+        // rule: users don't block themselves
+        if command.blocker == command.user {
+            return Err(DomainError::SelfBlock.into());
+        }
+
+        let block = Block {
+            blocker: command.blocker,
+            user: command.user,
+            blocked: Utc::now(),
+        };
+
+        let set = self.block_repository.set(&block).await?;
+        if !set {
+            return Ok(());
+        }
+
+        let event = UserBlockedV1 {
+            blocker: block.blocker.clone(),
+            user: block.user.clone(),
+            blocked: block.blocked,
+        };
+        self.event_publisher.publish(&event).await?;
+
+        // note: blocking ends following in either direction
+        for (follower, followee) in [(&block.blocker, &block.user), (&block.user, &block.blocker)] {
+            if self.follow_repository.unset(follower, followee).await? {
+                let event = UserUnfollowedV1 {
+                    follower: follower.clone(),
+                    followee: followee.clone(),
+                };
+                self.event_publisher.publish(&event).await?;
+            }
+        }
+
+        Ok(())
+    }
+
+    pub async fn unblock_user(&self, command: UnblockUser) -> Result<()> {
+        let unset = self.block_repository.unset(&command.blocker, &command.user).await?;
+        if unset {
+            let event = UserUnblockedV1 {
+                blocker: command.blocker,
+                user: command.user,
+            };
+            self.event_publisher.publish(&event).await?;
+        }
+
+        Ok(())
+    }
+
+    // - word list
+    pub async fn set_word_list(&self, command: SetWordList) -> Result<()> {
+        let community = self.community_repository
+            .get(&command.community)
+            .await?
+            .ok_or(DomainError::UnknownCommunity)?;
+
+        if !community.is_editor(&command.editor) {
+            return Err(DomainError::InsufficientPermissions.into());
+        }
+
+        let list = WordList::new(command.community, command.language, command.action, command.words);
+        self.word_list_repository.set(&list).await?;
+
+        let event = WordListSetV1 {
+            words: list.iter().cloned().collect(),
+            community: list.community,
+            language: list.language,
+            action: list.action,
         };
         self.event_publisher.publish(&event).await?;
 
         Ok(())
     }
 
+    // - report
+    pub async fn report_post(&self, command: ReportPost) -> Result<ReportResult> {
+        let mut post = self.post_repository
+            .get(&command.post)
+            .await?
+            .filter(|post| post.is_live())
+            .ok_or(DomainError::UnknownPost)?;
+
+        let id = ReportId::random();
+        let target = ReportTarget::Post(post.id.clone());
+        let reported = Utc::now();
+        let reporter = command.reporter;
+
+        let mut report = Report::new(id.clone(), target.clone(), post.community.clone(), reporter.clone(), command.category, command.text, reported);
+        self.report_repository.set(&report).await?;
+
+        let event = PostReportedV1 {
+            report: report.id.clone(),
+            post: post.id.clone(),
+            community: report.community.clone(),
+            reporter,
+            category: report.category,
+            text: report.text.clone(),
+            reported: report.reported,
+        };
+        self.event_publisher.publish(&event).await?;
+
+        // rule: posts reported by more than `report_threshold` users are hidden pending review
+        let reporters = self.report_repository.count_reporters(&target).await?;
+        if reporters > self.settings.report_threshold && post.hide() {
+            self.post_repository.set(&post).await?;
+
+            report.record_content_hidden(Utc::now());
+            self.report_repository.set(&report).await?;
+
+            let event = PostHiddenV1 { id: post.id, reporters };
+            self.event_publisher.publish(&event).await?;
+        }
+
+        Ok(ReportResult {
+            id
+        })
+    }
+
+    pub async fn report_comment(&self, command: ReportComment) -> Result<ReportResult> {
+        let comment = self.comment_repository
+            .get(&command.comment)
+            .await?
+            .filter(|comment| !comment.is_removed())
+            .ok_or(DomainError::UnknownComment)?;
+
+        let post = self.post_repository
+            .get(&comment.reply_to)
+            .await?
+            .filter(|post| post.is_live())
+            .ok_or(DomainError::UnknownPost)?;
+
+        let id = ReportId::random();
+        let target = ReportTarget::Comment(comment.id.clone());
+        let reported = Utc::now();
+        let reporter = command.reporter;
+
+        let report = Report::new(id.clone(), target, post.community, reporter.clone(), command.category, command.text, reported);
+        self.report_repository.set(&report).await?;
+
+        let event = CommentReportedV1 {
+            report: report.id,
+            comment: comment.id,
+            community: report.community,
+            reporter,
+            category: report.category,
+            text: report.text,
+            reported: report.reported,
+        };
+        self.event_publisher.publish(&event).await?;
+
+        Ok(ReportResult {
+            id
+        })
+    }
+
+    pub async fn dismiss_report(&self, command: DismissReport) -> Result<()> {
+        let (_, target, mut reports) = self.open_reports_to_resolve(&command.report, &command.moderator).await?;
+
+        let now = Utc::now();
+        let mut dismissed = Vec::new();
+        for report in reports.iter_mut() {
+            if report.dismiss(&command.moderator, now) {
+                self.report_repository.set(report).await?;
+                dismissed.push(report.id.clone());
+            }
+        }
+
+        if dismissed.is_empty() {
+            return Ok(());
+        }
+
+        // dismissing restores auto-hidden posts
+        if let ReportTarget::Post(id) = &target {
+            if let Some(mut post) = self.post_repository.get(id).await? {
+                if post.unhide() {
+                    self.post_repository.set(&post).await?;
+                }
+            }
+        }
+
+        let event = ReportDismissedV1 { reports: dismissed, target, moderator: command.moderator };
+        self.event_publisher.publish(&event).await?;
+
+        Ok(())
+    }
+
+    pub async fn remove_reported_content(&self, command: RemoveReportedContent) -> Result<()> {
+        let (_, target, mut reports) = self.open_reports_to_resolve(&command.report, &command.moderator).await?;
+
+        let now = Utc::now();
+        let mut resolved = Vec::new();
+        for report in reports.iter_mut() {
+            if report.resolve_content_removed(&command.moderator, now) {
+                self.report_repository.set(report).await?;
+                resolved.push(report.id.clone());
+            }
+        }
+
+        if resolved.is_empty() {
+            return Ok(());
+        }
+
+        let tombstone = Tombstone {
+            removed_by: command.moderator.clone(),
+            role: RemoverRole::Moderator,
+            reason: command.reason,
+            removed: now,
+        };
+
+        // note: content the author already removed keeps its original tombstone
+        match &target {
+            ReportTarget::Post(id) => {
+                if let Some(mut post) = self.post_repository.get(id).await? {
+                    if post.remove(tombstone.clone()) {
+                        self.post_repository.set(&post).await?;
+
+                        let event = PostRemovedV2 { id: id.clone(), tombstone };
+                        self.event_publisher.publish(&event).await?;
+                    }
+                }
+            },
+            ReportTarget::Comment(id) => {
+                if let Some(mut comment) = self.comment_repository.get(id).await? {
+                    if comment.remove(tombstone.clone()) {
+                        self.comment_repository.set(&comment).await?;
+
+                        let event = CommentRemovedV2 { id: id.clone(), tombstone };
+                        self.event_publisher.publish(&event).await?;
+                    }
+                }
+            },
+        }
+
+        let event = ReportedContentRemovedV1 { reports: resolved, target, moderator: command.moderator };
+        self.event_publisher.publish(&event).await?;
+
+        Ok(())
+    }
+
+    pub async fn ban_reported_author(&self, command: BanReportedAuthor) -> Result<()> {
+        let (mut community, target, mut reports) = self.open_reports_to_resolve(&command.report, &command.moderator).await?;
+        if reports.is_empty() {
+            return Ok(());
+        }
+
+        let author = match &target {
+            ReportTarget::Post(id) => self.post_repository
+                .get(id)
+                .await?
+                .ok_or(DomainError::UnknownPost)?
+                .author,
+            ReportTarget::Comment(id) => self.comment_repository
+                .get(id)
+                .await?
+                .ok_or(DomainError::UnknownComment)?
+                .author,
+        };
+
+        // rule: editors must be demoted before they can be banned, leaving the reports open
+        if community.is_editor(&author) {
+            return Err(DomainError::InsufficientPermissions.into());
+        }
+
+        let ban = Ban {
+            user: author.clone(),
+            reason: command.reason,
+            banned_by: command.moderator.clone(),
+            banned: Utc::now(),
+            expires: command.expires,
+        };
+
+        community.ban(ban.clone());
+        self.community_repository.set(&community).await?;
+
+        let event = UserBannedV1 {
+            community: community.id,
+            user: ban.user,
+            reason: ban.reason,
+            banned: ban.banned,
+            expires: ban.expires,
+            moderator: ban.banned_by,
+        };
+        self.event_publisher.publish(&event).await?;
+
+        let mut resolved = Vec::new();
+        for report in reports.iter_mut() {
+            if report.resolve_author_banned(&command.moderator, ban.banned) {
+                self.report_repository.set(report).await?;
+                resolved.push(report.id.clone());
+            }
+        }
+
+        let event = ReportedAuthorBannedV1 { reports: resolved, target, author, moderator: command.moderator };
+        self.event_publisher.publish(&event).await?;
+
+        Ok(())
+    }
+
+    // note: publishes a post right away, or schedules it for the publication job
+    async fn release(&self, mut post: Post, community: &Community, announcement: bool, scheduled: Option<DateTime<Utc>>) -> Result<()> {
+        let now = Utc::now();
+
+        let context = post_policy_context(community, &post.author, &now);
+        DefaultPostPolicy::allow_publish(&context).map_err(DomainError::from)?;
+        if announcement {
+            DefaultPostPolicy::allow_announce(&context).map_err(DomainError::from)?;
+        }
+
+        // rule: posts can only be scheduled in the future
+        if scheduled.filter(|at| *at <= now).is_some() {
+            return Err(DomainError::InvalidSchedule.into());
+        }
+
+        let language = self.profile_repository.get_language(&post.author).await?;
+        let content = FilterableContent {
+            community: community.id.clone(),
+            author: post.author.clone(),
+            kind: FilterableContentKind::Post(post.id.clone()),
+            text: post.text.to_string(),
+            language,
+        };
+        let outcome = self.content_filter.check(&content).await?;
+        if let ContentFilterOutcome::Reject(reason) = outcome {
+            return Err(DomainError::ContentRejected(reason).into());
+        }
+
+        if announcement {
+            post.announce(scheduled.unwrap_or(now) + self.settings.announcement_duration);
+        }
+        match scheduled {
+            Some(at) => post.schedule(at),
+            None => post.publish(now),
+        };
+        self.post_repository.set(&post).await?;
+
+        if let ContentFilterOutcome::Flag(reason) = outcome {
+            self.flag(ReportTarget::Post(post.id.clone()), post.community.clone(), reason).await?;
+        }
+
+        match scheduled {
+            Some(at) => {
+                let event = PostScheduledV1 {
+                    id: post.id,
+                    community: post.community,
+                    author: post.author,
+                    scheduled: at,
+                };
+                self.event_publisher.publish(&event).await?;
+            },
+            None => {
+                self.mention(post.mentions(), &post.author, &post.community, &post.id, None, post.published).await?;
+
+                let event = PostPublishedV1 {
+                    id: post.id,
+                    community: post.community,
+                    text: post.text,
+                    attachments: post.attachments,
+                    author: post.author,
+                    published: post.published,
+                };
+                self.event_publisher.publish(&event).await?;
+            },
+        }
+
+        Ok(())
+    }
+
+    async fn mention(&self, users: Vec<UserId>, author: &UserId, community: &CommunityId, post: &PostId, comment: Option<&CommentId>, at: DateTime<Utc>) -> Result<()> {
+        for user in users {
+            // rule: blocked users don't reach the blocker by mentioning them
+            if self.block_repository.is_blocked(&user, author).await? {
+                continue;
+            }
+
+            let event = UserMentionedV1 {
+                user,
+                author: author.clone(),
+                community: community.clone(),
+                post: post.clone(),
+                comment: comment.cloned(),
+                mentioned: at,
+            };
+            self.event_publisher.publish(&event).await?;
+        }
+
+        Ok(())
+    }
+
+    // note: flagged content ends up in the moderation queue as a report without reporter
+    async fn flag(&self, target: ReportTarget, community: CommunityId, reason: ContentFilterReason) -> Result<()> {
+        let category = match reason {
+            ContentFilterReason::BlockedWord(_) => ReportCategory::Other,
+            ContentFilterReason::RepeatedContent => ReportCategory::Spam,
+            ContentFilterReason::ExcessiveLinks(_) => ReportCategory::Spam,
+        };
+        let text = ReportText::parse(reason.to_string().as_str()).unwrap_or_default();
+
+        let report = Report::flagged(ReportId::random(), target, community, category, text, Utc::now());
+        self.report_repository.set(&report).await?;
+
+        let event = ContentFlaggedV1 {
+            report: report.id,
+            target: report.target,
+            community: report.community,
+            reason: reason.to_string(),
+        };
+        self.event_publisher.publish(&event).await?;
+
+        Ok(())
+    }
+
+    // note: resolving a report resolves all open reports of the same content
+    async fn open_reports_to_resolve(&self, id: &ReportId, moderator: &UserId) -> Result<(Community, ReportTarget, Vec<Report>)> {
+        let report = self.report_repository
+            .get(id)
+            .await?
+            .ok_or(DomainError::UnknownReport)?;
+
+        let community = self.community_repository
+            .get(&report.community)
+            .await?
+            .ok_or(DomainError::UnknownCommunity)?;
+
+        if !community.is_editor(moderator) {
+            return Err(DomainError::InsufficientPermissions.into());
+        }
+
+        let reports = self.report_repository.list_open(&report.target).await?;
+
+        Ok((community, report.target, reports))
+    }
+
     // queries
     pub async fn list_communities(&self, context: Option<CommunityContext>, after: Option<CommunityId>) -> Result<Vec<Community>> {
         self.community_repository
@@ -269,16 +1442,182 @@ impl SocialUsecase {
             .map_err(|err| err.into())
     }
 
-    pub async fn list_comments(&self, reply_to: PostId, after: Option<CommentId>) -> Result<Vec<Comment>> {
+    pub async fn list_offered_communities(&self, person: UserId, after: Option<CommunityId>) -> Result<Vec<Community>> {
+        self.community_repository
+            .list_offered(&person, &after).await
+            .map_err(|err| err.into())
+    }
+
+    pub async fn list_comments(&self, reply_to: PostId, viewer: UserId, after: Option<CommentId>, include_removed: bool, replies: u32) -> Result<Vec<CommentListing>> {
+        let replies = replies.min(CommentListing::MAX_REPLY_PREVIEW);
+        self.comment_repository
+            .list(&reply_to, &viewer, &after, include_removed, replies).await
+            .map_err(|err| err.into())
+    }
+
+    pub async fn list_comment_thread(&self, comment: CommentId, viewer: UserId, after: Option<CommentId>, include_removed: bool) -> Result<Vec<CommentListing>> {
         self.comment_repository
-            .list(&reply_to, &after).await
+            .list_thread(&comment, &viewer, &after, include_removed).await
             .map_err(|err| err.into())
     }
 
-    pub async fn list_feed(&self, feed: Feed, after: Option<PostId>) -> Result<FeedFragment> {
+    pub async fn list_reactions(&self, community: CommunityId) -> Result<Vec<ReactionDefinition>> {
+        let community = self.community_repository
+            .get(&community)
+            .await?
+            .ok_or(DomainError::UnknownCommunity)?;
+
+        Ok(community.reactions.definitions())
+    }
+
+    pub fn list_reaction_catalog(&self) -> Vec<ReactionDefinition> {
+        catalog()
+    }
+
+    // note: no reactions means all reactions
+    pub async fn list_post_reactions(&self, post: PostId, reactions: Vec<ReactionKey>, after: Option<UserId>) -> Result<Vec<PostReactionRecord>> {
+        let post = self.post_repository
+            .get(&post)
+            .await?
+            .filter(|post| post.is_live())
+            .ok_or(DomainError::UnknownPost)?;
+
+        self.post_reaction_repository
+            .list(&post.id, &reactions, &after).await
+            .map_err(|err| err.into())
+    }
+
+    pub async fn list_poll_votes(&self, post: PostId, choice: Option<u32>, after: Option<UserId>) -> Result<Vec<PollVote>> {
+        let post = self.post_repository
+            .get(&post)
+            .await?
+            .filter(|post| post.is_live())
+            .ok_or(DomainError::UnknownPost)?;
+
+        let poll = post.attachments
+            .poll()
+            .ok_or(DomainError::UnknownPoll)?;
+
+        // rule: voters of anonymous polls are never disclosed
+        if poll.visibility == PollVisibility::Anonymous {
+            return Err(DomainError::InsufficientPermissions.into());
+        }
+
+        self.poll_vote_repository
+            .list(&post.id, &choice, &after).await
+            .map_err(|err| err.into())
+    }
+
+    pub async fn list_post_revisions(&self, post: PostId, viewer: UserId) -> Result<Vec<PostRevision>> {
+        let post = self.post_repository
+            .get(&post)
+            .await?
+            .ok_or(DomainError::UnknownPost)?;
+
+        let community = self.community_repository
+            .get(&post.community)
+            .await?
+            .ok_or(DomainError::UnknownCommunity)?;
+
+        let context = post_policy_context(&community, &viewer, &Utc::now());
+        DefaultPostPolicy::allow_view_revisions(&context, &post).map_err(DomainError::from)?;
+
+        Ok(post.revisions)
+    }
+
+    pub async fn list_comment_revisions(&self, comment: CommentId, viewer: UserId) -> Result<Vec<CommentRevision>> {
+        let comment = self.comment_repository
+            .get(&comment)
+            .await?
+            .ok_or(DomainError::UnknownComment)?;
+
+        let post = self.post_repository
+            .get(&comment.reply_to)
+            .await?
+            .ok_or(DomainError::UnknownPost)?;
+
+        let community = self.community_repository
+            .get(&post.community)
+            .await?
+            .ok_or(DomainError::UnknownCommunity)?;
+
+        let context = comment_policy_context(&community, &post.id, &viewer, &Utc::now());
+        DefaultCommentPolicy::allow_view_revisions(&context, &comment).map_err(DomainError::from)?;
+
+        Ok(comment.revisions)
+    }
+
+    pub async fn list_reports(&self, editor: UserId, after: Option<ReportId>) -> Result<Vec<Report>> {
+        self.report_repository
+            .list(&editor, &after).await
+            .map_err(|err| err.into())
+    }
+
+    pub async fn list_drafts(&self, author: UserId, after: Option<PostId>) -> Result<Vec<Post>> {
+        self.post_repository
+            .list_drafts(&author, &after).await
+            .map_err(|err| err.into())
+    }
+
+    pub async fn list_posts_by_hashtag(&self, hashtag: Hashtag, viewer: UserId, after: Option<PostId>) -> Result<FeedFragment> {
+        self.feed_repository
+            .list(&Feed::Hashtag(hashtag), &viewer, &after, false).await
+            .map_err(|err| err.into())
+    }
+
+    pub async fn search(&self, query: SearchQuery, kinds: Vec<SearchKind>, viewer: UserId, after: Option<SearchTarget>) -> Result<Vec<SearchHit>> {
+        // rule: no kinds means all kinds
+        let kinds = match kinds.is_empty() {
+            true => vec![SearchKind::Community, SearchKind::Post, SearchKind::Comment],
+            false => kinds,
+        };
+
+        self.search_repository
+            .search(&query, &kinds, &viewer, &after).await
+            .map_err(|err| err.into())
+    }
+
+    pub async fn list_followers(&self, user: UserId, after: Option<UserId>) -> Result<Vec<Follow>> {
+        self.follow_repository
+            .list_followers(&user, &after).await
+            .map_err(|err| err.into())
+    }
+
+    pub async fn list_following(&self, user: UserId, after: Option<UserId>) -> Result<Vec<Follow>> {
+        self.follow_repository
+            .list_following(&user, &after).await
+            .map_err(|err| err.into())
+    }
+
+    pub async fn list_blocked_users(&self, blocker: UserId, after: Option<UserId>) -> Result<Vec<Block>> {
+        self.block_repository
+            .list(&blocker, &after).await
+            .map_err(|err| err.into())
+    }
+
+    pub async fn list_feed(&self, feed: Feed, viewer: UserId, after: Option<PostId>, include_removed: bool) -> Result<FeedFragment> {
         self.feed_repository
-            .list(&feed, &after).await
+            .list(&feed, &viewer, &after, include_removed).await
             .map_err(|err| err.into())
 
     }
-}
\ No newline at end of file
+}
+
+fn post_policy_context(community: &Community, user: &UserId, at: &DateTime<Utc>) -> PostPolicyExecutionContext {
+    PostPolicyExecutionContext {
+        user: user.clone(),
+        community: community.id.clone(),
+        editor: community.is_editor(user),
+        muted: community.is_muted(user, at),
+    }
+}
+
+fn comment_policy_context(community: &Community, post: &PostId, user: &UserId, at: &DateTime<Utc>) -> CommentPolicyExecutionContext {
+    CommentPolicyExecutionContext {
+        user: user.clone(),
+        community: community.id.clone(),
+        post: post.clone(),
+        editor: community.is_editor(user),
+        muted: community.is_muted(user, at),
+    }
+}
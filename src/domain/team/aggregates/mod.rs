@@ -1,7 +1,13 @@
+pub mod player;
+pub mod player_id;
+pub mod shirt_number;
 pub mod team;
 pub mod team_id;
 pub mod team_name;
 
+pub use player::{Player, PlayerPosition, PlayerStatus};
+pub use player_id::PlayerId;
+pub use shirt_number::ShirtNumber;
 pub use team::Team;
 pub use team_id::TeamId;
 pub use team_name::TeamName;
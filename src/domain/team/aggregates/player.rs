@@ -0,0 +1,80 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::domain::fixture::aggregates::PlayerName;
+use crate::domain::team::aggregates::{PlayerId, ShirtNumber, TeamId};
+use crate::domain::account::aggregates::UserId;
+
+#[derive(Serialize, Deserialize)]
+pub struct Player {
+    pub id: PlayerId,
+    pub name: PlayerName,
+    pub user: Option<UserId>, // players without an account are managed by staff only
+    pub team: Option<TeamId>, // none once released
+    pub shirt_number: Option<ShirtNumber>,
+    pub position: PlayerPosition,
+    pub status: PlayerStatus,
+    pub joined: DateTime<Utc>, // joined the current team
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Eq, Clone, Copy)]
+pub enum PlayerPosition {
+    Goalkeeper,
+    Defender,
+    Midfielder,
+    Forward,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Eq, Clone, Copy)]
+pub enum PlayerStatus {
+    Active,
+    Injured,
+    Loaned,
+}
+
+impl Player {
+    pub fn new(id: PlayerId, name: PlayerName, user: Option<UserId>, team: TeamId, shirt_number: Option<ShirtNumber>, position: PlayerPosition, joined: DateTime<Utc>) -> Player {
+        Player {
+            id,
+            name,
+            user,
+            team: Some(team),
+            shirt_number,
+            position,
+            status: PlayerStatus::Active,
+            joined,
+        }
+    }
+
+    pub fn is_released(&self) -> bool {
+        self.team.is_none()
+    }
+
+    pub fn transfer(&mut self, team: &TeamId, shirt_number: Option<ShirtNumber>, at: DateTime<Utc>) {
+        self.team = Some(team.clone());
+        self.shirt_number = shirt_number;
+        self.status = PlayerStatus::Active;
+        self.joined = at;
+    }
+
+    pub fn release(&mut self) {
+        self.team = None;
+        self.shirt_number = None;
+        self.status = PlayerStatus::Active;
+    }
+
+    pub fn set_status(&mut self, status: PlayerStatus) -> bool {
+        let changed = self.status != status;
+        self.status = status;
+
+        changed
+    }
+}
+
+impl PartialEq for Player {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id
+    }
+}
+
+impl Eq for Player {}
@@ -0,0 +1,34 @@
+use std::fmt::{Display, Formatter};
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, PartialEq, Eq, Hash, Clone, Copy)]
+pub struct ShirtNumber {
+    raw: u32,
+}
+
+#[derive(Debug)]
+pub enum ParseError {
+    OutOfRange,
+}
+
+impl ShirtNumber {
+    pub const MIN: u32 = 1;
+    pub const MAX: u32 = 99;
+
+    pub fn parse(input: u32) -> Result<ShirtNumber, ParseError> {
+        match (ShirtNumber::MIN..=ShirtNumber::MAX).contains(&input) {
+            true => Ok(ShirtNumber { raw: input }),
+            false => Err(ParseError::OutOfRange),
+        }
+    }
+
+    pub fn value(&self) -> u32 {
+        self.raw
+    }
+}
+
+impl Display for ShirtNumber {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.raw)
+    }
+}
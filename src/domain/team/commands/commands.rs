@@ -1,5 +1,6 @@
 use crate::domain::club::aggregates::{ClubId};
-use crate::domain::team::aggregates::{TeamId, TeamName};
+use crate::domain::fixture::aggregates::PlayerName;
+use crate::domain::team::aggregates::{PlayerId, PlayerPosition, PlayerStatus, ShirtNumber, TeamId, TeamName};
 use crate::domain::account::aggregates::{UserId};
 
 pub struct New {
@@ -19,4 +20,35 @@ pub struct AddStaffMember {
 pub struct RemoveStaffMember {
     pub team: TeamId,
     pub staff_member: UserId
-}
\ No newline at end of file
+}
+
+pub struct AddPlayer {
+    pub team: TeamId,
+    pub name: PlayerName,
+    pub user: Option<UserId>,
+    pub shirt_number: Option<ShirtNumber>,
+    pub position: PlayerPosition,
+    pub staff_member: UserId,
+}
+
+pub struct AddPlayerResult {
+    pub id: PlayerId,
+}
+
+pub struct TransferPlayer {
+    pub player: PlayerId,
+    pub to: TeamId,
+    pub shirt_number: Option<ShirtNumber>,
+    pub staff_member: UserId,
+}
+
+pub struct ReleasePlayer {
+    pub player: PlayerId,
+    pub staff_member: UserId,
+}
+
+pub struct SetPlayerStatus {
+    pub player: PlayerId,
+    pub status: PlayerStatus,
+    pub staff_member: UserId,
+}
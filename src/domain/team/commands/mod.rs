@@ -1,3 +1,4 @@
+#[allow(clippy::module_inception)]
 pub mod commands;
 
 pub use commands::*;
\ No newline at end of file
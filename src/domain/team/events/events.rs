@@ -1,7 +1,8 @@
 use serde::{Deserialize, Serialize};
 use crate::common::Event;
 use crate::domain::club::aggregates::{ClubId};
-use crate::domain::team::aggregates::{TeamId, TeamName};
+use crate::domain::fixture::aggregates::PlayerName;
+use crate::domain::team::aggregates::{PlayerId, PlayerPosition, PlayerStatus, ShirtNumber, TeamId, TeamName};
 use crate::domain::account::aggregates::UserId;
 
 #[derive(Serialize, Deserialize)]
@@ -39,4 +40,62 @@ impl Event for StaffMemberRemovedFromTeamV1 {
     fn kind(&self) -> &'static str {
         "StaffMemberRemovedFromTeamV1"
     }
-}
\ No newline at end of file
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct PlayerAddedV1 {
+    pub id: PlayerId,
+    pub team: TeamId,
+    pub name: PlayerName,
+    pub user: Option<UserId>,
+    pub shirt_number: Option<ShirtNumber>,
+    pub position: PlayerPosition,
+    pub staff_member: UserId,
+}
+
+impl Event for PlayerAddedV1 {
+    fn kind(&self) -> &'static str {
+        "PlayerAddedV1"
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct PlayerTransferredV1 {
+    pub player: PlayerId,
+    pub from: TeamId,
+    pub to: TeamId,
+    pub shirt_number: Option<ShirtNumber>,
+    pub staff_member: UserId,
+}
+
+impl Event for PlayerTransferredV1 {
+    fn kind(&self) -> &'static str {
+        "PlayerTransferredV1"
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct PlayerReleasedV1 {
+    pub player: PlayerId,
+    pub team: TeamId,
+    pub staff_member: UserId,
+}
+
+impl Event for PlayerReleasedV1 {
+    fn kind(&self) -> &'static str {
+        "PlayerReleasedV1"
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct PlayerStatusChangedV1 {
+    pub player: PlayerId,
+    pub status: PlayerStatus,
+    pub staff_member: UserId,
+}
+
+impl Event for PlayerStatusChangedV1 {
+    fn kind(&self) -> &'static str {
+        "PlayerStatusChangedV1"
+    }
+}
@@ -0,0 +1,9 @@
+use crate::common::RepositoryResult;
+use crate::domain::social::aggregates::Community;
+use crate::domain::team::aggregates::TeamId;
+
+#[tonic::async_trait]
+pub trait CommunityRepository {
+    async fn list_by_team(&self, team: &TeamId) -> RepositoryResult<Vec<Community>>;
+    async fn set(&self, community: &Community) -> RepositoryResult<()>;
+}
@@ -1,5 +1,9 @@
 pub mod team_repository;
 pub mod club_repository;
+pub mod player_repository;
+pub mod community_repository;
 
 pub use team_repository::TeamRepository;
 pub use club_repository::ClubRepository;
+pub use player_repository::PlayerRepository;
+pub use community_repository::CommunityRepository;
@@ -0,0 +1,9 @@
+use crate::common::RepositoryResult;
+use crate::domain::team::aggregates::{Player, PlayerId, TeamId};
+
+#[tonic::async_trait]
+pub trait PlayerRepository {
+    async fn list_by_team(&self, team: &TeamId) -> RepositoryResult<Vec<Player>>;
+    async fn get(&self, id: &PlayerId) -> RepositoryResult<Option<Player>>;
+    async fn set(&self, player: &Player) -> RepositoryResult<()>;
+}
@@ -3,12 +3,22 @@ use std::fmt::Formatter;
 #[derive(Debug)]
 pub enum DomainError {
     UnknownTeam,
+    UnknownPlayer,
+    InsufficientPermissions,
+    PlayerReleased,
+    ShirtNumberTaken,
+    SameTeam,
 }
 
 impl std::fmt::Display for DomainError {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         match self {
             DomainError::UnknownTeam => write!(f, "unknown team"),
+            DomainError::UnknownPlayer => write!(f, "unknown player"),
+            DomainError::InsufficientPermissions => write!(f, "insufficient permissions"),
+            DomainError::PlayerReleased => write!(f, "player released"),
+            DomainError::ShirtNumberTaken => write!(f, "shirt number taken"),
+            DomainError::SameTeam => write!(f, "player already in team"),
         }
     }
 }
@@ -1,30 +1,40 @@
 use std::error::Error;
 use std::sync::Arc;
+use chrono::Utc;
 use crate::common::EventPublisher;
-use crate::domain::team::aggregates::{Team, TeamId};
-use crate::domain::team::commands::{AddStaffMember, New, NewResult, RemoveStaffMember};
-use crate::domain::team::events::{StaffMemberAddedToTeamV1, StaffMemberRemovedFromTeamV1, TeamAddedV1};
-use crate::domain::team::repositories::TeamRepository;
+use crate::domain::account::aggregates::UserId;
+use crate::domain::social::events::{MembershipOfferWithdrawnV1, MembershipOfferedV1};
+use crate::domain::team::aggregates::{Player, PlayerId, ShirtNumber, Team, TeamId};
+use crate::domain::team::commands::{AddPlayer, AddPlayerResult, AddStaffMember, New, NewResult, ReleasePlayer, RemoveStaffMember, SetPlayerStatus, TransferPlayer};
+use crate::domain::team::events::{PlayerAddedV1, PlayerReleasedV1, PlayerStatusChangedV1, PlayerTransferredV1, StaffMemberAddedToTeamV1, StaffMemberRemovedFromTeamV1, TeamAddedV1};
+use crate::domain::team::repositories::{CommunityRepository, PlayerRepository, TeamRepository};
 use crate::domain::team::usecases::DomainError;
 
 pub type Result<T> = std::result::Result<T, Box<dyn Error + Send + Sync>>;
 
 pub struct TeamUsecase {
     team_repository: Box<dyn TeamRepository + Send + Sync>,
+    player_repository: Box<dyn PlayerRepository + Send + Sync>,
+    community_repository: Box<dyn CommunityRepository + Send + Sync>,
     event_publisher: Arc<EventPublisher>,
 }
 
 impl TeamUsecase {
     pub fn build(
         team_repository: Box<dyn TeamRepository + Send + Sync>,
+        player_repository: Box<dyn PlayerRepository + Send + Sync>,
+        community_repository: Box<dyn CommunityRepository + Send + Sync>,
         event_publisher: Arc<EventPublisher>) -> TeamUsecase {
         TeamUsecase {
             team_repository,
+            player_repository,
+            community_repository,
             event_publisher,
         }
     }
 
     // commands
+    #[allow(clippy::new_ret_no_self)]
     pub async fn new(&self, command: New) -> Result<NewResult> {
         let id = TeamId::random();
         let name = command.name;
@@ -74,10 +84,200 @@ impl TeamUsecase {
         Ok(())
     }
 
+    pub async fn add_player(&self, command: AddPlayer) -> Result<AddPlayerResult> {
+        let team = self.staffed(&command.team, &command.staff_member).await?;
+        self.check_shirt_number(&team.id, &command.shirt_number).await?;
+
+        let id = PlayerId::random();
+        let player = Player::new(id.clone(), command.name, command.user, team.id.clone(), command.shirt_number, command.position, Utc::now());
+        self.player_repository.set(&player).await?;
+
+        let event = PlayerAddedV1 {
+            id: player.id.clone(),
+            team: team.id.clone(),
+            name: player.name.clone(),
+            user: player.user.clone(),
+            shirt_number: player.shirt_number,
+            position: player.position,
+            staff_member: command.staff_member,
+        };
+        self.event_publisher.publish(&event).await?;
+
+        if let Some(user) = &player.user {
+            self.offer_membership(&team.id, user).await?;
+        }
+
+        Ok(AddPlayerResult {
+            id,
+        })
+    }
+
+    pub async fn transfer_player(&self, command: TransferPlayer) -> Result<()> {
+        let mut player = self.player(&command.player).await?;
+        let from = player.team.clone().ok_or(DomainError::PlayerReleased)?;
+
+        if from == command.to {
+            return Err(DomainError::SameTeam.into());
+        }
+
+        let teams = [
+            self.team_repository.get(&from).await?.ok_or(DomainError::UnknownTeam)?,
+            self.team_repository.get(&command.to).await?.ok_or(DomainError::UnknownTeam)?,
+        ];
+
+        // rule: staff of either team completes the transfer
+        if !teams.iter().any(|team| team.staff.contains(&command.staff_member)) {
+            return Err(DomainError::InsufficientPermissions.into());
+        }
+
+        self.check_shirt_number(&command.to, &command.shirt_number).await?;
+
+        player.transfer(&command.to, command.shirt_number, Utc::now());
+        self.player_repository.set(&player).await?;
+
+        let event = PlayerTransferredV1 {
+            player: command.player,
+            from: from.clone(),
+            to: command.to.clone(),
+            shirt_number: command.shirt_number,
+            staff_member: command.staff_member,
+        };
+        self.event_publisher.publish(&event).await?;
+
+        if let Some(user) = &player.user {
+            self.withdraw_offers(&from, user).await?;
+            self.offer_membership(&command.to, user).await?;
+        }
+
+        Ok(())
+    }
+
+    pub async fn release_player(&self, command: ReleasePlayer) -> Result<()> {
+        let mut player = self.player(&command.player).await?;
+        let team = player.team.clone().ok_or(DomainError::PlayerReleased)?;
+        self.staffed(&team, &command.staff_member).await?;
+
+        player.release();
+        self.player_repository.set(&player).await?;
+
+        let event = PlayerReleasedV1 {
+            player: command.player,
+            team: team.clone(),
+            staff_member: command.staff_member,
+        };
+        self.event_publisher.publish(&event).await?;
+
+        if let Some(user) = &player.user {
+            self.withdraw_offers(&team, user).await?;
+        }
+
+        Ok(())
+    }
+
+    pub async fn set_player_status(&self, command: SetPlayerStatus) -> Result<()> {
+        let mut player = self.player(&command.player).await?;
+        let team = player.team.clone().ok_or(DomainError::PlayerReleased)?;
+        self.staffed(&team, &command.staff_member).await?;
+
+        if !player.set_status(command.status) {
+            return Ok(());
+        }
+        self.player_repository.set(&player).await?;
+
+        let event = PlayerStatusChangedV1 {
+            player: command.player,
+            status: command.status,
+            staff_member: command.staff_member,
+        };
+        self.event_publisher.publish(&event).await?;
+
+        Ok(())
+    }
+
+    // loads a team on behalf of one of its staff members
+    async fn staffed(&self, id: &TeamId, staff_member: &UserId) -> Result<Team> {
+        let team = self.team_repository
+            .get(id)
+            .await?
+            .ok_or(DomainError::UnknownTeam)?;
+
+        // rule: only staff manage the squad
+        if !team.staff.contains(staff_member) {
+            return Err(DomainError::InsufficientPermissions.into());
+        }
+
+        Ok(team)
+    }
+
+    async fn player(&self, id: &PlayerId) -> Result<Player> {
+        let player = self.player_repository
+            .get(id)
+            .await?
+            .ok_or(DomainError::UnknownPlayer)?;
+
+        Ok(player)
+    }
+
+    async fn check_shirt_number(&self, team: &TeamId, shirt_number: &Option<ShirtNumber>) -> Result<()> {
+        if shirt_number.is_none() {
+            return Ok(());
+        }
+
+        // rule: shirt numbers are unique within a squad
+        let squad = self.player_repository.list_by_team(team).await?;
+        if squad.iter().any(|player| player.shirt_number == *shirt_number) {
+            return Err(DomainError::ShirtNumberTaken.into());
+        }
+
+        Ok(())
+    }
+
+    // note: players with an account are offered membership of the communities of their team
+    async fn offer_membership(&self, team: &TeamId, user: &UserId) -> Result<()> {
+        let now = Utc::now();
+        for mut community in self.community_repository.list_by_team(team).await? {
+            if !community.offer_membership(user, &now) {
+                continue;
+            }
+            self.community_repository.set(&community).await?;
+
+            let event = MembershipOfferedV1 { community: community.id, person: user.clone() };
+            self.event_publisher.publish(&event).await?;
+        }
+
+        Ok(())
+    }
+
+    // note: offers not yet accepted lapse when a player leaves the team
+    async fn withdraw_offers(&self, team: &TeamId, user: &UserId) -> Result<()> {
+        for mut community in self.community_repository.list_by_team(team).await? {
+            if !community.withdraw_offer(user) {
+                continue;
+            }
+            self.community_repository.set(&community).await?;
+
+            let event = MembershipOfferWithdrawnV1 { community: community.id, person: user.clone() };
+            self.event_publisher.publish(&event).await?;
+        }
+
+        Ok(())
+    }
+
     // queries
     pub async fn list_teams(&self, after: Option<TeamId>) -> Result<Vec<Team>> {
         self.team_repository
             .list(&after).await
             .map_err(|err| err.into())
     }
-}
\ No newline at end of file
+
+    pub async fn list_squad(&self, team: TeamId) -> Result<Vec<Player>> {
+        self.team_repository
+            .get(&team)
+            .await?
+            .ok_or(DomainError::UnknownTeam)?;
+
+        self.player_repository
+            .list_by_team(&team).await
+            .map_err(|err| err.into())
+    }
+}
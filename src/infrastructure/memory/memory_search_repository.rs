@@ -0,0 +1,310 @@
+use std::cmp::Ordering;
+use std::sync::RwLock;
+use chrono::Utc;
+
+use crate::common::{RepositoryError, RepositoryResult};
+use crate::domain::account::aggregates::UserId;
+use crate::domain::social::aggregates::{Ban, Block, Comment, Community, CommunityId, Highlight, Post, PostId, SearchHit, SearchKind, SearchQuery, SearchTarget};
+
+// note: in-memory counterpart of the postgres search repository, meant for tests;
+// terms match whole words (case insensitive), rank is the share of matching words
+pub struct MemorySearchRepository {
+    documents: RwLock<Vec<Document>>,
+    blocks: RwLock<Vec<Block>>,
+}
+
+struct Document {
+    target: SearchTarget,
+    community: CommunityId,
+    post: Option<PostId>,
+    texts: Vec<String>, // first text is highlighted, others (e.g. context name) only matched
+    authors: Vec<UserId>, // of the content and the post it replies to, hidden from users blocking any of them
+    bans: Vec<Ban>,
+    live: bool,
+}
+
+impl MemorySearchRepository {
+    pub fn build() -> MemorySearchRepository {
+        MemorySearchRepository { documents: RwLock::new(Vec::new()), blocks: RwLock::new(Vec::new()) }
+    }
+
+    // note: context is the name of the club or team the community belongs to
+    pub fn put_community(&self, community: &Community, context: Option<String>) -> RepositoryResult<()> {
+        let document = Document {
+            target: SearchTarget::Community(community.id.clone()),
+            community: community.id.clone(),
+            post: None,
+            texts: vec![Some(community.name.to_string()), context].into_iter().flatten().collect(),
+            authors: Vec::new(),
+            bans: community.bans.clone(),
+            live: true,
+        };
+
+        self.put(document)?;
+
+        // rule: bans apply to all content of the community
+        let mut documents = self.documents.write().map_err(|_| RepositoryError::StorageError)?;
+        documents
+            .iter_mut()
+            .filter(|document| document.community == community.id)
+            .for_each(|document| document.bans = community.bans.clone());
+
+        Ok(())
+    }
+
+    pub fn put_post(&self, post: &Post) -> RepositoryResult<()> {
+        let bans = self.bans(&post.community)?;
+        self.put(Document {
+            target: SearchTarget::Post(post.id.clone()),
+            community: post.community.clone(),
+            post: Some(post.id.clone()),
+            texts: vec![post.text.to_string()],
+            authors: vec![post.author.clone()],
+            bans,
+            live: post.is_live() && !post.hidden,
+        })
+    }
+
+    // note: comments are put after the post they reply to
+    pub fn put_comment(&self, comment: &Comment) -> RepositoryResult<()> {
+        let (community, live, mut authors) = {
+            let documents = self.documents.read().map_err(|_| RepositoryError::StorageError)?;
+            documents
+                .iter()
+                .find(|document| document.target == SearchTarget::Post(comment.reply_to.clone()))
+                .map(|post| (post.community.clone(), post.live, post.authors.clone()))
+                .ok_or(RepositoryError::UnknownError(String::from("unknown post")))?
+        };
+
+        authors.push(comment.author.clone());
+
+        let bans = self.bans(&community)?;
+        self.put(Document {
+            target: SearchTarget::Comment(comment.id.clone()),
+            community,
+            post: Some(comment.reply_to.clone()),
+            texts: vec![comment.text.to_string()],
+            authors,
+            bans,
+            live: live && !comment.is_removed(),
+        })
+    }
+
+    pub fn put_block(&self, block: &Block) -> RepositoryResult<()> {
+        let mut blocks = self.blocks.write().map_err(|_| RepositoryError::StorageError)?;
+        blocks.retain(|existing| existing.blocker != block.blocker || existing.user != block.user);
+        blocks.push(block.clone());
+        Ok(())
+    }
+
+    fn put(&self, document: Document) -> RepositoryResult<()> {
+        let mut documents = self.documents.write().map_err(|_| RepositoryError::StorageError)?;
+        documents.retain(|existing| existing.target != document.target);
+        documents.push(document);
+        Ok(())
+    }
+
+    fn bans(&self, community: &CommunityId) -> RepositoryResult<Vec<Ban>> {
+        let documents = self.documents.read().map_err(|_| RepositoryError::StorageError)?;
+        Ok(documents
+            .iter()
+            .find(|document| document.target == SearchTarget::Community(community.clone()))
+            .map(|document| document.bans.clone())
+            .unwrap_or_default())
+    }
+}
+
+fn words(text: &str) -> Vec<String> {
+    text.split(|c: char| !c.is_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .map(|word| word.to_lowercase())
+        .collect()
+}
+
+fn rank(texts: &[String], terms: &[String]) -> f32 {
+    let words: Vec<String> = texts.iter().flat_map(|text| words(text)).collect();
+    let matches = words.iter().filter(|word| terms.contains(word)).count();
+    match words.is_empty() {
+        true => 0.0,
+        false => matches as f32 / words.len() as f32,
+    }
+}
+
+fn highlight(text: &str, terms: &[String]) -> Highlight {
+    let mut marked = String::new();
+    let mut word = String::new();
+
+    let flush = |word: &mut String, marked: &mut String| {
+        if terms.contains(&word.to_lowercase()) {
+            marked.push(Highlight::START);
+            marked.push_str(word);
+            marked.push(Highlight::STOP);
+        } else {
+            marked.push_str(word);
+        }
+        word.clear();
+    };
+
+    for c in text.chars() {
+        if c.is_alphanumeric() {
+            word.push(c);
+        } else {
+            flush(&mut word, &mut marked);
+            marked.push(c);
+        }
+    }
+    flush(&mut word, &mut marked);
+
+    Highlight::from_marked(marked.as_str())
+}
+
+#[tonic::async_trait]
+impl crate::domain::social::repositories::SearchRepository for MemorySearchRepository {
+    async fn search(&self, query: &SearchQuery, kinds: &[SearchKind], viewer: &UserId, after: &Option<SearchTarget>) -> RepositoryResult<Vec<SearchHit>> {
+        let terms = query.terms();
+        let now = Utc::now();
+        let documents = self.documents.read().map_err(|_| RepositoryError::StorageError)?;
+        let blocks = self.blocks.read().map_err(|_| RepositoryError::StorageError)?;
+        let blocked: Vec<&UserId> = blocks.iter().filter(|block| block.blocker == *viewer).map(|block| &block.user).collect();
+
+        let mut hits: Vec<SearchHit> = documents
+            .iter()
+            .filter(|document| kinds.contains(&document.target.kind()))
+            .filter(|document| document.live)
+            .filter(|document| !document.bans.iter().any(|ban| ban.user == *viewer && ban.is_active(&now)))
+            .filter(|document| !document.authors.iter().any(|author| blocked.contains(&author)))
+            .map(|document| SearchHit {
+                target: document.target.clone(),
+                community: document.community.clone(),
+                post: document.post.clone(),
+                rank: rank(&document.texts, &terms),
+                highlight: highlight(document.texts.first().map(|text| text.as_str()).unwrap_or_default(), &terms),
+            })
+            .filter(|hit| hit.rank > 0.0)
+            .collect();
+
+        hits.sort_by(|a, b| b.rank
+            .partial_cmp(&a.rank)
+            .unwrap_or(Ordering::Equal)
+            .then_with(|| a.target.values().cmp(&b.target.values())));
+
+        let skip = match after {
+            Some(after) => hits
+                .iter()
+                .position(|hit| hit.target == *after)
+                .map(|position| position + 1)
+                .unwrap_or(hits.len()),
+            None => 0,
+        };
+
+        Ok(hits.into_iter().skip(skip).take(SearchHit::MAX_ELEMENTS).collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration;
+    use crate::domain::club::aggregates::ClubId;
+    use crate::domain::social::aggregates::{CommentId, CommentText, CommunityContext, CommunityName, ModerationReason, PostAttachments, PostText};
+    use crate::domain::social::repositories::SearchRepository;
+
+    fn user(name: &str) -> UserId {
+        UserId::parse(format!("{:0>20}", name).as_str()).unwrap()
+    }
+
+    fn community(name: &str) -> Community {
+        let name = CommunityName::parse(name).unwrap();
+        Community::new(CommunityId::random(), name, CommunityContext::Club(ClubId::random()), Utc::now())
+    }
+
+    fn post(community: &Community, author: &UserId, text: &str) -> Post {
+        let text = PostText::parse(text).unwrap();
+        Post::new(PostId::random(), community.id.clone(), text, PostAttachments::from_vec(Vec::new()), author.clone(), Utc::now())
+    }
+
+    fn comment(post: &Post, author: &UserId, text: &str) -> Comment {
+        Comment::new(CommentId::random(), post.id.clone(), CommentText::parse(text).unwrap(), author.clone(), Utc::now())
+    }
+
+    async fn search(repository: &MemorySearchRepository, query: &str, viewer: &UserId, after: Option<SearchTarget>) -> Vec<SearchHit> {
+        let kinds = [SearchKind::Community, SearchKind::Post, SearchKind::Comment];
+        repository.search(&SearchQuery::parse(query).unwrap(), &kinds, viewer, &after).await.unwrap()
+    }
+
+    fn targets(hits: &[SearchHit]) -> Vec<String> {
+        hits.iter().map(|hit| hit.target.values().1).collect()
+    }
+
+    #[tokio::test]
+    async fn ranks_by_share_of_matching_words() {
+        let repository = MemorySearchRepository::build();
+        let fans = community("Fans");
+        repository.put_community(&fans, None).unwrap();
+        let long = post(&fans, &user("author"), "Tickets for the derby are on sale");
+        let short = post(&fans, &user("author"), "Derby tickets!");
+        repository.put_post(&long).unwrap();
+        repository.put_post(&short).unwrap();
+
+        let hits = search(&repository, "derby tickets", &user("viewer"), None).await;
+
+        assert_eq!(targets(&hits), vec![short.id.to_string(), long.id.to_string()]);
+        assert!(hits[0].rank > hits[1].rank);
+    }
+
+    #[tokio::test]
+    async fn excludes_communities_viewer_is_banned_from() {
+        let repository = MemorySearchRepository::build();
+        let mut fans = community("Derby fans");
+        repository.put_community(&fans, None).unwrap();
+        let derby = post(&fans, &user("author"), "Derby day");
+        repository.put_post(&derby).unwrap();
+        repository.put_comment(&comment(&derby, &user("author"), "What a derby")).unwrap();
+
+        let now = Utc::now();
+        fans.ban(Ban {user: user("banned"), reason: ModerationReason::parse("spam").unwrap(), banned_by: user("editor"), banned: now, expires: None});
+        fans.ban(Ban {user: user("expired"), reason: ModerationReason::parse("spam").unwrap(), banned_by: user("editor"), banned: now, expires: Some(now - Duration::days(1))});
+        repository.put_community(&fans, None).unwrap();
+
+        assert!(search(&repository, "derby", &user("banned"), None).await.is_empty());
+        assert_eq!(search(&repository, "derby", &user("expired"), None).await.len(), 3);
+        assert_eq!(search(&repository, "derby", &user("viewer"), None).await.len(), 3);
+    }
+
+    #[tokio::test]
+    async fn excludes_authors_blocked_by_viewer() {
+        let repository = MemorySearchRepository::build();
+        let fans = community("Fans");
+        repository.put_community(&fans, None).unwrap();
+        let derby = post(&fans, &user("blocked"), "Derby day");
+        repository.put_post(&derby).unwrap();
+        let other = post(&fans, &user("author"), "Derby tickets");
+        repository.put_post(&other).unwrap();
+        repository.put_comment(&comment(&other, &user("blocked"), "Derby tickets here")).unwrap();
+        repository.put_block(&Block { blocker: user("viewer"), user: user("blocked"), blocked: Utc::now() }).unwrap();
+
+        assert_eq!(targets(&search(&repository, "derby", &user("viewer"), None).await), vec![other.id.to_string()]);
+        assert_eq!(search(&repository, "derby", &user("other"), None).await.len(), 3);
+    }
+
+    #[tokio::test]
+    async fn pages_after_cursor() {
+        let repository = MemorySearchRepository::build();
+        let fans = community("Fans");
+        repository.put_community(&fans, None).unwrap();
+        for _ in 0..30 {
+            repository.put_post(&post(&fans, &user("author"), "Derby day")).unwrap();
+        }
+
+        let first = search(&repository, "derby", &user("viewer"), None).await;
+        let after = first.last().map(|hit| hit.target.clone());
+        let second = search(&repository, "derby", &user("viewer"), after).await;
+
+        assert_eq!(first.len(), SearchHit::MAX_ELEMENTS);
+        assert_eq!(second.len(), 30 - SearchHit::MAX_ELEMENTS);
+        let mut all = [targets(&first), targets(&second)].concat();
+        all.sort();
+        all.dedup();
+        assert_eq!(all.len(), 30);
+    }
+}
@@ -0,0 +1,3 @@
+pub mod memory_search_repository;
+
+pub use memory_search_repository::MemorySearchRepository;
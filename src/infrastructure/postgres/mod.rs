@@ -4,8 +4,26 @@ pub mod pg_community_repository;
 pub mod pg_post_repository;
 pub mod pg_comment_repository;
 pub mod pg_post_reaction_repository;
+pub mod pg_poll_vote_repository;
+pub mod pg_comment_reaction_repository;
 pub mod pg_event_repository;
 pub mod pg_feed_repository;
+pub mod pg_report_repository;
+pub mod pg_content_filter_repository;
+pub mod pg_bookmark_repository;
+pub mod pg_follow_repository;
+pub mod pg_block_repository;
+pub mod pg_search_repository;
+pub mod pg_match_repository;
+pub mod pg_competition_repository;
+pub mod pg_season_repository;
+pub mod pg_player_repository;
+pub mod pg_team_event_repository;
+pub mod pg_rsvp_repository;
+pub mod pg_profile_repository;
+pub mod pg_identity_repository;
+pub mod pg_notification_repository;
+pub mod pg_notification_preferences_repository;
 
 pub use pg_club_repository::PgClubRepository;
 pub use pg_team_repository::PgTeamRepository;
@@ -13,13 +31,44 @@ pub use pg_community_repository::PgCommunityRepository;
 pub use pg_post_repository::PgPostRepository;
 pub use pg_comment_repository::PgCommentRepository;
 pub use pg_post_reaction_repository::PgPostReactionRepository;
+pub use pg_poll_vote_repository::PgPollVoteRepository;
+pub use pg_comment_reaction_repository::PgCommentReactionRepository;
 pub use pg_event_repository::PgEventRepository;
 pub use pg_feed_repository::PgFeedRepository;
+pub use pg_report_repository::PgReportRepository;
+pub use pg_content_filter_repository::PgContentFilterRepository;
+pub use pg_bookmark_repository::PgBookmarkRepository;
+pub use pg_follow_repository::PgFollowRepository;
+pub use pg_block_repository::PgBlockRepository;
+pub use pg_search_repository::PgSearchRepository;
+pub use pg_match_repository::PgMatchRepository;
+pub use pg_competition_repository::PgCompetitionRepository;
+pub use pg_season_repository::PgSeasonRepository;
+pub use pg_player_repository::PgPlayerRepository;
+pub use pg_team_event_repository::PgTeamEventRepository;
+pub use pg_rsvp_repository::PgRsvpRepository;
+pub use pg_profile_repository::PgProfileRepository;
+pub use pg_identity_repository::PgIdentityRepository;
+pub use pg_notification_repository::PgNotificationRepository;
+pub use pg_notification_preferences_repository::PgNotificationPreferencesRepository;
 
 // helpers
+use std::collections::HashMap;
+use sqlx::types::Json;
 use crate::common::RepositoryError;
+use crate::domain::social::aggregates::ReactionKey;
 
 fn to_repository_error(_error: sqlx::Error) -> RepositoryError {
     println!("postgres error {:?}", _error);
     RepositoryError::StorageError //TODO include message
 }
+
+fn to_reaction_counts(counts: Option<Json<HashMap<String, i64>>>) -> HashMap<ReactionKey, u64> {
+    // note: keys no longer valid are skipped
+    counts
+        .map(|counts| counts.0)
+        .unwrap_or_default()
+        .into_iter()
+        .filter_map(|(key, count)| Some((ReactionKey::parse(key.as_str()).ok()?, count as u64)))
+        .collect()
+}
@@ -0,0 +1,108 @@
+use sqlx::types::Json;
+use sqlx::{Pool, Postgres};
+
+use crate::common::{RepositoryResult};
+use crate::domain::account::aggregates::UserId;
+use crate::domain::social::aggregates::Block;
+use crate::infrastructure::postgres::to_repository_error;
+
+pub struct PgBlockRepository {
+    pool: Pool<Postgres>,
+}
+
+impl PgBlockRepository {
+    pub fn build(pool: Pool<Postgres>) -> PgBlockRepository {
+        PgBlockRepository { pool }
+    }
+}
+
+#[derive(sqlx::FromRow)]
+struct BlockRow {
+    data: Json<Block>,
+}
+
+#[tonic::async_trait]
+impl crate::domain::social::repositories::BlockRepository for PgBlockRepository {
+    async fn list(&self, blocker: &UserId, after: &Option<UserId>) -> RepositoryResult<Vec<Block>> {
+        let sql = r#"
+            with blocked as (
+                select blocked, data, row_number() over (order by since desc, blocked) row
+                from blocks
+                where blocker = $1
+            )
+
+            select data
+            from blocked
+            where ($2 is null or row > (select coalesce(max(row), bigint_max()) from blocked where blocked = $2))
+            order by row
+            limit 25"#;
+
+        let rows: Vec<BlockRow> = sqlx::query_as(sql)
+            .bind(blocker.to_string())
+            .bind(after.as_ref().map(|user| user.to_string()))
+            .fetch_all(&self.pool)
+            .await
+            .map_err(to_repository_error)?;
+
+        Ok(rows.into_iter().map(|row| row.data.0).collect())
+    }
+
+    async fn is_blocked(&self, blocker: &UserId, user: &UserId) -> RepositoryResult<bool> {
+        let sql = r#"
+              select data
+              from blocks
+              where blocker = $1
+                and blocked = $2
+              limit 1"#;
+
+        let row: Option<BlockRow> = sqlx::query_as(sql)
+            .bind(blocker.to_string())
+            .bind(user.to_string())
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(to_repository_error)?;
+
+        Ok(row.is_some())
+    }
+
+    async fn set(&self, block: &Block) -> RepositoryResult<bool> {
+        let sql = r#"
+               insert into blocks (blocker, blocked, data, since)
+               values ($1, $2, $3, text_to_timestamp($4))
+               on conflict (blocker, blocked) do nothing"#;
+
+        let result = sqlx::query(sql)
+            .bind(block.blocker.to_string())
+            .bind(block.user.to_string())
+            .bind(Json(block))
+            .bind(block.blocked.to_rfc3339())
+            .execute(&self.pool)
+            .await
+            .map_err(to_repository_error)?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    async fn unset(&self, blocker: &UserId, user: &UserId) -> RepositoryResult<bool> {
+        let sql = r#"
+               delete from blocks
+               where blocker = $1
+                 and blocked = $2"#;
+
+        let result = sqlx::query(sql)
+            .bind(blocker.to_string())
+            .bind(user.to_string())
+            .execute(&self.pool)
+            .await
+            .map_err(to_repository_error)?;
+
+        Ok(result.rows_affected() > 0)
+    }
+}
+
+#[tonic::async_trait]
+impl crate::domain::notification::repositories::BlockRepository for PgBlockRepository {
+    async fn is_blocked(&self, blocker: &UserId, user: &UserId) -> RepositoryResult<bool> {
+        crate::domain::social::repositories::BlockRepository::is_blocked(self, blocker, user).await
+    }
+}
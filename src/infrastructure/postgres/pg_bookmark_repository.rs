@@ -0,0 +1,60 @@
+use sqlx::types::Json;
+use sqlx::{Pool, Postgres};
+
+use crate::common::{RepositoryResult};
+use crate::domain::account::aggregates::UserId;
+use crate::domain::social::aggregates::{Bookmark, BookmarkTarget};
+use crate::infrastructure::postgres::to_repository_error;
+
+pub struct PgBookmarkRepository {
+    pool: Pool<Postgres>,
+}
+
+impl PgBookmarkRepository {
+    pub fn build(pool: Pool<Postgres>) -> PgBookmarkRepository {
+        PgBookmarkRepository { pool }
+    }
+}
+
+#[tonic::async_trait]
+impl crate::domain::social::repositories::BookmarkRepository for PgBookmarkRepository {
+    async fn set(&self, bookmark: &Bookmark) -> RepositoryResult<bool> {
+        let sql = r#"
+               insert into bookmarks (owner, target_kind, target_id, data)
+               values ($1, $2, $3, $4)
+               on conflict (owner, target_kind, target_id) do nothing"#;
+
+        let (kind, id) = bookmark.target.values();
+
+        let result = sqlx::query(sql)
+            .bind(bookmark.owner.to_string())
+            .bind(kind)
+            .bind(id)
+            .bind(Json(bookmark))
+            .execute(&self.pool)
+            .await
+            .map_err(to_repository_error)?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    async fn unset(&self, owner: &UserId, target: &BookmarkTarget) -> RepositoryResult<bool> {
+        let sql = r#"
+               delete from bookmarks
+               where owner = $1
+                 and target_kind = $2
+                 and target_id = $3"#;
+
+        let (kind, id) = target.values();
+
+        let result = sqlx::query(sql)
+            .bind(owner.to_string())
+            .bind(kind)
+            .bind(id)
+            .execute(&self.pool)
+            .await
+            .map_err(to_repository_error)?;
+
+        Ok(result.rows_affected() > 0)
+    }
+}
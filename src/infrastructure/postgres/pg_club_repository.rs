@@ -43,6 +43,13 @@ impl crate::domain::team::repositories::ClubRepository for PgClubRepository {
     }
 }
 
+#[tonic::async_trait]
+impl crate::domain::account::repositories::ClubRepository for PgClubRepository {
+    async fn exist(&self, id: &ClubId) -> RepositoryResult<bool> {
+        crate::domain::team::repositories::ClubRepository::exist(self, id).await
+    }
+}
+
 #[tonic::async_trait]
 impl crate::domain::club::repositories::ClubRepository for PgClubRepository {
     async fn list(&self, after: &Option<ClubId>) -> RepositoryResult<Vec<Club>> {
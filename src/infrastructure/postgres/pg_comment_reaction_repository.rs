@@ -0,0 +1,66 @@
+use sqlx::types::Json;
+use sqlx::{Pool, Postgres, Row};
+
+use crate::common::{RepositoryResult};
+use crate::domain::social::aggregates::{CommentReaction};
+use crate::infrastructure::postgres::to_repository_error;
+
+pub struct PgCommentReactionRepository {
+    pool: Pool<Postgres>,
+}
+
+impl PgCommentReactionRepository {
+    pub fn build(pool: Pool<Postgres>) -> PgCommentReactionRepository {
+        PgCommentReactionRepository { pool }
+    }
+}
+
+#[tonic::async_trait]
+impl crate::domain::social::repositories::CommentReactionRepository for PgCommentReactionRepository {
+    async fn set(&self, reaction: &CommentReaction) -> RepositoryResult<bool> {
+        let sql = r#"
+            with current as (
+                select count(0) as existing
+                from comment_reactions
+                where comment = $1 and author = $2 and emotion = $3
+            )
+            , update as (
+                 insert into comment_reactions (comment, author, emotion, data)
+                 values ($1, $2, $3, $4)
+                 on conflict (comment, author) where emotion <> $3 do update set emotion = $3, data = $4
+                 returning 1 as modified
+            )
+            -- returns number of exact duplicates (at most 1)
+            select existing from current, update"#;
+
+        let result: i64 = sqlx::query(sql)
+            .bind(reaction.comment.to_string())
+            .bind(reaction.author.to_string())
+            .bind(reaction.reaction.to_string())
+            .bind(Json(reaction))
+            .fetch_one(&self.pool)
+            .await
+            .map_err(to_repository_error)
+            .map(|row| row.get(0))?;
+
+        Ok(result == 0)
+    }
+
+    async fn unset(&self, reaction: &CommentReaction) -> RepositoryResult<bool> {
+        let sql = r#"
+               delete from comment_reactions
+               where comment = $1
+                 and author = $2
+                 and emotion = $3"#;
+
+        let result = sqlx::query(sql)
+            .bind(reaction.comment.to_string())
+            .bind(reaction.author.to_string())
+            .bind(reaction.reaction.to_string())
+            .execute(&self.pool)
+            .await
+            .map_err(to_repository_error)?;
+
+        Ok(result.rows_affected() > 0)
+    }
+}
\ No newline at end of file
@@ -1,10 +1,13 @@
+use chrono::{DateTime, Utc};
 use sqlx::types::Json;
 use sqlx::{Pool, Postgres};
+use std::collections::HashMap;
 use std::option::Option;
 
 use crate::common::{RepositoryResult};
-use crate::domain::social::aggregates::{Comment, CommentId, PostId};
-use crate::infrastructure::postgres::to_repository_error;
+use crate::domain::account::aggregates::UserId;
+use crate::domain::social::aggregates::{Comment, CommentId, CommentListing, PostId, ReactionKey, ViewerState};
+use crate::infrastructure::postgres::{to_reaction_counts, to_repository_error};
 
 pub struct PgCommentRepository {
     pool: Pool<Postgres>,
@@ -21,29 +24,155 @@ struct CommentRow {
     data: Json<Comment>,
 }
 
+#[derive(sqlx::FromRow)]
+struct CommentListingRow {
+    data: Json<Comment>,
+    replies: i64,
+    reactions: Option<Json<HashMap<String, i64>>>,
+    viewer_reaction: Option<String>,
+    viewer_author: bool,
+    viewer_can_edit: bool,
+    viewer_can_remove: bool,
+    viewer_bookmarked: bool,
+}
+
+impl CommentListingRow {
+    fn into_listing(self) -> CommentListing {
+        CommentListing {
+            comment: self.data.0,
+            replies: self.replies as u64,
+            reactions: to_reaction_counts(self.reactions),
+            viewer: ViewerState {
+                reaction: self.viewer_reaction.and_then(|reaction| ReactionKey::parse(reaction.as_str()).ok()),
+                author: self.viewer_author,
+                can_edit: self.viewer_can_edit,
+                can_remove: self.viewer_can_remove,
+                bookmarked: self.viewer_bookmarked,
+            },
+        }
+    }
+}
+
 #[tonic::async_trait]
 impl crate::domain::social::repositories::CommentRepository for PgCommentRepository {
-    async fn list(&self, reply_to: &PostId, after: &Option<CommentId>) -> RepositoryResult<Vec<Comment>> {
+    async fn list(&self, reply_to: &PostId, viewer: &UserId, after: &Option<CommentId>, include_removed: bool, replies: u32) -> RepositoryResult<Vec<CommentListing>> {
+        // note: pages may end with a reply preview, so a reply as cursor continues after its top-level comment
         let sql = r#"
-            with comments as (
+            with recursive cursor as (
+                select id, parent
+                from comments
+                where id = $2
+                union all
+                select comments.id, comments.parent
+                from comments
+                join cursor on comments.id = cursor.parent
+            ),
+            top as (
                 select id, data, row_number() over (order by published desc) row
                 from comments
                 where reply_to = $1
+                  and parent is null
+                  and ($3 or removed is null)
+                  and not user_blocked($5, author)
+            ),
+            page as (
+                select id, data, row, 0 as reply_row
+                from top
+                where ($2 is null or row > (select coalesce(max(row), bigint_max()) from top where id = (select id from cursor where parent is null)))
+                order by row
+                limit 25
+            ),
+            preview as (
+                select replies.id, replies.data, page.row, row_number() over (partition by replies.parent order by replies.published) reply_row
+                from comments replies
+                join page on replies.parent = page.id
+                where ($3 or replies.removed is null)
+                  and not user_blocked($5, replies.author)
+            ),
+            listing as (
+                select * from page
+                union all
+                select * from preview where reply_row <= $4
             )
 
-            select data
-            from comments
-            where ($2 is null or row > (select coalesce(max(row), bigint_max()) from comments where id = $2))
-            limit 25"#;
+            select
+               listing.data,
+               (select count(1) from comments where parent = listing.id and ($3 or removed is null) and not user_blocked($5, author)) as replies,
+               (select json_object_agg(reaction, reactions) from comment_reactions_stats where comment = listing.id) as reactions,
+               -- viewer state
+               (select emotion from comment_reactions where comment = listing.id and author = $5) as viewer_reaction,
+               (comments.data->'author')->>'raw' = $5 as viewer_author,
+               (comments.data->'author')->>'raw' = $5 and comments.removed is null and not community_member_muted(communities.data, $5) as viewer_can_edit,
+               ((comments.data->'author')->>'raw' = $5 or $5 = any(communities.editors)) and comments.removed is null as viewer_can_remove,
+               exists(select 1 from bookmarks where owner = $5 and target_kind = 'comment' and target_id = listing.id) as viewer_bookmarked
+            from listing
+            join comments on listing.id = comments.id
+            join posts on comments.reply_to = posts.id
+            join communities on posts.community = communities.id
+            order by row, reply_row"#;
 
-        let rows: Vec<CommentRow> = sqlx::query_as(sql)
+        let rows: Vec<CommentListingRow> = sqlx::query_as(sql)
             .bind(reply_to.to_string())
             .bind(after.as_ref().map(|p| p.to_string()))
+            .bind(include_removed)
+            .bind(replies as i64)
+            .bind(viewer.to_string())
             .fetch_all(&self.pool)
             .await
             .map_err(to_repository_error)?;
 
-        Ok(rows.into_iter().map(|row| row.data.0).collect())
+        Ok(rows.into_iter().map(CommentListingRow::into_listing).collect())
+    }
+
+    async fn list_thread(&self, comment: &CommentId, viewer: &UserId, after: &Option<CommentId>, include_removed: bool) -> RepositoryResult<Vec<CommentListing>> {
+        // note: path orders replies depth first, siblings oldest first; replies by blocked users hide their subthread
+        let sql = r#"
+            with recursive thread as (
+                select id, data, array[published] as path
+                from comments
+                where parent = $1
+                  and ($3 or removed is null)
+                  and not user_blocked($4, author)
+                union all
+                select replies.id, replies.data, thread.path || replies.published
+                from comments replies
+                join thread on replies.parent = thread.id
+                where ($3 or replies.removed is null)
+                  and not user_blocked($4, replies.author)
+            ),
+            listing as (
+                select id, data, row_number() over (order by path) row
+                from thread
+            )
+
+            select
+               listing.data,
+               (select count(1) from comments where parent = listing.id and ($3 or removed is null) and not user_blocked($4, author)) as replies,
+               (select json_object_agg(reaction, reactions) from comment_reactions_stats where comment = listing.id) as reactions,
+               -- viewer state
+               (select emotion from comment_reactions where comment = listing.id and author = $4) as viewer_reaction,
+               (comments.data->'author')->>'raw' = $4 as viewer_author,
+               (comments.data->'author')->>'raw' = $4 and comments.removed is null and not community_member_muted(communities.data, $4) as viewer_can_edit,
+               ((comments.data->'author')->>'raw' = $4 or $4 = any(communities.editors)) and comments.removed is null as viewer_can_remove,
+               exists(select 1 from bookmarks where owner = $4 and target_kind = 'comment' and target_id = listing.id) as viewer_bookmarked
+            from listing
+            join comments on listing.id = comments.id
+            join posts on comments.reply_to = posts.id
+            join communities on posts.community = communities.id
+            where ($2 is null or row > (select coalesce(max(row), bigint_max()) from listing where id = $2))
+            order by row
+            limit 25"#;
+
+        let rows: Vec<CommentListingRow> = sqlx::query_as(sql)
+            .bind(comment.to_string())
+            .bind(after.as_ref().map(|p| p.to_string()))
+            .bind(include_removed)
+            .bind(viewer.to_string())
+            .fetch_all(&self.pool)
+            .await
+            .map_err(to_repository_error)?;
+
+        Ok(rows.into_iter().map(CommentListingRow::into_listing).collect())
     }
 
     async fn get(&self, id: &CommentId) -> RepositoryResult<Option<Comment>> {
@@ -64,13 +193,14 @@ impl crate::domain::social::repositories::CommentRepository for PgCommentReposit
 
     async fn set(&self, comment: &Comment) -> RepositoryResult<()> {
         let sql = r#"
-               insert into comments (id, data)
-               values ($1, $2)
-               on conflict (id) do update set data = $2"#;
+               insert into comments (id, data, removed)
+               values ($1, $2, text_to_timestamp($3))
+               on conflict (id) do update set data = $2, removed = text_to_timestamp($3)"#;
 
         sqlx::query(sql)
             .bind(comment.id.to_string())
             .bind(Json(comment))
+            .bind(comment.removed.as_ref().map(|tombstone| tombstone.removed.to_rfc3339()))
             .execute(&self.pool)
             .await
             .map_err(to_repository_error)?;
@@ -78,16 +208,50 @@ impl crate::domain::social::repositories::CommentRepository for PgCommentReposit
         Ok(())
     }
 
-    async fn remove(&self, id: &CommentId) -> RepositoryResult<()> {
-        let sql = r#"delete from comments where id = $1"#;
+    async fn purge(&self, removed_before: &DateTime<Utc>) -> RepositoryResult<Vec<CommentId>> {
+        let sql = r#"
+               with recursive purged as (
+                   select id
+                   from comments
+                   where removed < text_to_timestamp($1)
+                   union
+                   select replies.id
+                   from comments replies
+                   join purged on replies.parent = purged.id
+               ),
+               deleted as (
+                   delete from comments
+                   where id in (select id from purged)
+                   returning id
+               ),
+               purged_reactions as (
+                   delete from comment_reactions
+                   where comment in (select id from deleted)
+               ),
+               purged_bookmarks as (
+                   delete from bookmarks
+                   where target_kind = 'comment'
+                     and target_id in (select id from deleted)
+               )
+               select id
+               from deleted"#;
 
-        sqlx::query(sql)
-            .bind(id.to_string())
-            .execute(&self.pool)
+        let rows: Vec<(String,)> = sqlx::query_as(sql)
+            .bind(removed_before.to_rfc3339())
+            .fetch_all(&self.pool)
             .await
             .map_err(to_repository_error)?;
 
-        Ok(())
+        Ok(rows
+            .into_iter()
+            .filter_map(|(id,)| CommentId::parse(id.as_str()).ok())
+            .collect())
     }
 }
 
+#[tonic::async_trait]
+impl crate::domain::notification::repositories::CommentRepository for PgCommentRepository {
+    async fn get(&self, id: &CommentId) -> RepositoryResult<Option<Comment>> {
+        crate::domain::social::repositories::CommentRepository::get(self, id).await
+    }
+}
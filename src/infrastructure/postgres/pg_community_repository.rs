@@ -6,7 +6,9 @@ use sqlx::{Pool, Postgres};
 use std::option::Option;
 
 use crate::common::{RepositoryResult};
+use crate::domain::account::aggregates::UserId;
 use crate::domain::social::aggregates::{Community, CommunityContext, CommunityId};
+use crate::domain::team::aggregates::TeamId;
 use crate::infrastructure::postgres::to_repository_error;
 
 pub struct PgCommunityRepository {
@@ -29,7 +31,7 @@ impl crate::domain::social::repositories::CommunityRepository for PgCommunityRep
     async fn list(&self, context: &Option<CommunityContext>, after: &Option<CommunityId>) -> RepositoryResult<Vec<Community>> {
         let sql = r#"
             with communities as (
-                select id, data, context_club, context_team, row_number() over (order by name) row
+                select id, data, context_club, context_team, context_competition, row_number() over (order by name) row
                 from communities
             )
 
@@ -38,18 +40,44 @@ impl crate::domain::social::repositories::CommunityRepository for PgCommunityRep
             where ($1 is null or row > (select coalesce(max(row), bigint_max()) from communities where id = $1))
               and ($2 is null or context_club = $2)
               and ($3 is null or context_team = $3)
+              and ($4 is null or context_competition = $4)
             limit 25"#;
 
-        let (club, team) = match context {
-            Some(CommunityContext::Club(id)) => (Some(id.to_string()), None),
-            Some(CommunityContext::Team(id)) => (None, Some(id.to_string())),
-            _ => (None, None)
+        let (club, team, competition) = match context {
+            Some(CommunityContext::Club(id)) => (Some(id.to_string()), None, None),
+            Some(CommunityContext::Team(id)) => (None, Some(id.to_string()), None),
+            Some(CommunityContext::Competition(id)) => (None, None, Some(id.to_string())),
+            _ => (None, None, None)
         };
 
         let rows: Vec<CommunityRow> = sqlx::query_as(sql)
             .bind(after.as_ref().map(|p| p.to_string()))
             .bind(club)
             .bind(team)
+            .bind(competition)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(to_repository_error)?;
+
+        Ok(rows.into_iter().map(|row| row.data.0).collect_vec())
+    }
+
+    async fn list_offered(&self, person: &UserId, after: &Option<CommunityId>) -> RepositoryResult<Vec<Community>> {
+        let sql = r#"
+            with communities as (
+                select id, data, row_number() over (order by name) row
+                from communities
+                where $1 = any(offers)
+            )
+
+            select data
+            from communities
+            where ($2 is null or row > (select coalesce(max(row), bigint_max()) from communities where id = $2))
+            limit 25"#;
+
+        let rows: Vec<CommunityRow> = sqlx::query_as(sql)
+            .bind(person.to_string())
+            .bind(after.as_ref().map(|p| p.to_string()))
             .fetch_all(&self.pool)
             .await
             .map_err(to_repository_error)?;
@@ -75,13 +103,14 @@ impl crate::domain::social::repositories::CommunityRepository for PgCommunityRep
 
     async fn set(&self, community: &Community) -> RepositoryResult<()> {
         let sql = r#"
-               insert into communities (id, data, context_club, context_team)
-               values ($1, $2, $3, $4)
-               on conflict (id) do update set data = $2, context_club = $3, context_team = $4"#;
-
-        let (context_club,context_team) = match &community.context {
-            CommunityContext::Club(id) => (Some(id.to_string()), None),
-            CommunityContext::Team(id) => (None, Some(id.to_string()))
+               insert into communities (id, data, context_club, context_team, context_competition)
+               values ($1, $2, $3, $4, $5)
+               on conflict (id) do update set data = $2, context_club = $3, context_team = $4, context_competition = $5"#;
+
+        let (context_club, context_team, context_competition) = match &community.context {
+            CommunityContext::Club(id) => (Some(id.to_string()), None, None),
+            CommunityContext::Team(id) => (None, Some(id.to_string()), None),
+            CommunityContext::Competition(id) => (None, None, Some(id.to_string())),
         };
 
         sqlx::query(sql)
@@ -89,6 +118,7 @@ impl crate::domain::social::repositories::CommunityRepository for PgCommunityRep
             .bind(Json(community))
             .bind(context_club)
             .bind(context_team)
+            .bind(context_competition)
             .execute(&self.pool)
             .await
             .map_err(to_repository_error)?;
@@ -96,3 +126,44 @@ impl crate::domain::social::repositories::CommunityRepository for PgCommunityRep
         Ok(())
     }
 }
+
+#[tonic::async_trait]
+impl crate::domain::fixture::repositories::CommunityRepository for PgCommunityRepository {
+    async fn list_by_team(&self, team: &TeamId) -> RepositoryResult<Vec<CommunityId>> {
+        let sql = r#"
+              select id
+              from communities
+              where context_team = $1"#;
+
+        let ids: Vec<(String,)> = sqlx::query_as(sql)
+            .bind(team.to_string())
+            .fetch_all(&self.pool)
+            .await
+            .map_err(to_repository_error)?;
+
+        // note: ids no longer valid are skipped
+        Ok(ids.into_iter().filter_map(|(id,)| CommunityId::parse(id.as_str()).ok()).collect_vec())
+    }
+}
+
+#[tonic::async_trait]
+impl crate::domain::team::repositories::CommunityRepository for PgCommunityRepository {
+    async fn list_by_team(&self, team: &TeamId) -> RepositoryResult<Vec<Community>> {
+        let sql = r#"
+              select data
+              from communities
+              where context_team = $1"#;
+
+        let rows: Vec<CommunityRow> = sqlx::query_as(sql)
+            .bind(team.to_string())
+            .fetch_all(&self.pool)
+            .await
+            .map_err(to_repository_error)?;
+
+        Ok(rows.into_iter().map(|row| row.data.0).collect_vec())
+    }
+
+    async fn set(&self, community: &Community) -> RepositoryResult<()> {
+        crate::domain::social::repositories::CommunityRepository::set(self, community).await
+    }
+}
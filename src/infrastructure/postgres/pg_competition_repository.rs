@@ -0,0 +1,80 @@
+use itertools::Itertools;
+use sqlx::types::Json;
+use sqlx::{Pool, Postgres};
+use std::option::Option;
+
+use crate::common::{RepositoryResult};
+use crate::domain::competition::aggregates::{Competition, CompetitionId};
+use crate::infrastructure::postgres::to_repository_error;
+
+pub struct PgCompetitionRepository {
+    pool: Pool<Postgres>,
+}
+
+impl PgCompetitionRepository {
+    pub fn build(pool: Pool<Postgres>) -> PgCompetitionRepository {
+        PgCompetitionRepository { pool }
+    }
+}
+
+#[derive(sqlx::FromRow)]
+struct CompetitionRow {
+    data: Json<Competition>,
+}
+
+#[tonic::async_trait]
+impl crate::domain::competition::repositories::CompetitionRepository for PgCompetitionRepository {
+    async fn list(&self, after: &Option<CompetitionId>) -> RepositoryResult<Vec<Competition>> {
+        let sql = r#"
+            with competitions as (
+                select id, data, row_number() over (order by name, id) row
+                from competitions
+            )
+
+            select data
+            from competitions
+            where ($1 is null or row > (select coalesce(max(row), bigint_max()) from competitions where id = $1))
+            order by row
+            limit 25"#;
+
+        let rows: Vec<CompetitionRow> = sqlx::query_as(sql)
+            .bind(after.as_ref().map(|p| p.to_string()))
+            .fetch_all(&self.pool)
+            .await
+            .map_err(to_repository_error)?;
+
+        Ok(rows.into_iter().map(|row| row.data.0).collect_vec())
+    }
+
+    async fn get(&self, id: &CompetitionId) -> RepositoryResult<Option<Competition>> {
+        let sql = r#"
+              select data
+              from competitions
+              where id = $1
+              limit 1"#;
+
+        let row: Option<CompetitionRow> = sqlx::query_as(sql)
+            .bind(id.to_string())
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(to_repository_error)?;
+
+        Ok(row.map(|columns| columns.data.0))
+    }
+
+    async fn set(&self, competition: &Competition) -> RepositoryResult<()> {
+        let sql = r#"
+               insert into competitions (id, data)
+               values ($1, $2)
+               on conflict (id) do update set data = $2"#;
+
+        sqlx::query(sql)
+            .bind(competition.id.to_string())
+            .bind(Json(competition))
+            .execute(&self.pool)
+            .await
+            .map_err(to_repository_error)?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,103 @@
+use chrono::{DateTime, Utc};
+use itertools::Itertools;
+use sqlx::types::Json;
+use sqlx::{Pool, Postgres};
+
+use crate::common::{RepositoryResult};
+use crate::domain::account::aggregates::UserId;
+use crate::domain::social::aggregates::{CommunityId, WordList};
+use crate::domain::social::filters::FilterableContentKind;
+use crate::infrastructure::postgres::to_repository_error;
+
+#[derive(Clone)]
+pub struct PgContentFilterRepository {
+    pool: Pool<Postgres>,
+}
+
+impl PgContentFilterRepository {
+    pub fn build(pool: Pool<Postgres>) -> PgContentFilterRepository {
+        PgContentFilterRepository { pool }
+    }
+}
+
+#[derive(sqlx::FromRow)]
+struct WordListRow {
+    data: Json<WordList>,
+}
+
+#[derive(sqlx::FromRow)]
+struct TextRow {
+    text: String,
+}
+
+#[tonic::async_trait]
+impl crate::domain::social::repositories::WordListRepository for PgContentFilterRepository {
+    async fn list(&self, community: &CommunityId) -> RepositoryResult<Vec<WordList>> {
+        let sql = r#"
+              select data
+              from word_lists
+              where community = $1"#;
+
+        let rows: Vec<WordListRow> = sqlx::query_as(sql)
+            .bind(community.to_string())
+            .fetch_all(&self.pool)
+            .await
+            .map_err(to_repository_error)?;
+
+        Ok(rows.into_iter().map(|row| row.data.0).collect_vec())
+    }
+
+    async fn set(&self, list: &WordList) -> RepositoryResult<()> {
+        let sql = r#"
+               insert into word_lists (community, language, data)
+               values ($1, $2, $3)
+               on conflict (community, language) do update set data = $3"#;
+
+        sqlx::query(sql)
+            .bind(list.community.to_string())
+            .bind(list.language.to_string())
+            .bind(Json(list))
+            .execute(&self.pool)
+            .await
+            .map_err(to_repository_error)?;
+
+        Ok(())
+    }
+}
+
+#[tonic::async_trait]
+impl crate::domain::social::repositories::ContentHistoryRepository for PgContentFilterRepository {
+    async fn recent_texts(&self, author: &UserId, since: &DateTime<Utc>, except: &FilterableContentKind) -> RepositoryResult<Vec<String>> {
+        // note: drafts, scheduled and removed posts or comments don't count as repeats
+        let sql = r#"
+              select (data -> 'text') ->> 'raw' as text
+              from posts
+              where (data -> 'author') ->> 'raw' = $1
+                and published > text_to_timestamp($2)
+                and status = 'published'
+                and removed is null
+                and id <> $3
+              union all
+              select (data -> 'text') ->> 'raw' as text
+              from comments
+              where (data -> 'author') ->> 'raw' = $1
+                and published > text_to_timestamp($2)
+                and removed is null
+                and id <> $3"#;
+
+        let except = match except {
+            FilterableContentKind::Post(id) => id.to_string(),
+            FilterableContentKind::Comment(id) => id.to_string(),
+        };
+
+        let rows: Vec<TextRow> = sqlx::query_as(sql)
+            .bind(author.to_string())
+            .bind(since.to_rfc3339())
+            .bind(except)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(to_repository_error)?;
+
+        Ok(rows.into_iter().map(|row| row.text).collect_vec())
+    }
+}
@@ -1,6 +1,6 @@
 use sqlx::{Pool, Postgres};
 
-use crate::common::{EventPublisherClient, EventPublishError, RawEvent};
+use crate::common::{EventConsumeError, EventConsumerClient, EventPublisherClient, EventPublishError, RawEvent, StoredEvent};
 
 #[derive(Clone)]
 pub struct PgEventRepository {
@@ -13,6 +13,13 @@ impl PgEventRepository {
     }
 }
 
+#[derive(sqlx::FromRow)]
+struct EventRow {
+    id: i32,
+    kind: String,
+    data: String,
+}
+
 #[tonic::async_trait]
 impl EventPublisherClient for PgEventRepository {
     async fn publish(&self, event: RawEvent) -> Result<(), EventPublishError> {
@@ -29,4 +36,46 @@ impl EventPublisherClient for PgEventRepository {
 
         Ok(())
     }
-}
\ No newline at end of file
+}
+
+#[tonic::async_trait]
+impl EventConsumerClient for PgEventRepository {
+    // note: consumers without a position start at the first event
+    async fn poll(&self, consumer: &str, limit: i64) -> Result<Vec<StoredEvent>, EventConsumeError> {
+        let sql = r#"
+            select id, kind, data::text as data
+            from events
+            where id > coalesce((select position from event_consumers where consumer = $1), 0)
+            order by id
+            limit $2"#;
+
+        let rows: Vec<EventRow> = sqlx::query_as(sql)
+            .bind(consumer)
+            .bind(limit)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(|_| EventConsumeError::PersistentError)?;
+
+        Ok(rows.into_iter().map(|row| StoredEvent {
+            position: row.id as i64,
+            kind: row.kind,
+            data: row.data,
+        }).collect())
+    }
+
+    async fn acknowledge(&self, consumer: &str, position: i64) -> Result<(), EventConsumeError> {
+        let sql = r#"
+               insert into event_consumers (consumer, position)
+               values ($1, $2)
+               on conflict (consumer) do update set position = greatest(event_consumers.position, excluded.position)"#;
+
+        sqlx::query(sql)
+            .bind(consumer)
+            .bind(position as i32)
+            .execute(&self.pool)
+            .await
+            .map_err(|_| EventConsumeError::PersistentError)?;
+
+        Ok(())
+    }
+}
@@ -1,10 +1,13 @@
+use chrono::Utc;
 use sqlx::{Pool, Postgres};
 use std::option::Option;
 use sqlx::types::Json;
+use std::collections::HashMap;
 
 use crate::common::{RepositoryResult};
-use crate::domain::social::aggregates::{Feed, FeedListing, FeedFragment, Post, PostId};
-use crate::infrastructure::postgres::to_repository_error;
+use crate::domain::account::aggregates::UserId;
+use crate::domain::social::aggregates::{Feed, FeedListing, FeedFragment, PollTally, Post, PostId, ReactionKey, ViewerState};
+use crate::infrastructure::postgres::{to_reaction_counts, to_repository_error};
 
 pub struct PgFeedRepository {
     pool: Pool<Postgres>,
@@ -20,89 +23,261 @@ impl PgFeedRepository {
 struct FeedRow {
     post: Json<Post>,
     comments: i64,
-    reactions_love: i64,
-    reactions_funny: i64,
-    reactions_celebrate: i64,
-    reactions_support: i64,
-    reactions_insightful: i64
+    reactions: Option<Json<HashMap<String, i64>>>,
+    viewer_reaction: Option<String>,
+    viewer_author: bool,
+    viewer_can_edit: bool,
+    viewer_can_remove: bool,
+    viewer_bookmarked: bool,
+    poll_votes: Option<Json<HashMap<String, i64>>>,
+    poll_voters: i64,
+    viewer_poll_choices: Option<Json<Vec<u32>>>,
 }
 
+impl FeedRow {
+    fn into_listing(self) -> FeedListing {
+        let votes = self.poll_votes.map(|votes| votes.0).unwrap_or_default();
+        let poll = self.post.attachments.poll().map(|poll| PollTally {
+            votes: (0..poll.options().len())
+                .map(|option| votes.get(&option.to_string()).map(|count| *count as u64).unwrap_or(0))
+                .collect(),
+            voters: self.poll_voters as u64,
+            viewer: self.viewer_poll_choices.map(|choices| choices.0).unwrap_or_default(),
+        });
+
+        FeedListing {
+            post: self.post.0,
+            comments: self.comments as u64,
+            reactions: to_reaction_counts(self.reactions),
+            viewer: ViewerState {
+                reaction: self.viewer_reaction.and_then(|reaction| ReactionKey::parse(reaction.as_str()).ok()),
+                author: self.viewer_author,
+                can_edit: self.viewer_can_edit,
+                can_remove: self.viewer_can_remove,
+                bookmarked: self.viewer_bookmarked,
+            },
+            poll,
+        }
+    }
+}
+
+// note: listing columns shared by all feed queries; the viewer is always bound as $1
+// note: posts by users the viewer blocked are filtered within the feed queries, keeping pagination intact
+const LISTING_COLUMNS: &str = r#"
+       posts.data as post,
+       (select count(1) from comments where reply_to = feed.id) as comments,
+       (select json_object_agg(reaction, reactions) from post_reactions_stats where post = feed.id) as reactions,
+       -- viewer state
+       (select emotion from post_reactions where post = feed.id and author = $1) as viewer_reaction,
+       (posts.data->'author')->>'raw' = $1 as viewer_author,
+       (posts.data->'author')->>'raw' = $1 and posts.removed is null and not community_member_muted(communities.data, $1) as viewer_can_edit,
+       ((posts.data->'author')->>'raw' = $1 or $1 = any(communities.editors)) and posts.removed is null as viewer_can_remove,
+       exists(select 1 from bookmarks where owner = $1 and target_kind = 'post' and target_id = feed.id) as viewer_bookmarked,
+       -- poll tally
+       (select json_object_agg(choice, votes) from (select unnest(choices) as choice, count(*) as votes from poll_votes where post = feed.id group by choice) tally) as poll_votes,
+       (select count(1) from poll_votes where post = feed.id) as poll_voters,
+       (select to_json(choices) from poll_votes where post = feed.id and voter = $1) as viewer_poll_choices"#;
+
 #[tonic::async_trait]
 impl crate::domain::social::repositories::FeedRepository for PgFeedRepository {
-    async fn list(&self, feed: &Feed, after: &Option<PostId>) -> RepositoryResult<FeedFragment> {
-        let rows: Vec<FeedRow> = match feed {
+    async fn list(&self, feed: &Feed, viewer: &UserId, after: &Option<PostId>, include_removed: bool) -> RepositoryResult<FeedFragment> {
+        let now = Utc::now().to_rfc3339();
+
+        let (pinned, rows): (Vec<FeedRow>, Vec<FeedRow>) = match feed {
             Feed::Memberships(user) => {
-                let sql = r#"
+                // note: active announcements top the timeline, instead of appearing in between
+                let pinned_sql = format!(r#"
                     with feed as (
                         select posts.id, row_number() over (order by posts.published desc) row
                         from posts
                         join communities on posts.community = communities.id
-                        where $1 = any(members)
+                        where $2 = any(members)
+                          and not posts.hidden
+                          and not user_blocked($1, posts.author)
+                          and posts.status = 'published'
+                          and posts.removed is null
+                          and posts.announced_until > text_to_timestamp($3)
                         limit 25
                     )
-                    select
-                       (select data from posts as data where id = feed.id) as post,
-                       (select count(1) from comments where reply_to = feed.id) as comments,
-                       -- (select count(1) from post_reactions where post = feed.id) as reactions,
-                       post_reactions_stats.reactions_love,
-                       post_reactions_stats.reactions_funny,
-                       post_reactions_stats.reactions_celebrate,
-                       post_reactions_stats.reactions_support,
-                       post_reactions_stats.reactions_insightful
+                    select {}
+                    from feed
+                    join posts on feed.id = posts.id
+                    join communities on posts.community = communities.id
+                    order by row"#, LISTING_COLUMNS);
+
+                let sql = format!(r#"
+                    with feed as (
+                        select posts.id, row_number() over (order by posts.published desc) row
+                        from posts
+                        join communities on posts.community = communities.id
+                        where $2 = any(members)
+                          and not posts.hidden
+                          and not user_blocked($1, posts.author)
+                          and posts.status = 'published'
+                          and ($4 or posts.removed is null)
+                          and (posts.announced_until is null or posts.announced_until <= text_to_timestamp($5))
+                    )
+                    select {}
                     from feed
-                    join post_reactions_stats on feed.id = post_reactions_stats.post
-                    where ($2 is null or row > (select coalesce(max(row), bigint_max()) from feed where id = $2))"#;
+                    join posts on feed.id = posts.id
+                    join communities on posts.community = communities.id
+                    where ($3 is null or row > (select coalesce(max(row), bigint_max()) from feed where id = $3))
+                    order by row
+                    limit 25"#, LISTING_COLUMNS);
+
+                let pinned = match after {
+                    Some(_) => Vec::new(),
+                    None => sqlx::query_as(pinned_sql.as_str())
+                        .bind(viewer.to_string())
+                        .bind(user.to_string())
+                        .bind(now.as_str())
+                        .fetch_all(&self.pool)
+                        .await
+                        .map_err(to_repository_error)?,
+                };
 
-                sqlx::query_as(sql)
+                let rows = sqlx::query_as(sql.as_str())
+                    .bind(viewer.to_string())
                     .bind(user.to_string())
                     .bind(after.as_ref().map(|p| p.to_string()))
+                    .bind(include_removed)
+                    .bind(now.as_str())
                     .fetch_all(&self.pool)
                     .await
-                    .map_err(to_repository_error)?
+                    .map_err(to_repository_error)?;
+
+                (pinned, rows)
             },
 
             Feed::Community(community) => {
-                let sql = r#"
+                let pinned_sql = format!(r#"
+                    with feed as (
+                        select posts.id, row_number() over (order by posts.pinned desc) row
+                        from posts
+                        where posts.community = $2
+                          and not posts.hidden
+                          and not user_blocked($1, posts.author)
+                          and posts.status = 'published'
+                          and posts.removed is null
+                          and posts.pinned is not null
+                    )
+                    select {}
+                    from feed
+                    join posts on feed.id = posts.id
+                    join communities on posts.community = communities.id
+                    order by row"#, LISTING_COLUMNS);
+
+                let sql = format!(r#"
                     with feed as (
                         select posts.id, posts.data, row_number() over (order by posts.published desc) row
                         from posts
-                        where posts.community = $1
-                        limit 25
+                        where posts.community = $2
+                          and not posts.hidden
+                          and not user_blocked($1, posts.author)
+                          and posts.status = 'published'
+                          and ($4 or posts.removed is null)
+                          and posts.pinned is null
                     )
-                    select
-                       (select data from posts as data where id = feed.id) as post,
-                       (select count(1) from comments where reply_to = feed.id) as comments,
-                       -- (select count(1) from post_reactions where post = feed.id) as reactions,
-                       post_reactions_stats.reactions_love,
-                       post_reactions_stats.reactions_funny,
-                       post_reactions_stats.reactions_celebrate,
-                       post_reactions_stats.reactions_support,
-                       post_reactions_stats.reactions_insightful
+                    select {}
                     from feed
-                    join post_reactions_stats on feed.id = post_reactions_stats.post
-                    where ($2 is null or row > (select coalesce(max(row), bigint_max()) from feed where id = $2))"#;
+                    join posts on feed.id = posts.id
+                    join communities on posts.community = communities.id
+                    where ($3 is null or row > (select coalesce(max(row), bigint_max()) from feed where id = $3))
+                    order by row
+                    limit 25"#, LISTING_COLUMNS);
+
+                let pinned = match after {
+                    Some(_) => Vec::new(),
+                    None => sqlx::query_as(pinned_sql.as_str())
+                        .bind(viewer.to_string())
+                        .bind(community.to_string())
+                        .fetch_all(&self.pool)
+                        .await
+                        .map_err(to_repository_error)?,
+                };
 
-                sqlx::query_as(sql)
+                let rows = sqlx::query_as(sql.as_str())
+                    .bind(viewer.to_string())
                     .bind(community.to_string())
                     .bind(after.as_ref().map(|p| p.to_string()))
+                    .bind(include_removed)
                     .fetch_all(&self.pool)
                     .await
-                    .map_err(to_repository_error)?
+                    .map_err(to_repository_error)?;
+
+                (pinned, rows)
+            },
+
+            Feed::Hashtag(hashtag) => {
+                let sql = format!(r#"
+                    with feed as (
+                        select posts.id, row_number() over (order by posts.published desc) row
+                        from posts
+                        where $2 = any(posts.hashtags)
+                          and not posts.hidden
+                          and not user_blocked($1, posts.author)
+                          and posts.status = 'published'
+                          and ($4 or posts.removed is null)
+                    )
+                    select {}
+                    from feed
+                    join posts on feed.id = posts.id
+                    join communities on posts.community = communities.id
+                    where ($3 is null or row > (select coalesce(max(row), bigint_max()) from feed where id = $3))
+                    order by row
+                    limit 25"#, LISTING_COLUMNS);
+
+                let rows = sqlx::query_as(sql.as_str())
+                    .bind(viewer.to_string())
+                    .bind(hashtag.to_string())
+                    .bind(after.as_ref().map(|p| p.to_string()))
+                    .bind(include_removed)
+                    .fetch_all(&self.pool)
+                    .await
+                    .map_err(to_repository_error)?;
+
+                (Vec::new(), rows)
+            },
+
+            Feed::Following(user) => {
+                // note: posts of communities the viewer is banned from are left out
+                let sql = format!(r#"
+                    with feed as (
+                        select posts.id, row_number() over (order by posts.published desc) row
+                        from posts
+                        join follows on follows.followee = posts.author
+                        join communities on posts.community = communities.id
+                        where follows.follower = $2
+                          and not posts.hidden
+                          and not user_blocked($1, posts.author)
+                          and posts.status = 'published'
+                          and ($4 or posts.removed is null)
+                          and not community_member_banned(communities.data, $1)
+                    )
+                    select {}
+                    from feed
+                    join posts on feed.id = posts.id
+                    join communities on posts.community = communities.id
+                    where ($3 is null or row > (select coalesce(max(row), bigint_max()) from feed where id = $3))
+                    order by row
+                    limit 25"#, LISTING_COLUMNS);
+
+                let rows = sqlx::query_as(sql.as_str())
+                    .bind(viewer.to_string())
+                    .bind(user.to_string())
+                    .bind(after.as_ref().map(|p| p.to_string()))
+                    .bind(include_removed)
+                    .fetch_all(&self.pool)
+                    .await
+                    .map_err(to_repository_error)?;
+
+                (Vec::new(), rows)
             }
         };
 
-        Ok(FeedFragment::from_vec(rows
-            .into_iter()
-            .map(|row| FeedListing {
-                post: row.post.0,
-                comments: row.comments as u64,
-                reactions_love: row.reactions_love as u64,
-                reactions_funny: row.reactions_funny as u64,
-                reactions_celebrate: row.reactions_celebrate as u64,
-                reactions_support: row.reactions_support as u64,
-                reactions_insightful: row.reactions_insightful as u64,
-            })
-            .collect()
+        Ok(FeedFragment::with_pinned(
+            pinned.into_iter().map(FeedRow::into_listing).collect(),
+            rows.into_iter().map(FeedRow::into_listing).collect(),
         ))
     }
-}
\ No newline at end of file
+}
@@ -0,0 +1,107 @@
+use sqlx::types::Json;
+use sqlx::{Pool, Postgres};
+
+use crate::common::{RepositoryResult};
+use crate::domain::account::aggregates::UserId;
+use crate::domain::social::aggregates::Follow;
+use crate::infrastructure::postgres::to_repository_error;
+
+pub struct PgFollowRepository {
+    pool: Pool<Postgres>,
+}
+
+impl PgFollowRepository {
+    pub fn build(pool: Pool<Postgres>) -> PgFollowRepository {
+        PgFollowRepository { pool }
+    }
+}
+
+#[derive(sqlx::FromRow)]
+struct FollowRow {
+    data: Json<Follow>,
+}
+
+#[tonic::async_trait]
+impl crate::domain::social::repositories::FollowRepository for PgFollowRepository {
+    async fn list_followers(&self, followee: &UserId, after: &Option<UserId>) -> RepositoryResult<Vec<Follow>> {
+        let sql = r#"
+            with followers as (
+                select follower, data, row_number() over (order by followed desc, follower) row
+                from follows
+                where followee = $1
+            )
+
+            select data
+            from followers
+            where ($2 is null or row > (select coalesce(max(row), bigint_max()) from followers where follower = $2))
+            order by row
+            limit 25"#;
+
+        let rows: Vec<FollowRow> = sqlx::query_as(sql)
+            .bind(followee.to_string())
+            .bind(after.as_ref().map(|user| user.to_string()))
+            .fetch_all(&self.pool)
+            .await
+            .map_err(to_repository_error)?;
+
+        Ok(rows.into_iter().map(|row| row.data.0).collect())
+    }
+
+    async fn list_following(&self, follower: &UserId, after: &Option<UserId>) -> RepositoryResult<Vec<Follow>> {
+        let sql = r#"
+            with following as (
+                select followee, data, row_number() over (order by followed desc, followee) row
+                from follows
+                where follower = $1
+            )
+
+            select data
+            from following
+            where ($2 is null or row > (select coalesce(max(row), bigint_max()) from following where followee = $2))
+            order by row
+            limit 25"#;
+
+        let rows: Vec<FollowRow> = sqlx::query_as(sql)
+            .bind(follower.to_string())
+            .bind(after.as_ref().map(|user| user.to_string()))
+            .fetch_all(&self.pool)
+            .await
+            .map_err(to_repository_error)?;
+
+        Ok(rows.into_iter().map(|row| row.data.0).collect())
+    }
+
+    async fn set(&self, follow: &Follow) -> RepositoryResult<bool> {
+        let sql = r#"
+               insert into follows (follower, followee, data, followed)
+               values ($1, $2, $3, text_to_timestamp($4))
+               on conflict (follower, followee) do nothing"#;
+
+        let result = sqlx::query(sql)
+            .bind(follow.follower.to_string())
+            .bind(follow.followee.to_string())
+            .bind(Json(follow))
+            .bind(follow.followed.to_rfc3339())
+            .execute(&self.pool)
+            .await
+            .map_err(to_repository_error)?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    async fn unset(&self, follower: &UserId, followee: &UserId) -> RepositoryResult<bool> {
+        let sql = r#"
+               delete from follows
+               where follower = $1
+                 and followee = $2"#;
+
+        let result = sqlx::query(sql)
+            .bind(follower.to_string())
+            .bind(followee.to_string())
+            .execute(&self.pool)
+            .await
+            .map_err(to_repository_error)?;
+
+        Ok(result.rows_affected() > 0)
+    }
+}
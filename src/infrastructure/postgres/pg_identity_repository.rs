@@ -0,0 +1,143 @@
+use itertools::Itertools;
+use sqlx::types::Json;
+use sqlx::{Pool, Postgres};
+
+use crate::common::RepositoryResult;
+use crate::domain::account::aggregates::{Identity, IdentityLink, LinkCode, LinkedIdentity, UserId};
+use crate::infrastructure::postgres::to_repository_error;
+
+pub struct PgIdentityRepository {
+    pool: Pool<Postgres>,
+}
+
+impl PgIdentityRepository {
+    pub fn build(pool: Pool<Postgres>) -> PgIdentityRepository {
+        PgIdentityRepository { pool }
+    }
+}
+
+#[derive(sqlx::FromRow)]
+struct IdentityRow {
+    data: Json<LinkedIdentity>,
+}
+
+#[derive(sqlx::FromRow)]
+struct IdentityLinkRow {
+    data: Json<IdentityLink>,
+}
+
+#[tonic::async_trait]
+impl crate::domain::account::repositories::IdentityRepository for PgIdentityRepository {
+    async fn list_by_account(&self, account: &UserId) -> RepositoryResult<Vec<LinkedIdentity>> {
+        let sql = r#"
+              select data
+              from identities
+              where account = $1
+              order by identity"#;
+
+        let rows: Vec<IdentityRow> = sqlx::query_as(sql)
+            .bind(account.to_string())
+            .fetch_all(&self.pool)
+            .await
+            .map_err(to_repository_error)?;
+
+        Ok(rows.into_iter().map(|row| row.data.0).collect_vec())
+    }
+
+    async fn get(&self, identity: &Identity) -> RepositoryResult<Option<LinkedIdentity>> {
+        let sql = r#"
+              select data
+              from identities
+              where identity = $1
+              limit 1"#;
+
+        let row: Option<IdentityRow> = sqlx::query_as(sql)
+            .bind(identity.to_string())
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(to_repository_error)?;
+
+        Ok(row.map(|columns| columns.data.0))
+    }
+
+    async fn set(&self, identity: &LinkedIdentity) -> RepositoryResult<()> {
+        let sql = r#"
+               insert into identities (identity, account, data)
+               values ($1, $2, $3)
+               on conflict (identity) do update set account = $2, data = $3"#;
+
+        sqlx::query(sql)
+            .bind(identity.identity.to_string())
+            .bind(identity.account.to_string())
+            .bind(Json(identity))
+            .execute(&self.pool)
+            .await
+            .map_err(to_repository_error)?;
+
+        Ok(())
+    }
+
+    async fn unset(&self, identity: &Identity) -> RepositoryResult<bool> {
+        let sql = r#"
+               delete from identities
+               where identity = $1"#;
+
+        let result = sqlx::query(sql)
+            .bind(identity.to_string())
+            .execute(&self.pool)
+            .await
+            .map_err(to_repository_error)?;
+
+        Ok(result.rows_affected() > 0)
+    }
+}
+
+#[tonic::async_trait]
+impl crate::domain::account::repositories::IdentityLinkRepository for PgIdentityRepository {
+    async fn get(&self, code: &LinkCode) -> RepositoryResult<Option<IdentityLink>> {
+        let sql = r#"
+              select data
+              from identity_links
+              where code = $1
+              limit 1"#;
+
+        let row: Option<IdentityLinkRow> = sqlx::query_as(sql)
+            .bind(code.to_string())
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(to_repository_error)?;
+
+        Ok(row.map(|columns| columns.data.0))
+    }
+
+    async fn set(&self, link: &IdentityLink) -> RepositoryResult<()> {
+        let sql = r#"
+               insert into identity_links (code, account, data, expires)
+               values ($1, $2, $3, text_to_timestamp($4))"#;
+
+        sqlx::query(sql)
+            .bind(link.code.to_string())
+            .bind(link.account.to_string())
+            .bind(Json(link))
+            .bind(link.expires.to_rfc3339())
+            .execute(&self.pool)
+            .await
+            .map_err(to_repository_error)?;
+
+        Ok(())
+    }
+
+    async fn unset(&self, code: &LinkCode) -> RepositoryResult<bool> {
+        let sql = r#"
+               delete from identity_links
+               where code = $1"#;
+
+        let result = sqlx::query(sql)
+            .bind(code.to_string())
+            .execute(&self.pool)
+            .await
+            .map_err(to_repository_error)?;
+
+        Ok(result.rows_affected() > 0)
+    }
+}
@@ -0,0 +1,128 @@
+use chrono::{DateTime, Utc};
+use itertools::Itertools;
+use sqlx::types::Json;
+use sqlx::{Pool, Postgres};
+use std::option::Option;
+
+use crate::common::{RepositoryResult};
+use crate::domain::competition::aggregates::{MatchResult, SeasonId};
+use crate::domain::fixture::aggregates::{FixtureScope, Match, MatchId};
+use crate::infrastructure::postgres::to_repository_error;
+
+pub struct PgMatchRepository {
+    pool: Pool<Postgres>,
+}
+
+impl PgMatchRepository {
+    pub fn build(pool: Pool<Postgres>) -> PgMatchRepository {
+        PgMatchRepository { pool }
+    }
+}
+
+#[derive(sqlx::FromRow)]
+struct MatchRow {
+    data: Json<Match>,
+}
+
+#[tonic::async_trait]
+impl crate::domain::fixture::repositories::MatchRepository for PgMatchRepository {
+    async fn list(&self, scope: &FixtureScope, from: &Option<DateTime<Utc>>, until: &Option<DateTime<Utc>>, after: &Option<MatchId>) -> RepositoryResult<Vec<Match>> {
+        let sql = r#"
+            with fixtures as (
+                select matches.id, matches.data, row_number() over (order by matches.kickoff, matches.id) row
+                from matches
+                where ($1 is null or $1 in (matches.home, matches.away))
+                  and ($2 is null or exists(select 1 from teams where teams.id in (matches.home, matches.away) and (teams.data->'club')->>'raw' = $2))
+                  and ($3 is null or matches.kickoff >= text_to_timestamp($3))
+                  and ($4 is null or matches.kickoff < text_to_timestamp($4))
+            )
+
+            select data
+            from fixtures
+            where ($5 is null or row > (select coalesce(max(row), bigint_max()) from fixtures where id = $5))
+            order by row
+            limit 25"#;
+
+        let (team, club) = match scope {
+            FixtureScope::Team(id) => (Some(id.to_string()), None),
+            FixtureScope::Club(id) => (None, Some(id.to_string())),
+        };
+
+        let rows: Vec<MatchRow> = sqlx::query_as(sql)
+            .bind(team)
+            .bind(club)
+            .bind(from.map(|from| from.to_rfc3339()))
+            .bind(until.map(|until| until.to_rfc3339()))
+            .bind(after.as_ref().map(|p| p.to_string()))
+            .fetch_all(&self.pool)
+            .await
+            .map_err(to_repository_error)?;
+
+        Ok(rows.into_iter().map(|row| row.data.0).collect_vec())
+    }
+
+    async fn get(&self, id: &MatchId) -> RepositoryResult<Option<Match>> {
+        let sql = r#"
+              select data
+              from matches
+              where id = $1
+              limit 1"#;
+
+        let row: Option<MatchRow> = sqlx::query_as(sql)
+            .bind(id.to_string())
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(to_repository_error)?;
+
+        Ok(row.map(|columns| columns.data.0))
+    }
+
+    async fn set(&self, fixture: &Match) -> RepositoryResult<()> {
+        let sql = r#"
+               insert into matches (id, data, home, away, kickoff, status, season)
+               values ($1, $2, $3, $4, text_to_timestamp($5), $6, $7)
+               on conflict (id) do update set data = $2, kickoff = text_to_timestamp($5), status = $6, season = $7"#;
+
+        sqlx::query(sql)
+            .bind(fixture.id.to_string())
+            .bind(Json(fixture))
+            .bind(fixture.home.to_string())
+            .bind(fixture.away.to_string())
+            .bind(fixture.kickoff.to_rfc3339())
+            .bind(fixture.status.as_str())
+            .bind(fixture.season.as_ref().map(|season| season.to_string()))
+            .execute(&self.pool)
+            .await
+            .map_err(to_repository_error)?;
+
+        Ok(())
+    }
+}
+
+#[tonic::async_trait]
+impl crate::domain::competition::repositories::ResultRepository for PgMatchRepository {
+    async fn list(&self, season: &SeasonId) -> RepositoryResult<Vec<MatchResult>> {
+        let sql = r#"
+              select data
+              from matches
+              where season = $1
+                and status = 'finished'"#;
+
+        let rows: Vec<MatchRow> = sqlx::query_as(sql)
+            .bind(season.to_string())
+            .fetch_all(&self.pool)
+            .await
+            .map_err(to_repository_error)?;
+
+        // note: finished matches without a score do not count towards the standings
+        Ok(rows
+            .into_iter()
+            .map(|row| row.data.0)
+            .filter_map(|fixture| Some(MatchResult {
+                score: fixture.score?,
+                home: fixture.home,
+                away: fixture.away,
+            }))
+            .collect_vec())
+    }
+}
@@ -0,0 +1,57 @@
+use sqlx::types::Json;
+use sqlx::{Pool, Postgres};
+
+use crate::common::RepositoryResult;
+use crate::domain::account::aggregates::UserId;
+use crate::domain::notification::aggregates::NotificationPreferences;
+use crate::infrastructure::postgres::to_repository_error;
+
+pub struct PgNotificationPreferencesRepository {
+    pool: Pool<Postgres>,
+}
+
+impl PgNotificationPreferencesRepository {
+    pub fn build(pool: Pool<Postgres>) -> PgNotificationPreferencesRepository {
+        PgNotificationPreferencesRepository { pool }
+    }
+}
+
+#[derive(sqlx::FromRow)]
+struct NotificationPreferencesRow {
+    data: Json<NotificationPreferences>,
+}
+
+#[tonic::async_trait]
+impl crate::domain::notification::repositories::NotificationPreferencesRepository for PgNotificationPreferencesRepository {
+    async fn get(&self, user: &UserId) -> RepositoryResult<Option<NotificationPreferences>> {
+        let sql = r#"
+              select data
+              from notification_preferences
+              where id = $1
+              limit 1"#;
+
+        let row: Option<NotificationPreferencesRow> = sqlx::query_as(sql)
+            .bind(user.to_string())
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(to_repository_error)?;
+
+        Ok(row.map(|columns| columns.data.0))
+    }
+
+    async fn set(&self, preferences: &NotificationPreferences) -> RepositoryResult<()> {
+        let sql = r#"
+               insert into notification_preferences (id, data)
+               values ($1, $2)
+               on conflict (id) do update set data = $2"#;
+
+        sqlx::query(sql)
+            .bind(preferences.user.to_string())
+            .bind(Json(preferences))
+            .execute(&self.pool)
+            .await
+            .map_err(to_repository_error)?;
+
+        Ok(())
+    }
+}
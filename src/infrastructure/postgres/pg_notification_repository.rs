@@ -0,0 +1,142 @@
+use itertools::Itertools;
+use sqlx::types::Json;
+use sqlx::{Pool, Postgres, Row};
+
+use crate::common::RepositoryResult;
+use crate::domain::account::aggregates::UserId;
+use crate::domain::notification::aggregates::{Notification, NotificationId};
+use crate::infrastructure::postgres::to_repository_error;
+
+pub struct PgNotificationRepository {
+    pool: Pool<Postgres>,
+}
+
+impl PgNotificationRepository {
+    pub fn build(pool: Pool<Postgres>) -> PgNotificationRepository {
+        PgNotificationRepository { pool }
+    }
+}
+
+#[derive(sqlx::FromRow)]
+struct NotificationRow {
+    data: Json<Notification>,
+}
+
+#[tonic::async_trait]
+impl crate::domain::notification::repositories::NotificationRepository for PgNotificationRepository {
+    async fn list(&self, recipient: &UserId, unread_only: bool, after: &Option<NotificationId>) -> RepositoryResult<Vec<Notification>> {
+        let sql = r#"
+            with inbox as (
+                select id, data, row_number() over (order by updated desc, id) row
+                from notifications
+                where recipient = $1
+                  and (not $3 or not read)
+            )
+
+            select data
+            from inbox
+            where ($2 is null or row > (select coalesce(max(row), bigint_max()) from inbox where id = $2))
+            order by row
+            limit 25"#;
+
+        let rows: Vec<NotificationRow> = sqlx::query_as(sql)
+            .bind(recipient.to_string())
+            .bind(after.as_ref().map(|id| id.to_string()))
+            .bind(unread_only)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(to_repository_error)?;
+
+        Ok(rows.into_iter().map(|row| row.data.0).collect())
+    }
+
+    async fn count_unread(&self, recipient: &UserId) -> RepositoryResult<u64> {
+        let sql = r#"
+              select count(1)
+              from notifications
+              where recipient = $1
+                and not read"#;
+
+        let result: i64 = sqlx::query(sql)
+            .bind(recipient.to_string())
+            .fetch_one(&self.pool)
+            .await
+            .map_err(to_repository_error)
+            .map(|row| row.get(0))?;
+
+        Ok(result as u64)
+    }
+
+    async fn get_unread(&self, recipient: &UserId, key: &str) -> RepositoryResult<Option<Notification>> {
+        let sql = r#"
+              select data
+              from notifications
+              where recipient = $1
+                and key = $2
+                and not read
+              limit 1"#;
+
+        let row: Option<NotificationRow> = sqlx::query_as(sql)
+            .bind(recipient.to_string())
+            .bind(key)
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(to_repository_error)?;
+
+        Ok(row.map(|columns| columns.data.0))
+    }
+
+    async fn set(&self, notification: &Notification) -> RepositoryResult<()> {
+        let sql = r#"
+               insert into notifications (id, recipient, key, data, updated, read)
+               values ($1, $2, $3, $4, text_to_timestamp($5), $6)
+               on conflict (id) do update set data = excluded.data, updated = excluded.updated, read = excluded.read"#;
+
+        sqlx::query(sql)
+            .bind(notification.id.to_string())
+            .bind(notification.recipient.to_string())
+            .bind(notification.kind.key())
+            .bind(Json(notification))
+            .bind(notification.updated.to_rfc3339())
+            .bind(notification.read)
+            .execute(&self.pool)
+            .await
+            .map_err(to_repository_error)?;
+
+        Ok(())
+    }
+
+    async fn mark_read(&self, recipient: &UserId, ids: &[NotificationId]) -> RepositoryResult<u64> {
+        let sql = r#"
+               update notifications
+               set read = true, data = (data::jsonb || '{"read": true}')::json
+               where recipient = $1
+                 and id = any($2)
+                 and not read"#;
+
+        let result = sqlx::query(sql)
+            .bind(recipient.to_string())
+            .bind(ids.iter().map(|id| id.to_string()).collect_vec())
+            .execute(&self.pool)
+            .await
+            .map_err(to_repository_error)?;
+
+        Ok(result.rows_affected())
+    }
+
+    async fn mark_all_read(&self, recipient: &UserId) -> RepositoryResult<u64> {
+        let sql = r#"
+               update notifications
+               set read = true, data = (data::jsonb || '{"read": true}')::json
+               where recipient = $1
+                 and not read"#;
+
+        let result = sqlx::query(sql)
+            .bind(recipient.to_string())
+            .execute(&self.pool)
+            .await
+            .map_err(to_repository_error)?;
+
+        Ok(result.rows_affected())
+    }
+}
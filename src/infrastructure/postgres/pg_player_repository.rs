@@ -0,0 +1,85 @@
+use itertools::Itertools;
+use sqlx::types::Json;
+use sqlx::{Pool, Postgres};
+use std::option::Option;
+
+use crate::common::{RepositoryResult};
+use crate::domain::team::aggregates::{Player, PlayerId, TeamId};
+use crate::infrastructure::postgres::to_repository_error;
+
+pub struct PgPlayerRepository {
+    pool: Pool<Postgres>,
+}
+
+impl PgPlayerRepository {
+    pub fn build(pool: Pool<Postgres>) -> PgPlayerRepository {
+        PgPlayerRepository { pool }
+    }
+}
+
+#[derive(sqlx::FromRow)]
+struct PlayerRow {
+    data: Json<Player>,
+}
+
+#[tonic::async_trait]
+impl crate::domain::team::repositories::PlayerRepository for PgPlayerRepository {
+    async fn list_by_team(&self, team: &TeamId) -> RepositoryResult<Vec<Player>> {
+        // note: squads are small enough to be listed at once
+        let sql = r#"
+              select data
+              from players
+              where team = $1
+              order by shirt_number nulls last, name, id"#;
+
+        let rows: Vec<PlayerRow> = sqlx::query_as(sql)
+            .bind(team.to_string())
+            .fetch_all(&self.pool)
+            .await
+            .map_err(to_repository_error)?;
+
+        Ok(rows.into_iter().map(|row| row.data.0).collect_vec())
+    }
+
+    async fn get(&self, id: &PlayerId) -> RepositoryResult<Option<Player>> {
+        let sql = r#"
+              select data
+              from players
+              where id = $1
+              limit 1"#;
+
+        let row: Option<PlayerRow> = sqlx::query_as(sql)
+            .bind(id.to_string())
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(to_repository_error)?;
+
+        Ok(row.map(|columns| columns.data.0))
+    }
+
+    async fn set(&self, player: &Player) -> RepositoryResult<()> {
+        let sql = r#"
+               insert into players (id, data, name, team, shirt_number)
+               values ($1, $2, $3, $4, $5)
+               on conflict (id) do update set data = $2, name = $3, team = $4, shirt_number = $5"#;
+
+        sqlx::query(sql)
+            .bind(player.id.to_string())
+            .bind(Json(player))
+            .bind(player.name.to_string())
+            .bind(player.team.as_ref().map(|team| team.to_string()))
+            .bind(player.shirt_number.map(|number| number.value() as i32))
+            .execute(&self.pool)
+            .await
+            .map_err(to_repository_error)?;
+
+        Ok(())
+    }
+}
+
+#[tonic::async_trait]
+impl crate::domain::calendar::repositories::PlayerRepository for PgPlayerRepository {
+    async fn list_by_team(&self, team: &TeamId) -> RepositoryResult<Vec<Player>> {
+        crate::domain::team::repositories::PlayerRepository::list_by_team(self, team).await
+    }
+}
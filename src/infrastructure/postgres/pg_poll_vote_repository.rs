@@ -0,0 +1,93 @@
+use chrono::{DateTime, Utc};
+use sqlx::types::Json;
+use sqlx::{Pool, Postgres};
+
+use crate::common::{RepositoryResult};
+use crate::domain::account::aggregates::UserId;
+use crate::domain::social::aggregates::{PollVote, PostId};
+use crate::infrastructure::postgres::to_repository_error;
+
+pub struct PgPollVoteRepository {
+    pool: Pool<Postgres>,
+}
+
+impl PgPollVoteRepository {
+    pub fn build(pool: Pool<Postgres>) -> PgPollVoteRepository {
+        PgPollVoteRepository { pool }
+    }
+}
+
+#[derive(sqlx::FromRow)]
+struct PollVoteRow {
+    data: Json<PollVote>,
+}
+
+#[tonic::async_trait]
+impl crate::domain::social::repositories::PollVoteRepository for PgPollVoteRepository {
+    async fn list(&self, post: &PostId, choice: &Option<u32>, after: &Option<UserId>) -> RepositoryResult<Vec<PollVote>> {
+        let sql = r#"
+            with votes as (
+                select voter, data, row_number() over (order by voted desc, voter) row
+                from poll_votes
+                where post = $1
+                  and ($2 is null or $2 = any(choices))
+            )
+
+            select data
+            from votes
+            where ($3 is null or row > (select coalesce(max(row), bigint_max()) from votes where voter = $3))
+            order by row
+            limit 25"#;
+
+        let rows: Vec<PollVoteRow> = sqlx::query_as(sql)
+            .bind(post.to_string())
+            .bind(choice.map(|choice| choice as i32))
+            .bind(after.as_ref().map(|user| user.to_string()))
+            .fetch_all(&self.pool)
+            .await
+            .map_err(to_repository_error)?;
+
+        Ok(rows.into_iter().map(|row| row.data.0).collect())
+    }
+
+    async fn set(&self, vote: &PollVote) -> RepositoryResult<bool> {
+        let sql = r#"
+               insert into poll_votes (post, voter, choices, data, voted)
+               values ($1, $2, $3, $4, text_to_timestamp($5))
+               on conflict (post, voter) do nothing"#;
+
+        let result = sqlx::query(sql)
+            .bind(vote.post.to_string())
+            .bind(vote.voter.to_string())
+            .bind(vote.choices.iter().map(|choice| *choice as i32).collect::<Vec<i32>>())
+            .bind(Json(vote))
+            .bind(vote.voted.to_rfc3339())
+            .execute(&self.pool)
+            .await
+            .map_err(to_repository_error)?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    async fn close(&self, closes_before: &DateTime<Utc>) -> RepositoryResult<Vec<PostId>> {
+        // note: polls of scheduled posts close once the post went live
+        let sql = r#"
+               update posts
+               set poll_closed = true
+               where poll_closes <= text_to_timestamp($1)
+                 and not poll_closed
+                 and status = 'published'
+               returning id"#;
+
+        let rows: Vec<(String,)> = sqlx::query_as(sql)
+            .bind(closes_before.to_rfc3339())
+            .fetch_all(&self.pool)
+            .await
+            .map_err(to_repository_error)?;
+
+        Ok(rows
+            .into_iter()
+            .filter_map(|(id,)| PostId::parse(id.as_str()).ok())
+            .collect())
+    }
+}
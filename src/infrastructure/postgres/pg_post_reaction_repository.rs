@@ -1,8 +1,10 @@
+use chrono::{DateTime, Utc};
 use sqlx::types::Json;
 use sqlx::{Pool, Postgres, Row};
 
 use crate::common::{RepositoryResult};
-use crate::domain::social::aggregates::{PostReaction};
+use crate::domain::account::aggregates::UserId;
+use crate::domain::social::aggregates::{PostId, PostReaction, PostReactionRecord, ReactionKey};
 use crate::infrastructure::postgres::to_repository_error;
 
 pub struct PgPostReactionRepository {
@@ -15,9 +17,58 @@ impl PgPostReactionRepository {
     }
 }
 
+#[derive(sqlx::FromRow)]
+struct PostReactionRow {
+    author: String,
+    emotion: String,
+    reacted: String,
+}
+
 #[tonic::async_trait]
 impl crate::domain::social::repositories::PostReactionRepository for PgPostReactionRepository {
-    async fn set(&self, reaction: &PostReaction) -> RepositoryResult<bool> {
+    async fn list(&self, post: &PostId, reactions: &[ReactionKey], after: &Option<UserId>) -> RepositoryResult<Vec<PostReactionRecord>> {
+        // note: records are derived from columns, as data predates the current format for older reactions
+        // note: reactions given before timestamps were recorded are listed last
+        let sql = r#"
+            with reactions as (
+                select
+                    author,
+                    emotion,
+                    coalesce(data->>'reacted', '1970-01-01T00:00:00Z') as reacted,
+                    row_number() over (order by reacted desc nulls last, author) row
+                from post_reactions
+                where post = $1
+                  and (cardinality($2::text[]) = 0 or emotion = any($2))
+            )
+
+            select author, emotion, reacted
+            from reactions
+            where ($3 is null or row > (select coalesce(max(row), bigint_max()) from reactions where author = $3))
+            order by row
+            limit 25"#;
+
+        let rows: Vec<PostReactionRow> = sqlx::query_as(sql)
+            .bind(post.to_string())
+            .bind(reactions.iter().map(|reaction| reaction.to_string()).collect::<Vec<String>>())
+            .bind(after.as_ref().map(|user| user.to_string()))
+            .fetch_all(&self.pool)
+            .await
+            .map_err(to_repository_error)?;
+
+        Ok(rows
+            .into_iter()
+            .filter_map(|row| Some(PostReactionRecord {
+                reaction: PostReaction {
+                    author: UserId::parse(row.author.as_str()).ok()?,
+                    post: post.clone(),
+                    reaction: ReactionKey::parse(row.emotion.as_str()).ok()?,
+                },
+                reacted: DateTime::parse_from_rfc3339(row.reacted.as_str()).ok()?.with_timezone(&Utc),
+            }))
+            .collect())
+    }
+
+    async fn set(&self, record: &PostReactionRecord) -> RepositoryResult<bool> {
         let sql = r#"
             with current as (
                 select count(0) as existing
@@ -25,21 +76,22 @@ impl crate::domain::social::repositories::PostReactionRepository for PgPostReact
                 where post = $1 and author = $2 and emotion = $3
             )
             , update as (
-                 insert into post_reactions (post, author, emotion, data)
-                 values ($1, $2, $3, $4)
-                 on conflict (post, author) where emotion <> $3 do update set emotion = $3, data = $4
+                 insert into post_reactions (post, author, emotion, data, reacted)
+                 values ($1, $2, $3, $4, text_to_timestamp($5))
+                 on conflict (post, author) where emotion <> $3 do update set emotion = $3, data = $4, reacted = text_to_timestamp($5)
                  returning 1 as modified
             )
             -- returns number of exact duplicates (at most 1)
             select existing from current, update"#;
 
-        let (emotion, author, post) = reaction.values();
+        let reaction = &record.reaction;
 
         let result: i64 = sqlx::query(sql)
-            .bind(post.to_string())
-            .bind(author.to_string())
-            .bind(emotion)
-            .bind(Json(reaction))
+            .bind(reaction.post.to_string())
+            .bind(reaction.author.to_string())
+            .bind(reaction.reaction.to_string())
+            .bind(Json(record))
+            .bind(record.reacted.to_rfc3339())
             .fetch_one(&self.pool)
             .await
             .map_err(to_repository_error)
@@ -55,16 +107,14 @@ impl crate::domain::social::repositories::PostReactionRepository for PgPostReact
                  and author = $2
                  and emotion = $3"#;
 
-        let (emotion, author, post) = reaction.values();
-
         let result = sqlx::query(sql)
-            .bind(post.to_string())
-            .bind(author.to_string())
-            .bind(emotion)
+            .bind(reaction.post.to_string())
+            .bind(reaction.author.to_string())
+            .bind(reaction.reaction.to_string())
             .execute(&self.pool)
             .await
             .map_err(to_repository_error)?;
 
         Ok(result.rows_affected() > 0)
     }
-}
\ No newline at end of file
+}
@@ -1,12 +1,14 @@
 
 
 
+use chrono::{DateTime, Utc};
 use sqlx::types::Json;
 use sqlx::{Pool, Postgres};
 use std::option::Option;
 
 use crate::common::{RepositoryResult};
-use crate::domain::social::aggregates::{Post, PostId};
+use crate::domain::account::aggregates::UserId;
+use crate::domain::social::aggregates::{CommunityId, Post, PostId, TextEntity};
 use crate::infrastructure::postgres::to_repository_error;
 
 pub struct PgPostRepository {
@@ -44,13 +46,21 @@ impl crate::domain::social::repositories::PostRepository for PgPostRepository {
 
     async fn set(&self, post: &Post) -> RepositoryResult<()> {
         let sql = r#"
-               insert into posts (id, data)
-               values ($1, $2)
-               on conflict (id) do update set data = $2"#;
+               insert into posts (id, data, hidden, removed, pinned, announced_until, status, scheduled, poll_closes, hashtags)
+               values ($1, $2, $3, text_to_timestamp($4), text_to_timestamp($5), text_to_timestamp($6), $7, text_to_timestamp($8), text_to_timestamp($9), $10)
+               on conflict (id) do update set data = $2, hidden = $3, removed = text_to_timestamp($4), pinned = text_to_timestamp($5), announced_until = text_to_timestamp($6), status = $7, scheduled = text_to_timestamp($8), poll_closes = text_to_timestamp($9), hashtags = $10"#;
 
         sqlx::query(sql)
             .bind(post.id.to_string())
             .bind(Json(post))
+            .bind(post.hidden)
+            .bind(post.removed.as_ref().map(|tombstone| tombstone.removed.to_rfc3339()))
+            .bind(post.pinned.map(|pinned| pinned.to_rfc3339()))
+            .bind(post.kind.announced_until().map(|until| until.to_rfc3339()))
+            .bind(post.status.as_str())
+            .bind(post.status.scheduled().map(|at| at.to_rfc3339()))
+            .bind(post.attachments.poll().and_then(|poll| poll.closes).map(|closes| closes.to_rfc3339()))
+            .bind(TextEntity::hashtags(&post.entities).iter().map(|tag| tag.to_string()).collect::<Vec<String>>())
             .execute(&self.pool)
             .await
             .map_err(to_repository_error)?;
@@ -58,18 +68,124 @@ impl crate::domain::social::repositories::PostRepository for PgPostRepository {
         Ok(())
     }
 
-    async fn remove(&self, id: &PostId) -> RepositoryResult<()> {
+    async fn list_pinned(&self, community: &CommunityId) -> RepositoryResult<Vec<Post>> {
         let sql = r#"
-               delete from posts
-               where id = $1"#;
+              select data
+              from posts
+              where community = $1
+                and pinned is not null
+                and status = 'published'
+                and removed is null
+              order by pinned desc"#;
+
+        let rows: Vec<PostRow> = sqlx::query_as(sql)
+            .bind(community.to_string())
+            .fetch_all(&self.pool)
+            .await
+            .map_err(to_repository_error)?;
 
-        sqlx::query(sql)
-            .bind(id.to_string())
-            .execute(&self.pool)
+        Ok(rows.into_iter().map(|row| row.data.0).collect())
+    }
+
+    async fn list_drafts(&self, author: &UserId, after: &Option<PostId>) -> RepositoryResult<Vec<Post>> {
+        let sql = r#"
+              with drafts as (
+                  select id, data, row_number() over (order by published desc) row
+                  from posts
+                  where (data->'author')->>'raw' = $1
+                    and status <> 'published'
+                    and removed is null
+              )
+
+              select data
+              from drafts
+              where ($2 is null or row > (select coalesce(max(row), bigint_max()) from drafts where id = $2))
+              order by row
+              limit 25"#;
+
+        let rows: Vec<PostRow> = sqlx::query_as(sql)
+            .bind(author.to_string())
+            .bind(after.as_ref().map(|id| id.to_string()))
+            .fetch_all(&self.pool)
             .await
             .map_err(to_repository_error)?;
 
-        Ok(())
+        Ok(rows.into_iter().map(|row| row.data.0).collect())
+    }
+
+    async fn list_due(&self, at: &DateTime<Utc>) -> RepositoryResult<Vec<Post>> {
+        let sql = r#"
+              select data
+              from posts
+              where status = 'scheduled'
+                and scheduled <= text_to_timestamp($1)
+                and removed is null
+              order by scheduled"#;
+
+        let rows: Vec<PostRow> = sqlx::query_as(sql)
+            .bind(at.to_rfc3339())
+            .fetch_all(&self.pool)
+            .await
+            .map_err(to_repository_error)?;
+
+        Ok(rows.into_iter().map(|row| row.data.0).collect())
+    }
+
+    async fn purge(&self, removed_before: &DateTime<Utc>) -> RepositoryResult<Vec<PostId>> {
+        let sql = r#"
+               with purged as (
+                   delete from posts
+                   where removed < text_to_timestamp($1)
+                   returning id
+               ),
+               purged_comments as (
+                   delete from comments
+                   where reply_to in (select id from purged)
+                   returning id
+               ),
+               purged_comment_reactions as (
+                   delete from comment_reactions
+                   where comment in (select id from purged_comments)
+               ),
+               purged_reactions as (
+                   delete from post_reactions
+                   where post in (select id from purged)
+               ),
+               purged_poll_votes as (
+                   delete from poll_votes
+                   where post in (select id from purged)
+               ),
+               purged_bookmarks as (
+                   delete from bookmarks
+                   where (target_kind = 'post' and target_id in (select id from purged))
+                      or (target_kind = 'comment' and target_id in (select id from purged_comments))
+               )
+               select id
+               from purged"#;
+
+        let rows: Vec<(String,)> = sqlx::query_as(sql)
+            .bind(removed_before.to_rfc3339())
+            .fetch_all(&self.pool)
+            .await
+            .map_err(to_repository_error)?;
+
+        Ok(rows
+            .into_iter()
+            .filter_map(|(id,)| PostId::parse(id.as_str()).ok())
+            .collect())
     }
 }
 
+#[tonic::async_trait]
+impl crate::domain::fixture::repositories::PostRepository for PgPostRepository {
+    async fn set(&self, post: &Post) -> RepositoryResult<()> {
+        crate::domain::social::repositories::PostRepository::set(self, post).await
+    }
+}
+
+#[tonic::async_trait]
+impl crate::domain::notification::repositories::PostRepository for PgPostRepository {
+    async fn get(&self, id: &PostId) -> RepositoryResult<Option<Post>> {
+        crate::domain::social::repositories::PostRepository::get(self, id).await
+    }
+}
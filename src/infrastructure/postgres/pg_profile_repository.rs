@@ -0,0 +1,84 @@
+use itertools::Itertools;
+use sqlx::types::Json;
+use sqlx::{Pool, Postgres};
+
+use crate::common::RepositoryResult;
+use crate::domain::account::aggregates::{UserId, UserProfile};
+use crate::domain::social::aggregates::Language;
+use crate::infrastructure::postgres::to_repository_error;
+
+pub struct PgProfileRepository {
+    pool: Pool<Postgres>,
+}
+
+impl PgProfileRepository {
+    pub fn build(pool: Pool<Postgres>) -> PgProfileRepository {
+        PgProfileRepository { pool }
+    }
+}
+
+#[derive(sqlx::FromRow)]
+struct ProfileRow {
+    data: Json<UserProfile>,
+}
+
+#[tonic::async_trait]
+impl crate::domain::account::repositories::ProfileRepository for PgProfileRepository {
+    async fn get(&self, id: &UserId) -> RepositoryResult<Option<UserProfile>> {
+        let sql = r#"
+              select data
+              from profiles
+              where id = $1
+              limit 1"#;
+
+        let row: Option<ProfileRow> = sqlx::query_as(sql)
+            .bind(id.to_string())
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(to_repository_error)?;
+
+        Ok(row.map(|columns| columns.data.0))
+    }
+
+    async fn get_many(&self, ids: &[UserId]) -> RepositoryResult<Vec<UserProfile>> {
+        let sql = r#"
+              select data
+              from profiles
+              where id = any($1)"#;
+
+        let rows: Vec<ProfileRow> = sqlx::query_as(sql)
+            .bind(ids.iter().map(|id| id.to_string()).collect_vec())
+            .fetch_all(&self.pool)
+            .await
+            .map_err(to_repository_error)?;
+
+        Ok(rows.into_iter().map(|row| row.data.0).collect_vec())
+    }
+
+    async fn set(&self, profile: &UserProfile) -> RepositoryResult<()> {
+        let sql = r#"
+               insert into profiles (id, data)
+               values ($1, $2)
+               on conflict (id) do update set data = $2"#;
+
+        sqlx::query(sql)
+            .bind(profile.id.to_string())
+            .bind(Json(profile))
+            .execute(&self.pool)
+            .await
+            .map_err(to_repository_error)?;
+
+        Ok(())
+    }
+}
+
+#[tonic::async_trait]
+impl crate::domain::social::repositories::ProfileRepository for PgProfileRepository {
+    async fn get_language(&self, user: &UserId) -> RepositoryResult<Option<Language>> {
+        let profile = crate::domain::account::repositories::ProfileRepository::get(self, user).await?;
+
+        Ok(profile
+            .and_then(|profile| profile.locale)
+            .and_then(|locale| Language::parse(locale.language()).ok()))
+    }
+}
@@ -0,0 +1,133 @@
+use itertools::Itertools;
+use sqlx::types::Json;
+use sqlx::{Pool, Postgres, Row};
+use std::option::Option;
+
+use crate::common::{RepositoryResult};
+use crate::domain::account::aggregates::UserId;
+use crate::domain::social::aggregates::{Report, ReportId, ReportTarget};
+use crate::infrastructure::postgres::to_repository_error;
+
+pub struct PgReportRepository {
+    pool: Pool<Postgres>,
+}
+
+impl PgReportRepository {
+    pub fn build(pool: Pool<Postgres>) -> PgReportRepository {
+        PgReportRepository { pool }
+    }
+}
+
+#[derive(sqlx::FromRow)]
+struct ReportRow {
+    data: Json<Report>,
+}
+
+#[tonic::async_trait]
+impl crate::domain::social::repositories::ReportRepository for PgReportRepository {
+    async fn list(&self, editor: &UserId, after: &Option<ReportId>) -> RepositoryResult<Vec<Report>> {
+        let sql = r#"
+            with reports as (
+                select reports.id, reports.data, row_number() over (order by reports.reported) row
+                from reports
+                join communities on reports.community = communities.id
+                where reports.status = 'open'
+                  and $1 = any(communities.editors)
+            )
+
+            select data
+            from reports
+            where ($2 is null or row > (select coalesce(max(row), bigint_max()) from reports where id = $2))
+            limit 25"#;
+
+        let rows: Vec<ReportRow> = sqlx::query_as(sql)
+            .bind(editor.to_string())
+            .bind(after.as_ref().map(|p| p.to_string()))
+            .fetch_all(&self.pool)
+            .await
+            .map_err(to_repository_error)?;
+
+        Ok(rows.into_iter().map(|row| row.data.0).collect_vec())
+    }
+
+    async fn list_open(&self, target: &ReportTarget) -> RepositoryResult<Vec<Report>> {
+        let sql = r#"
+              select data
+              from reports
+              where target_kind = $1
+                and target_id = $2
+                and status = 'open'
+              order by reported"#;
+
+        let (kind, id) = target.values();
+
+        let rows: Vec<ReportRow> = sqlx::query_as(sql)
+            .bind(kind)
+            .bind(id)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(to_repository_error)?;
+
+        Ok(rows.into_iter().map(|row| row.data.0).collect_vec())
+    }
+
+    async fn count_reporters(&self, target: &ReportTarget) -> RepositoryResult<u64> {
+        let sql = r#"
+              select count(distinct reporter)
+              from reports
+              where target_kind = $1
+                and target_id = $2
+                and status = 'open'"#;
+
+        let (kind, id) = target.values();
+
+        let result: i64 = sqlx::query(sql)
+            .bind(kind)
+            .bind(id)
+            .fetch_one(&self.pool)
+            .await
+            .map_err(to_repository_error)
+            .map(|row| row.get(0))?;
+
+        Ok(result as u64)
+    }
+
+    async fn get(&self, id: &ReportId) -> RepositoryResult<Option<Report>> {
+        let sql = r#"
+              select data
+              from reports
+              where id = $1
+              limit 1"#;
+
+        let row: Option<ReportRow> = sqlx::query_as(sql)
+            .bind(id.to_string())
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(to_repository_error)?;
+
+        Ok(row.map(|columns| columns.data.0))
+    }
+
+    async fn set(&self, report: &Report) -> RepositoryResult<()> {
+        let sql = r#"
+               insert into reports (id, data, community, target_kind, target_id, reporter, status)
+               values ($1, $2, $3, $4, $5, $6, $7)
+               on conflict (id) do update set data = $2, status = $7"#;
+
+        let (kind, id) = report.target.values();
+
+        sqlx::query(sql)
+            .bind(report.id.to_string())
+            .bind(Json(report))
+            .bind(report.community.to_string())
+            .bind(kind)
+            .bind(id)
+            .bind(report.reporter.as_ref().map(|r| r.to_string()))
+            .bind(report.status.as_str())
+            .execute(&self.pool)
+            .await
+            .map_err(to_repository_error)?;
+
+        Ok(())
+    }
+}
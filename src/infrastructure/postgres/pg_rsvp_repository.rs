@@ -0,0 +1,61 @@
+use chrono::{DateTime, Utc};
+use itertools::Itertools;
+use sqlx::types::Json;
+use sqlx::{Pool, Postgres};
+
+use crate::common::{RepositoryResult};
+use crate::domain::calendar::aggregates::{Rsvp, TeamEventId};
+use crate::infrastructure::postgres::to_repository_error;
+
+pub struct PgRsvpRepository {
+    pool: Pool<Postgres>,
+}
+
+impl PgRsvpRepository {
+    pub fn build(pool: Pool<Postgres>) -> PgRsvpRepository {
+        PgRsvpRepository { pool }
+    }
+}
+
+#[derive(sqlx::FromRow)]
+struct RsvpRow {
+    data: Json<Rsvp>,
+}
+
+#[tonic::async_trait]
+impl crate::domain::calendar::repositories::RsvpRepository for PgRsvpRepository {
+    async fn list(&self, event: &TeamEventId, occurrence: &DateTime<Utc>) -> RepositoryResult<Vec<Rsvp>> {
+        let sql = r#"
+              select data
+              from rsvps
+              where event = $1
+                and occurrence = text_to_timestamp($2)"#;
+
+        let rows: Vec<RsvpRow> = sqlx::query_as(sql)
+            .bind(event.to_string())
+            .bind(occurrence.to_rfc3339())
+            .fetch_all(&self.pool)
+            .await
+            .map_err(to_repository_error)?;
+
+        Ok(rows.into_iter().map(|row| row.data.0).collect_vec())
+    }
+
+    async fn set(&self, rsvp: &Rsvp) -> RepositoryResult<()> {
+        let sql = r#"
+               insert into rsvps (event, occurrence, respondent, data)
+               values ($1, text_to_timestamp($2), $3, $4)
+               on conflict (event, occurrence, respondent) do update set data = $4"#;
+
+        sqlx::query(sql)
+            .bind(rsvp.event.to_string())
+            .bind(rsvp.occurrence.to_rfc3339())
+            .bind(rsvp.user.to_string())
+            .bind(Json(rsvp))
+            .execute(&self.pool)
+            .await
+            .map_err(to_repository_error)?;
+
+        Ok(())
+    }
+}
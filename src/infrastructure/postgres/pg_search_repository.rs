@@ -0,0 +1,160 @@
+use sqlx::{Pool, Postgres};
+use std::option::Option;
+
+use crate::common::{RepositoryResult};
+use crate::domain::account::aggregates::UserId;
+use crate::domain::social::aggregates::{CommentId, CommunityId, Highlight, PostId, SearchHit, SearchKind, SearchQuery, SearchTarget};
+use crate::infrastructure::postgres::to_repository_error;
+
+pub struct PgSearchRepository {
+    pool: Pool<Postgres>,
+    configuration: String,
+}
+
+impl PgSearchRepository {
+    // note: configuration is a postgres text search configuration (e.g. simple, english, dutch);
+    // the search indexes must be created with the same configuration to be used, see migrations/search.sql
+    pub fn build(pool: Pool<Postgres>, configuration: &str) -> PgSearchRepository {
+        // rule: configuration is formatted into the statements, so only plain names are accepted
+        let configuration = match !configuration.is_empty() && configuration.chars().all(|c| c.is_ascii_lowercase() || c == '_') {
+            true => configuration.to_string(),
+            false => String::from("simple"),
+        };
+
+        PgSearchRepository { pool, configuration }
+    }
+}
+
+#[derive(sqlx::FromRow)]
+struct SearchRow {
+    kind: String,
+    id: String,
+    community: String,
+    post: Option<String>,
+    rank: f32,
+    highlight: String,
+}
+
+impl SearchRow {
+    fn into_hit(self) -> Option<SearchHit> {
+        let target = match self.kind.as_str() {
+            "community" => SearchTarget::Community(CommunityId::parse(self.id.as_str()).ok()?),
+            "post" => SearchTarget::Post(PostId::parse(self.id.as_str()).ok()?),
+            "comment" => SearchTarget::Comment(CommentId::parse(self.id.as_str()).ok()?),
+            _ => return None,
+        };
+
+        Some(SearchHit {
+            target,
+            community: CommunityId::parse(self.community.as_str()).ok()?,
+            post: match self.post {
+                Some(post) => Some(PostId::parse(post.as_str()).ok()?),
+                None => None,
+            },
+            rank: self.rank,
+            highlight: Highlight::from_marked(self.highlight.as_str()),
+        })
+    }
+}
+
+fn to_kind(kind: &SearchKind) -> String {
+    match kind {
+        SearchKind::Community => String::from("community"),
+        SearchKind::Post => String::from("post"),
+        SearchKind::Comment => String::from("comment"),
+    }
+}
+
+#[tonic::async_trait]
+impl crate::domain::social::repositories::SearchRepository for PgSearchRepository {
+    async fn search(&self, query: &SearchQuery, kinds: &[SearchKind], viewer: &UserId, after: &Option<SearchTarget>) -> RepositoryResult<Vec<SearchHit>> {
+        // note: communities match on their own name or the name of their context (club, team or competition);
+        // posts & comments only when live and not by users the viewer blocked, and nothing from communities the viewer is banned from
+        let sql = format!(r#"
+            with query as (
+                select websearch_to_tsquery('{config}', $3) as query
+            ), hits as (
+                select 'community' as kind, communities.id, communities.id as community, null as post,
+                       ts_rank(to_tsvector('{config}', communities.name), query.query) + ts_rank(to_tsvector('{config}', coalesce(clubs.name, teams.name, competitions.name, '')), query.query) as rank,
+                       ts_headline('{config}', communities.name, query.query, $4) as highlight
+                from communities
+                left join clubs on communities.context_club = clubs.id
+                left join teams on communities.context_team = teams.id
+                left join competitions on communities.context_competition = competitions.id
+                cross join query
+                where 'community' = any($2)
+                  and (to_tsvector('{config}', communities.name) @@ query.query
+                    or to_tsvector('{config}', clubs.name) @@ query.query
+                    or to_tsvector('{config}', teams.name) @@ query.query
+                    or to_tsvector('{config}', competitions.name) @@ query.query)
+                  and not community_member_banned(communities.data, $1)
+
+                union all
+
+                select 'post' as kind, posts.id, posts.community, posts.id as post,
+                       ts_rank(to_tsvector('{config}', (posts.data->'text')->>'raw'), query.query) as rank,
+                       ts_headline('{config}', (posts.data->'text')->>'raw', query.query, $4) as highlight
+                from posts
+                join communities on posts.community = communities.id
+                cross join query
+                where 'post' = any($2)
+                  and to_tsvector('{config}', (posts.data->'text')->>'raw') @@ query.query
+                  and not posts.hidden
+                  and not user_blocked($1, posts.author)
+                  and posts.status = 'published'
+                  and posts.removed is null
+                  and not community_member_banned(communities.data, $1)
+
+                union all
+
+                select 'comment' as kind, comments.id, posts.community, posts.id as post,
+                       ts_rank(to_tsvector('{config}', (comments.data->'text')->>'raw'), query.query) as rank,
+                       ts_headline('{config}', (comments.data->'text')->>'raw', query.query, $4) as highlight
+                from comments
+                join posts on comments.reply_to = posts.id
+                join communities on posts.community = communities.id
+                cross join query
+                where 'comment' = any($2)
+                  and to_tsvector('{config}', (comments.data->'text')->>'raw') @@ query.query
+                  and comments.removed is null
+                  and not user_blocked($1, comments.author)
+                  and not posts.hidden
+                  and not user_blocked($1, posts.author)
+                  and posts.status = 'published'
+                  and posts.removed is null
+                  and not community_member_banned(communities.data, $1)
+            ), ranked as (
+                select *, row_number() over (order by rank desc, kind, id) row
+                from hits
+            )
+
+            select kind, id, community, post, rank, highlight
+            from ranked
+            where ($5 is null or row > (select coalesce(max(row), bigint_max()) from ranked where kind = $5 and id = $6))
+            order by row
+            limit 25"#, config = self.configuration);
+
+        let options = format!("StartSel={}, StopSel={}, MinWords=10, MaxWords=30, MaxFragments=2", Highlight::START, Highlight::STOP);
+        let (after_kind, after_id) = match after {
+            Some(target) => {
+                let (kind, id) = target.values();
+                (Some(kind.to_string()), Some(id))
+            },
+            None => (None, None),
+        };
+
+        let rows: Vec<SearchRow> = sqlx::query_as(sql.as_str())
+            .bind(viewer.to_string())
+            .bind(kinds.iter().map(to_kind).collect::<Vec<String>>())
+            .bind(query.to_string())
+            .bind(options)
+            .bind(after_kind)
+            .bind(after_id)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(to_repository_error)?;
+
+        // note: rows with ids no longer valid are skipped
+        Ok(rows.into_iter().filter_map(SearchRow::into_hit).collect())
+    }
+}
@@ -0,0 +1,89 @@
+use itertools::Itertools;
+use sqlx::types::Json;
+use sqlx::{Pool, Postgres};
+use std::option::Option;
+
+use crate::common::{RepositoryResult};
+use crate::domain::competition::aggregates::{CompetitionId, Season, SeasonId};
+use crate::infrastructure::postgres::to_repository_error;
+
+pub struct PgSeasonRepository {
+    pool: Pool<Postgres>,
+}
+
+impl PgSeasonRepository {
+    pub fn build(pool: Pool<Postgres>) -> PgSeasonRepository {
+        PgSeasonRepository { pool }
+    }
+}
+
+#[derive(sqlx::FromRow)]
+struct SeasonRow {
+    data: Json<Season>,
+}
+
+#[tonic::async_trait]
+impl crate::domain::competition::repositories::SeasonRepository for PgSeasonRepository {
+    async fn list(&self, competition: &CompetitionId, after: &Option<SeasonId>) -> RepositoryResult<Vec<Season>> {
+        let sql = r#"
+            with seasons as (
+                select id, data, row_number() over (order by name desc, id) row
+                from seasons
+                where competition = $1
+            )
+
+            select data
+            from seasons
+            where ($2 is null or row > (select coalesce(max(row), bigint_max()) from seasons where id = $2))
+            order by row
+            limit 25"#;
+
+        let rows: Vec<SeasonRow> = sqlx::query_as(sql)
+            .bind(competition.to_string())
+            .bind(after.as_ref().map(|p| p.to_string()))
+            .fetch_all(&self.pool)
+            .await
+            .map_err(to_repository_error)?;
+
+        Ok(rows.into_iter().map(|row| row.data.0).collect_vec())
+    }
+
+    async fn get(&self, id: &SeasonId) -> RepositoryResult<Option<Season>> {
+        let sql = r#"
+              select data
+              from seasons
+              where id = $1
+              limit 1"#;
+
+        let row: Option<SeasonRow> = sqlx::query_as(sql)
+            .bind(id.to_string())
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(to_repository_error)?;
+
+        Ok(row.map(|columns| columns.data.0))
+    }
+
+    async fn set(&self, season: &Season) -> RepositoryResult<()> {
+        let sql = r#"
+               insert into seasons (id, data)
+               values ($1, $2)
+               on conflict (id) do update set data = $2"#;
+
+        sqlx::query(sql)
+            .bind(season.id.to_string())
+            .bind(Json(season))
+            .execute(&self.pool)
+            .await
+            .map_err(to_repository_error)?;
+
+        Ok(())
+    }
+}
+
+#[tonic::async_trait]
+impl crate::domain::fixture::repositories::SeasonRepository for PgSeasonRepository {
+    async fn get(&self, id: &SeasonId) -> RepositoryResult<Option<Season>> {
+        crate::domain::competition::repositories::SeasonRepository::get(self, id).await
+    }
+}
@@ -0,0 +1,84 @@
+use chrono::{DateTime, Utc};
+use itertools::Itertools;
+use sqlx::types::Json;
+use sqlx::{Pool, Postgres};
+use std::option::Option;
+
+use crate::common::{RepositoryResult};
+use crate::domain::calendar::aggregates::{TeamEvent, TeamEventId};
+use crate::domain::team::aggregates::TeamId;
+use crate::infrastructure::postgres::to_repository_error;
+
+pub struct PgTeamEventRepository {
+    pool: Pool<Postgres>,
+}
+
+impl PgTeamEventRepository {
+    pub fn build(pool: Pool<Postgres>) -> PgTeamEventRepository {
+        PgTeamEventRepository { pool }
+    }
+}
+
+#[derive(sqlx::FromRow)]
+struct TeamEventRow {
+    data: Json<TeamEvent>,
+}
+
+#[tonic::async_trait]
+impl crate::domain::calendar::repositories::TeamEventRepository for PgTeamEventRepository {
+    async fn list(&self, team: &TeamId, from: &Option<DateTime<Utc>>, until: &Option<DateTime<Utc>>) -> RepositoryResult<Vec<TeamEvent>> {
+        // note: last_end is the end of the last occurrence, so recurring events are found by any of their occurrences
+        let sql = r#"
+              select data
+              from team_events
+              where team = $1
+                and ($2 is null or last_end > text_to_timestamp($2))
+                and ($3 is null or start < text_to_timestamp($3))
+              order by start, id"#;
+
+        let rows: Vec<TeamEventRow> = sqlx::query_as(sql)
+            .bind(team.to_string())
+            .bind(from.map(|from| from.to_rfc3339()))
+            .bind(until.map(|until| until.to_rfc3339()))
+            .fetch_all(&self.pool)
+            .await
+            .map_err(to_repository_error)?;
+
+        Ok(rows.into_iter().map(|row| row.data.0).collect_vec())
+    }
+
+    async fn get(&self, id: &TeamEventId) -> RepositoryResult<Option<TeamEvent>> {
+        let sql = r#"
+              select data
+              from team_events
+              where id = $1
+              limit 1"#;
+
+        let row: Option<TeamEventRow> = sqlx::query_as(sql)
+            .bind(id.to_string())
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(to_repository_error)?;
+
+        Ok(row.map(|columns| columns.data.0))
+    }
+
+    async fn set(&self, event: &TeamEvent) -> RepositoryResult<()> {
+        let sql = r#"
+               insert into team_events (id, data, team, start, last_end)
+               values ($1, $2, $3, text_to_timestamp($4), text_to_timestamp($5))
+               on conflict (id) do update set data = $2, start = text_to_timestamp($4), last_end = text_to_timestamp($5)"#;
+
+        sqlx::query(sql)
+            .bind(event.id.to_string())
+            .bind(Json(event))
+            .bind(event.team.to_string())
+            .bind(event.start.to_rfc3339())
+            .bind(event.last_end().to_rfc3339())
+            .execute(&self.pool)
+            .await
+            .map_err(to_repository_error)?;
+
+        Ok(())
+    }
+}
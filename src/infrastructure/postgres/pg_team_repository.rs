@@ -77,3 +77,25 @@ impl crate::domain::team::repositories::TeamRepository for PgTeamRepository {
         Ok(())
     }
 }
+
+#[tonic::async_trait]
+impl crate::domain::fixture::repositories::TeamRepository for PgTeamRepository {
+    async fn get(&self, id: &TeamId) -> RepositoryResult<Option<Team>> {
+        crate::domain::team::repositories::TeamRepository::get(self, id).await
+    }
+}
+
+#[tonic::async_trait]
+impl crate::domain::competition::repositories::TeamRepository for PgTeamRepository {
+    async fn exist(&self, id: &TeamId) -> RepositoryResult<bool> {
+        let team = crate::domain::team::repositories::TeamRepository::get(self, id).await?;
+        Ok(team.is_some())
+    }
+}
+
+#[tonic::async_trait]
+impl crate::domain::calendar::repositories::TeamRepository for PgTeamRepository {
+    async fn get(&self, id: &TeamId) -> RepositoryResult<Option<Team>> {
+        crate::domain::team::repositories::TeamRepository::get(self, id).await
+    }
+}
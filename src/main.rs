@@ -5,15 +5,24 @@ pub mod api;
 pub mod config;
 
 use std::sync::Arc;
+use chrono::Duration;
 use sqlx::postgres::PgPoolOptions;
 use tonic::{transport::Server};
 
 use api::api_v1_server::{ApiV1Server};
 use api::ApiService;
-use crate::common::EventPublisher;
+use crate::common::{scheduler, EventPublisher};
 use crate::config::Configuration;
 
+use crate::domain::account::aggregates::IdentityProviders;
+use crate::domain::account::usecases::AccountUsecase;
+use crate::domain::calendar::usecases::CalendarUsecase;
 use crate::domain::club::usecases::ClubUsecase;
+use crate::domain::competition::usecases::CompetitionUsecase;
+use crate::domain::fixture::usecases::FixtureUsecase;
+use crate::domain::notification::usecases::NotificationUsecase;
+use crate::domain::social::filters::{RulesContentFilter, RulesEngine, RulesSettings};
+use crate::domain::social::usecases::{PublicationUsecase, RetentionUsecase, SocialSettings};
 use crate::domain::social::usecases::usecase::SocialUsecase;
 use crate::domain::team::usecases::TeamUsecase;
 use crate::infrastructure::postgres::*;
@@ -34,22 +43,142 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     let community_repository = Box::new(PgCommunityRepository::build(pool.clone()));
     let post_repository = Box::new(PgPostRepository::build(pool.clone()));
     let post_reaction_repository = Box::new(PgPostReactionRepository::build(pool.clone()));
+    let poll_vote_repository = Box::new(PgPollVoteRepository::build(pool.clone()));
     let comment_repository = Box::new(PgCommentRepository::build(pool.clone()));
+    let comment_reaction_repository = Box::new(PgCommentReactionRepository::build(pool.clone()));
+    let bookmark_repository = Box::new(PgBookmarkRepository::build(pool.clone()));
+    let follow_repository = Box::new(PgFollowRepository::build(pool.clone()));
+    let block_repository = Box::new(PgBlockRepository::build(pool.clone()));
     let feed_repository = Box::new(PgFeedRepository::build(pool.clone()));
+    let search_repository = Box::new(PgSearchRepository::build(pool.clone(), configuration.search_configuration.as_str()));
+    let report_repository = Box::new(PgReportRepository::build(pool.clone()));
+    let content_filter_repository = PgContentFilterRepository::build(pool.clone());
 
     // clients
     let event_publisher = Arc::new(EventPublisher::build(event_repository.clone()));
+    let content_filter = Box::new(RulesContentFilter::build(
+        Box::new(content_filter_repository.clone()),
+        Box::new(content_filter_repository.clone()),
+        RulesEngine::build(RulesSettings {
+            max_links: configuration.content_filter_max_links,
+            max_repeats: configuration.content_filter_max_repeats,
+            repeat_window: Duration::minutes(configuration.content_filter_repeat_window_minutes),
+        }),
+    ));
 
     // usecases
+    let account_usecase = AccountUsecase::build(
+        Box::new(PgProfileRepository::build(pool.clone())),
+        Box::new(PgIdentityRepository::build(pool.clone())),
+        Box::new(PgIdentityRepository::build(pool.clone())),
+        Box::new(PgClubRepository::build(pool.clone())),
+        event_publisher.clone(),
+    );
     let club_usecase = ClubUsecase::build(club_repository, event_publisher.clone());
-    let team_usecase = TeamUsecase::build(team_repository, event_publisher.clone());
-    let social_usecase = SocialUsecase::build(community_repository, post_repository, post_reaction_repository, comment_repository, feed_repository,event_publisher.clone());
+    let team_usecase = TeamUsecase::build(
+        team_repository,
+        Box::new(PgPlayerRepository::build(pool.clone())),
+        Box::new(PgCommunityRepository::build(pool.clone())),
+        event_publisher.clone(),
+    );
+    let fixture_usecase = FixtureUsecase::build(
+        Box::new(PgMatchRepository::build(pool.clone())),
+        Box::new(PgTeamRepository::build(pool.clone())),
+        Box::new(PgSeasonRepository::build(pool.clone())),
+        Box::new(PgCommunityRepository::build(pool.clone())),
+        Box::new(PgPostRepository::build(pool.clone())),
+        event_publisher.clone(),
+    );
+    let competition_usecase = CompetitionUsecase::build(
+        Box::new(PgCompetitionRepository::build(pool.clone())),
+        Box::new(PgSeasonRepository::build(pool.clone())),
+        Box::new(PgMatchRepository::build(pool.clone())),
+        Box::new(PgTeamRepository::build(pool.clone())),
+        event_publisher.clone(),
+    );
+    let calendar_usecase = CalendarUsecase::build(
+        Box::new(PgTeamEventRepository::build(pool.clone())),
+        Box::new(PgRsvpRepository::build(pool.clone())),
+        Box::new(PgTeamRepository::build(pool.clone())),
+        Box::new(PgPlayerRepository::build(pool.clone())),
+        event_publisher.clone(),
+    );
+    let social_settings = SocialSettings {
+        report_threshold: configuration.report_threshold,
+        max_comment_depth: configuration.max_comment_depth,
+        max_pinned_posts: configuration.max_pinned_posts,
+        announcement_duration: Duration::hours(configuration.announcement_hours),
+    };
+    let social_usecase = SocialUsecase::build(community_repository, post_repository, post_reaction_repository, poll_vote_repository, comment_repository, comment_reaction_repository, bookmark_repository, follow_repository, block_repository, Box::new(PgProfileRepository::build(pool.clone())), feed_repository, search_repository, report_repository, Box::new(content_filter_repository), content_filter, event_publisher.clone(), social_settings);
+
+    // jobs
+    let retention_usecase = Arc::new(RetentionUsecase::build(
+        Box::new(PgPostRepository::build(pool.clone())),
+        Box::new(PgCommentRepository::build(pool.clone())),
+        event_publisher.clone(),
+        Duration::days(configuration.removed_content_retention_days),
+    ));
+    scheduler::every(std::time::Duration::from_secs(configuration.retention_interval_minutes * 60), move || {
+        let retention_usecase = retention_usecase.clone();
+        async move {
+            if let Err(error) = retention_usecase.purge_removed().await {
+                println!("retention error {:?}", error);
+            }
+        }
+    });
+
+    let publication_usecase = Arc::new(PublicationUsecase::build(
+        Box::new(PgPostRepository::build(pool.clone())),
+        Box::new(PgPollVoteRepository::build(pool.clone())),
+        Box::new(PgBlockRepository::build(pool.clone())),
+        event_publisher.clone(),
+    ));
+    scheduler::every(std::time::Duration::from_secs(configuration.publication_interval_seconds), move || {
+        let publication_usecase = publication_usecase.clone();
+        async move {
+            if let Err(error) = publication_usecase.publish_due().await {
+                println!("publication error {:?}", error);
+            }
+            if let Err(error) = publication_usecase.close_due_polls().await {
+                println!("poll closing error {:?}", error);
+            }
+        }
+    });
+
+    let notification_usecase = Arc::new(NotificationUsecase::build(
+        Box::new(PgNotificationRepository::build(pool.clone())),
+        Box::new(PgNotificationPreferencesRepository::build(pool.clone())),
+        Box::new(PgPostRepository::build(pool.clone())),
+        Box::new(PgCommentRepository::build(pool.clone())),
+        Box::new(PgBlockRepository::build(pool.clone())),
+        event_repository.clone(),
+        event_publisher.clone(),
+    ));
+    let consuming_usecase = notification_usecase.clone();
+    scheduler::every(std::time::Duration::from_secs(configuration.notification_interval_seconds), move || {
+        let notification_usecase = consuming_usecase.clone();
+        async move {
+            if let Err(error) = notification_usecase.consume_events().await {
+                println!("notification error {:?}", error);
+            }
+        }
+    });
 
     // api
+    let identity_providers = IdentityProviders {
+        firebase_issuer: configuration.firebase_issuer.clone(),
+        service_account_issuer: configuration.service_account_issuer.clone(),
+    };
     let service = ApiService::build(
+        identity_providers,
+        account_usecase,
         club_usecase,
         team_usecase,
-        social_usecase
+        fixture_usecase,
+        competition_usecase,
+        calendar_usecase,
+        social_usecase,
+        notification_usecase
     );
 
     Server::builder()